@@ -4,6 +4,7 @@ import test_echo
 import test_lint
 import test_format
 import test_hurl
+import test_secret
 
 
 def get_files(glob_expr):
@@ -32,6 +33,7 @@ def main():
         + get_files("tests_failed/*.hurl")
         + get_files("ssl/*.hurl")
     ]
+    test_secret.main()
 
     print("test integration ok!")
 
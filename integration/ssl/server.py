@@ -1,6 +1,8 @@
 #!/usr/bin/python
 from flask import Flask
 import os
+import ssl
+import threading
 
 app = Flask(__name__)
 
@@ -10,6 +12,21 @@ def hello():
     return "Hello World!"
 
 
+def client_cert_context(ssl_dir):
+    # TLS context for a server requiring a client certificate signed by
+    # (or equal to) client-cert.pem
+    context = ssl.SSLContext(ssl.PROTOCOL_TLS_SERVER)
+    context.load_cert_chain(ssl_dir + "/cert.pem", ssl_dir + "/key.pem")
+    context.load_verify_locations(ssl_dir + "/client-cert.pem")
+    context.verify_mode = ssl.CERT_REQUIRED
+    return context
+
+
 if __name__ == "__main__":
     ssl_dir = os.path.dirname(os.path.realpath(__file__))
+    threading.Thread(
+        target=app.run,
+        kwargs={"port": 8002, "ssl_context": client_cert_context(ssl_dir)},
+        daemon=True,
+    ).start()
     app.run(port=8001, ssl_context=(ssl_dir + "/cert.pem", ssl_dir + "/key.pem"))
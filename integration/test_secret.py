@@ -0,0 +1,44 @@
+#!/usr/bin/env python3
+# Check that secret values are not leaked
+# in logs, json output, junit and html reports
+#
+import glob
+import os
+import subprocess
+import sys
+import tempfile
+
+
+def test(hurl_file, secret):
+    options = open(hurl_file.replace(".hurl", ".options")).read().strip().split("\n")
+    with tempfile.TemporaryDirectory() as report_dir:
+        cmd = [
+            "hurl",
+            hurl_file,
+            "--verbose",
+            "--json",
+            "--report-junit",
+            os.path.join(report_dir, "tests.xml"),
+            "--report-html",
+            report_dir,
+        ] + options
+        print(" ".join(cmd))
+        result = subprocess.run(cmd, stdout=subprocess.PIPE, stderr=subprocess.PIPE)
+
+        outputs = {"stdout": result.stdout, "stderr": result.stderr}
+        for f in glob.glob(os.path.join(report_dir, "**", "*"), recursive=True):
+            if os.path.isfile(f):
+                outputs[f] = open(f, "rb").read()
+
+        for name, content in outputs.items():
+            if secret.encode() in content:
+                print(f">>> secret leaked in {name}")
+                sys.exit(1)
+
+
+def main():
+    test("tests_failed/secret.hurl", "s3cr3t")
+
+
+if __name__ == "__main__":
+    main()
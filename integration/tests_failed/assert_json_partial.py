@@ -0,0 +1,14 @@
+from app import app
+from flask import Response
+
+
+@app.route("/error-assert-json-partial")
+def error_assert_json_partial():
+    return Response(
+        """{
+  "id": 1,
+  "name": "Bob",
+  "tags": ["admin", "user"]
+}""",
+        mimetype="application/json",
+    )
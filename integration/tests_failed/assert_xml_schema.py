@@ -0,0 +1,14 @@
+from app import app
+from flask import Response
+
+
+@app.route("/error-assert-xml-schema")
+def error_assert_xml_schema():
+    return Response(
+        """<?xml version="1.0" encoding="UTF-8"?>
+<order>
+  <id>one</id>
+  <price>free</price>
+</order>""",
+        mimetype="application/xml",
+    )
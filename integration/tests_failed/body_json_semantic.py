@@ -0,0 +1,10 @@
+from app import app
+from flask import Response
+
+
+@app.route("/error-body-json-semantic")
+def error_body_json_semantic():
+    return Response(
+        """{"id":1,"name":"Bill","tags":["admin"],"address":{"city":"Paris","country":"France"}}""",
+        mimetype="application/json",
+    )
@@ -0,0 +1,8 @@
+from app import app
+from flask import request, Response
+
+
+@app.route("/secret")
+def secret():
+    token = request.headers["Authorization"][len("Bearer ") :]
+    return Response(headers={"X-Token": token})
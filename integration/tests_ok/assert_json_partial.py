@@ -0,0 +1,18 @@
+from app import app
+from flask import Response
+
+
+@app.route("/assert-json-partial")
+def assert_json_partial():
+    return Response(
+        """{
+  "id": 1,
+  "name": "Bob",
+  "tags": ["admin", "user"],
+  "users": [
+    {"id": 2, "name": "Bill", "email": "bill@example.org"},
+    {"id": 3, "name": "Alice"}
+  ]
+}""",
+        mimetype="application/json",
+    )
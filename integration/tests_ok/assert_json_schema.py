@@ -0,0 +1,19 @@
+from app import app
+from flask import Response
+
+
+@app.route("/assert-json-schema")
+def assert_json_schema():
+    return Response(
+        """{
+  "id": 1,
+  "name": "Bob",
+  "email": "bob@example.org",
+  "tags": ["admin"],
+  "users": [
+    {"id": 2, "name": "Bill", "email": "bill@example.org"},
+    {"id": "3", "name": "Alice"}
+  ]
+}""",
+        mimetype="application/json",
+    )
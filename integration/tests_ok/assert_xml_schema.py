@@ -0,0 +1,25 @@
+from app import app
+from flask import Response
+
+
+@app.route("/assert-xml-schema")
+def assert_xml_schema():
+    return Response(
+        """<?xml version="1.0" encoding="UTF-8"?>
+<order>
+  <id>1</id>
+  <price>9.99</price>
+</order>""",
+        mimetype="application/xml",
+    )
+
+
+@app.route("/assert-xml-schema-invalid")
+def assert_xml_schema_invalid():
+    return Response(
+        """<?xml version="1.0" encoding="UTF-8"?>
+<order>
+  <id>1</id>
+</order>""",
+        mimetype="application/xml",
+    )
@@ -0,0 +1,11 @@
+from app import app
+from flask import Response
+
+
+@app.route("/body-json-semantic")
+def body_json_semantic():
+    return Response(
+        """{"address":{"zip":"75001","city":"Paris"},"tags":["admin","user"],"score":4,
+"requestId":"c4a0b8e2","name":"Bob","id":1,"updatedAt":"2022-10-17T08:12:45Z"}""",
+        mimetype="application/json",
+    )
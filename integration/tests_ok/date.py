@@ -0,0 +1,14 @@
+from app import app
+from flask import Response
+
+
+@app.route("/date")
+def date():
+    return Response(
+        """{
+  "createdAt": "2022-01-06T17:30:00.123Z",
+  "day": "2022-01-06"
+}""",
+        mimetype="application/json",
+        headers={"Last-Modified": "Thu, 06 Jan 2022 17:30:00 GMT"},
+    )
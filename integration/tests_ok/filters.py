@@ -0,0 +1,20 @@
+from app import app
+from flask import Response
+
+
+@app.route("/filters")
+def filters():
+    return Response(
+        """{
+  "list": [1, 2, 13],
+  "ids": "12,27,45",
+  "price": "12.5",
+  "url": "https://mozilla.org/?x=шеллы",
+  "encoded_url": "https%3A%2F%2Fmozilla.org%2F%3Fx%3D%D1%88%D0%B5%D0%BB%D0%BB%D1%8B",
+  "html": "a > b && a < c",
+  "encoded_html": "a &gt; b &amp;&amp; a &lt; c",
+  "encoded_base64": "SGVsbG8=",
+  "message": "Hello World!"
+}""",
+        mimetype="application/json",
+    )
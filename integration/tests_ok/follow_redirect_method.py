@@ -0,0 +1,25 @@
+from app import app
+from flask import redirect, request
+
+
+@app.route("/follow-redirect-307", methods=["POST"])
+def follow_redirect_307():
+    return redirect("http://localhost:8000/followed-redirect-method", code=307)
+
+
+@app.route("/follow-redirect-302", methods=["POST"])
+def follow_redirect_302():
+    return redirect("http://localhost:8000/followed-redirect-method", code=302)
+
+
+@app.route("/follow-redirect-cross-origin")
+def follow_redirect_cross_origin():
+    return redirect("http://127.0.0.1:8000/followed-redirect-method", code=302)
+
+
+@app.route("/followed-redirect-method", methods=["GET", "POST"])
+def followed_redirect_method():
+    authorization = request.headers.get("Authorization", "none")
+    custom = request.headers.get("X-Custom", "none")
+    body = request.data.decode("utf-8")
+    return f"{request.method} body={body} authorization={authorization} custom={custom}"
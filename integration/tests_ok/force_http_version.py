@@ -0,0 +1,7 @@
+from app import app
+from flask import request
+
+
+@app.route("/force-http-version")
+def force_http_version():
+    return request.environ["SERVER_PROTOCOL"]
@@ -0,0 +1 @@
+FUNCTIONS_TOKEN=abc123
@@ -0,0 +1,13 @@
+from app import app
+from flask import request, jsonify
+
+
+@app.route("/functions", methods=["POST"])
+def functions():
+    assert request.headers["Authorization"] == "Bearer abc123"
+    return jsonify(
+        id=request.args.get("id"),
+        value=int(request.args.get("value")),
+        date=request.headers["Date"],
+        body_id=request.json["id"],
+    )
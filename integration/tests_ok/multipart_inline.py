@@ -0,0 +1,30 @@
+from app import app
+from flask import request
+
+
+@app.route("/multipart-inline", methods=["POST"])
+def multipart_inline():
+
+    assert request.form["key1"] == "value1"
+
+    upload1 = request.files["upload1"]
+    assert upload1.filename == "hello.txt"
+    assert upload1.content_type == "text/plain"
+    assert upload1.read() == b"Hello World!"
+
+    upload2 = request.files["upload2"]
+    assert upload2.filename == "data.bin"
+    assert upload2.content_type == "application/octet-stream"
+    assert upload2.read() == b"\x01\x02\x03"
+
+    upload3 = request.files["upload3"]
+    assert upload3.filename == "data.bin"
+    assert upload3.content_type == "text/html"
+    assert upload3.read() == b"Hello"
+
+    upload4 = request.files["upload4"]
+    assert upload4.filename == "report-1.csv"
+    assert upload4.content_type == "text/csv"
+    assert upload4.read() == b"a,b"
+
+    return ""
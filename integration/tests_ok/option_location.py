@@ -0,0 +1,12 @@
+from app import app
+from flask import redirect
+
+
+@app.route("/option/location")
+def option_location():
+    return redirect("http://localhost:8000/option/location-redirected")
+
+
+@app.route("/option/location-redirected")
+def option_location_redirected():
+    return "Redirected!"
@@ -0,0 +1,11 @@
+from app import app
+from flask import Response
+
+
+@app.route("/output_file")
+def output_file():
+    def generate():
+        for i in range(256):
+            yield bytes([i]) * 4096
+
+    return Response(generate(), mimetype="application/octet-stream")
@@ -0,0 +1,8 @@
+from app import app
+import time
+
+
+@app.route("/parallel/<name>/<int:delay>")
+def parallel(name, delay):
+    time.sleep(delay / 1000)
+    return name
@@ -0,0 +1,8 @@
+from flask import request
+from app import app
+
+
+@app.route("/post-template-file", methods=["POST"])
+def post_template_file():
+    assert request.json == {"id": 1, "name": "Bob"}
+    return ""
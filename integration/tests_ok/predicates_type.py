@@ -0,0 +1,20 @@
+from app import app
+from flask import Response
+
+
+@app.route("/predicates-type")
+def predicates_type():
+    return Response(
+        """{
+  "id": 123,
+  "score": 4.5,
+  "name": "Bob",
+  "nickname": "",
+  "tags": ["a", "b"],
+  "errors": [],
+  "options": {},
+  "createdAt": "2022-01-06T17:30:00.000Z",
+  "birthday": "06/01/1980"
+}""",
+        mimetype="application/json",
+    )
@@ -0,0 +1,19 @@
+from app import app
+from flask import Response
+
+count = 0
+
+
+@app.route("/retry/reset", methods=["POST"])
+def retry_reset():
+    global count
+    count = 0
+    return ""
+
+
+@app.route("/retry/job")
+def retry_job():
+    global count
+    count += 1
+    state = "COMPLETED" if count > 3 else "RUNNING"
+    return Response(f'{{"state":"{state}"}}', mimetype="application/json")
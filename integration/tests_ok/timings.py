@@ -0,0 +1,8 @@
+from app import app
+import time
+
+
+@app.route("/timings")
+def timings():
+    time.sleep(0.1)
+    return "Hello World!"
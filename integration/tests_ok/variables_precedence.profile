@@ -0,0 +1,4 @@
+HURL_env_only=env
+HURL_file_env=env
+HURL_env_cli=env
+HURL_file_env_cli=env
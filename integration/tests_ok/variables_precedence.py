@@ -0,0 +1,6 @@
+from app import app
+
+
+@app.route("/variables-precedence")
+def variables_precedence():
+    return ""
@@ -20,23 +20,40 @@ use colored::*;
 use hurl_core::error::Error;
 use hurl_core::parser;
 
+use super::Stderr;
 use crate::runner;
+use crate::util::redact;
 
-pub fn make_logger_verbose(verbose: bool) -> impl Fn(&str) {
-    move |message| log_verbose(verbose, message)
+pub fn make_logger_verbose(verbose: bool, secrets: Vec<String>, stderr: Stderr) -> impl Fn(&str) {
+    move |message| log_verbose(&stderr, verbose, redact(message, &secrets).as_str())
 }
 
-pub fn make_logger_error_message(color: bool) -> impl Fn(bool, &str) {
-    move |warning, message| log_error_message(color, warning, message)
+pub fn make_logger_error_message(
+    color: bool,
+    secrets: Vec<String>,
+    stderr: Stderr,
+) -> impl Fn(bool, &str) {
+    move |warning, message| {
+        log_error_message(&stderr, color, warning, redact(message, &secrets).as_str())
+    }
 }
 
 pub fn make_logger_parser_error(
     lines: Vec<String>,
     color: bool,
     filename: Option<String>,
+    stderr: Stderr,
 ) -> impl Fn(&parser::Error, bool) {
     move |error: &parser::Error, warning: bool| {
-        log_error(lines.clone(), color, filename.clone(), error, warning)
+        log_error(
+            &stderr,
+            lines.clone(),
+            color,
+            filename.clone(),
+            error,
+            warning,
+            &[],
+        )
     }
 }
 
@@ -44,9 +61,19 @@ pub fn make_logger_runner_error(
     lines: Vec<String>,
     color: bool,
     filename: Option<String>,
+    secrets: Vec<String>,
+    stderr: Stderr,
 ) -> impl Fn(&runner::Error, bool) {
     move |error: &runner::Error, warning: bool| {
-        log_error(lines.clone(), color, filename.clone(), error, warning)
+        log_error(
+            &stderr,
+            lines.clone(),
+            color,
+            filename.clone(),
+            error,
+            warning,
+            &secrets,
+        )
     }
 }
 
@@ -54,28 +81,30 @@ pub fn log_info(message: &str) {
     eprintln!("{}", message);
 }
 
-fn log_error_message(color: bool, warning: bool, message: &str) {
+fn log_error_message(stderr: &Stderr, color: bool, warning: bool, message: &str) {
     let log_type = match (color, warning) {
         (false, true) => "warning".to_string(),
         (false, false) => "error".to_string(),
         (true, true) => "warning".yellow().bold().to_string(),
         (true, false) => "error".red().bold().to_string(),
     };
-    eprintln!("{}: {}", log_type, message);
+    stderr.eprintln(format!("{}: {}", log_type, message).as_str());
 }
 
-fn log_verbose(verbose: bool, message: &str) {
+fn log_verbose(stderr: &Stderr, verbose: bool, message: &str) {
     if verbose {
-        eprintln!("* {}", message);
+        stderr.eprintln(format!("* {}", message).as_str());
     }
 }
 
 fn log_error(
+    stderr: &Stderr,
     lines: Vec<String>,
     color: bool,
     filename: Option<String>,
     error: &dyn Error,
     warning: bool,
+    secrets: &[String],
 ) {
     let error_type = if warning {
         String::from("warning")
@@ -95,8 +124,8 @@ fn log_error(
     } else {
         "".to_string()
     };
-    let error_message = error_string(&lines, filename, error);
-    eprintln!("{}: {}\n", error_type, error_message);
+    let error_message = redact(error_string(&lines, filename, error).as_str(), secrets);
+    stderr.eprintln(format!("{}: {}\n", error_type, error_message).as_str());
 }
 
 pub fn error_string(lines: &[String], filename: String, error: &dyn Error) -> String {
@@ -276,6 +305,39 @@ pub mod tests {
         )
     }
 
+    #[test]
+    fn test_assert_error_xml_schema() {
+        let lines = vec![
+            "POST http://localhost/soap".to_string(),
+            "HTTP/1.0 200".to_string(),
+            "[Asserts]".to_string(),
+            "body xmlschema file,order.xsd;".to_string(),
+        ];
+        let filename = "test.hurl".to_string();
+        let error = runner::Error {
+            source_info: SourceInfo::init(4, 0, 4, 0),
+            inner: runner::RunnerError::AssertXmlSchema {
+                schema: "order.xsd".to_string(),
+                violations: vec![(
+                    3,
+                    "Element 'price': 'free' is not a valid value of the atomic type 'xs:decimal'."
+                        .to_string(),
+                )],
+            },
+            assert: true,
+        };
+        assert_eq!(
+            error_string(&lines, filename, &error),
+            r#"Assert XML Schema
+  --> test.hurl:4:0
+   |
+ 4 | body xmlschema file,order.xsd;
+   |   value is not valid against schema order.xsd:
+   |     line 3: Element 'price': 'free' is not a valid value of the atomic type 'xs:decimal'.
+   |"#
+        )
+    }
+
     #[test]
     fn test_assert_error_newline() {
         let lines = vec![
@@ -290,6 +352,7 @@ pub mod tests {
             inner: runner::RunnerError::AssertBodyValueError {
                 actual: "<p>Hello</p>\n\n".to_string(),
                 expected: "<p>Hello</p>\n".to_string(),
+                diff: vec![],
             },
             assert: true,
         };
@@ -27,13 +27,16 @@ pub use self::options::app;
 pub use self::options::output_color;
 pub use self::options::parse_options;
 pub use self::options::{CliOptions, OutputType};
+pub use self::stderr::Stderr;
 pub use self::variables::parse as parse_variable;
+pub use self::variables::parse_env_vars as parse_env_variables;
 pub use self::variables::parse_value as parse_variable_value;
 
 mod fs;
 pub mod interactive;
 mod logger;
 mod options;
+mod stderr;
 mod variables;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -18,7 +18,7 @@
 
 use crate::cli;
 use crate::cli::CliError;
-use crate::http::ClientOptions;
+use crate::http::{ClientOptions, HttpVersion};
 use crate::runner::Value;
 use atty::Stream;
 use clap::{App, AppSettings, ArgMatches};
@@ -31,27 +31,40 @@ use std::time::Duration;
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct CliOptions {
     pub cacert_file: Option<String>,
+    pub client_cert_file: Option<String>,
+    pub client_key_file: Option<String>,
     pub color: bool,
     pub compressed: bool,
     pub connect_timeout: Duration,
+    pub connects_to: Vec<String>,
     pub cookie_input_file: Option<String>,
     pub cookie_output_file: Option<String>,
+    pub duration: Option<Duration>,
     pub fail_fast: bool,
     pub file_root: Option<String>,
     pub follow_location: bool,
+    pub follow_location_trusted: bool,
     pub glob_files: Vec<String>,
+    pub http_version: Option<HttpVersion>,
     pub html_dir: Option<PathBuf>,
     pub ignore_asserts: bool,
     pub include: bool,
     pub insecure: bool,
     pub interactive: bool,
+    pub jobs: Option<usize>,
     pub junit_file: Option<String>,
+    pub key_password: Option<String>,
     pub max_redirect: Option<usize>,
     pub no_proxy: Option<String>,
     pub output: Option<String>,
     pub output_type: OutputType,
     pub progress: bool,
     pub proxy: Option<String>,
+    pub repeat: Option<usize>,
+    pub resolves: Vec<String>,
+    pub retry: usize,
+    pub retry_interval: Duration,
+    pub secrets: Vec<String>,
     pub summary: bool,
     pub timeout: Duration,
     pub to_entry: Option<usize>,
@@ -86,6 +99,13 @@ pub fn app(version: &str) -> App {
                 .value_name("FILE")
                 .help("CA certificate to verify peer against (PEM format)"),
         )
+        .arg(
+            clap::Arg::new("client_cert_file")
+                .short('E')
+                .long("cert")
+                .value_name("CERTIFICATE")
+                .help("Client certificate file (PEM format)"),
+        )
         .arg(
             clap::Arg::new("color")
                 .long("color")
@@ -103,6 +123,15 @@ pub fn app(version: &str) -> App {
                 .value_name("SECONDS")
                 .help("Maximum time allowed for connection"),
         )
+        .arg(
+            clap::Arg::new("connect_to")
+                .long("connect-to")
+                .value_name("HOST1:PORT1:HOST2:PORT2")
+                .multiple_occurrences(true)
+                .number_of_values(1)
+                .help("For a request to the given HOST1:PORT1 pair, connect to HOST2:PORT2 instead")
+                .takes_value(true),
+        )
         .arg(
             clap::Arg::new("cookies_input_file")
                 .short('b')
@@ -117,6 +146,14 @@ pub fn app(version: &str) -> App {
                 .value_name("FILE")
                 .help("Write cookies to FILE after running the session (only for one session)"),
         )
+        .arg(
+            clap::Arg::new("duration")
+                .long("duration")
+                .value_name("SECONDS")
+                .conflicts_with("interactive")
+                .help("Run each file repeatedly during SECONDS and print a load test summary")
+                .takes_value(true),
+        )
         .arg(
             clap::Arg::new("fail_at_end")
                 .long("fail-at-end")
@@ -136,6 +173,11 @@ pub fn app(version: &str) -> App {
                 .long("location")
                 .help("Follow redirects"),
         )
+        .arg(
+            clap::Arg::new("follow_location_trusted")
+                .long("location-trusted")
+                .help("Follow redirects, sending credentials to other hosts"),
+        )
         .arg(
             clap::Arg::new("glob")
                 .long("glob")
@@ -144,6 +186,30 @@ pub fn app(version: &str) -> App {
                 .number_of_values(1)
                 .help("Specify input files that match the given blob. Multiple glob flags may be used."),
         )
+        .arg(
+            clap::Arg::new("http10")
+                .short('0')
+                .long("http1.0")
+                .conflicts_with_all(&["http11", "http2", "http2_prior_knowledge"])
+                .help("Tell Hurl to use HTTP version 1.0"),
+        )
+        .arg(
+            clap::Arg::new("http11")
+                .long("http1.1")
+                .conflicts_with_all(&["http2", "http2_prior_knowledge"])
+                .help("Tell Hurl to use HTTP version 1.1"),
+        )
+        .arg(
+            clap::Arg::new("http2")
+                .long("http2")
+                .conflicts_with("http2_prior_knowledge")
+                .help("Tell Hurl to use HTTP version 2"),
+        )
+        .arg(
+            clap::Arg::new("http2_prior_knowledge")
+                .long("http2-prior-knowledge")
+                .help("Tell Hurl to use HTTP version 2 without HTTP/1.1 Upgrade"),
+        )
         .arg(
             clap::Arg::new("include")
                 .short('i')
@@ -167,12 +233,32 @@ pub fn app(version: &str) -> App {
                 .conflicts_with("to_entry")
                 .help("Turn on interactive mode"),
         )
+        .arg(
+            clap::Arg::new("jobs")
+                .long("jobs")
+                .value_name("NUM")
+                .conflicts_with("interactive")
+                .help("Maximum number of files (or load test runs) run in parallel")
+                .takes_value(true),
+        )
         .arg(
             clap::Arg::new("json")
                 .long("json")
                 .conflicts_with("no_output")
                 .help("Output each hurl file result to JSON"),
         )
+        .arg(
+            clap::Arg::new("client_key_file")
+                .long("key")
+                .value_name("KEY")
+                .help("Private key file name (PEM format)"),
+        )
+        .arg(
+            clap::Arg::new("key_password")
+                .long("key-password")
+                .value_name("PASSWORD")
+                .help("Passphrase for the private key"),
+        )
         .arg(
             clap::Arg::new("max_redirects")
                 .long("max-redirs")
@@ -214,6 +300,12 @@ pub fn app(version: &str) -> App {
                 .value_name("FILE")
                 .help("Write to FILE instead of stdout"),
         )
+        .arg(
+            clap::Arg::new("parallel")
+                .long("parallel")
+                .conflicts_with("interactive")
+                .help("Run files in parallel"),
+        )
         .arg(
             clap::Arg::new("progress")
                 .long("progress")
@@ -240,6 +332,46 @@ pub fn app(version: &str) -> App {
                 .help("Generate html report to dir")
                 .takes_value(true),
         )
+        .arg(
+            clap::Arg::new("repeat")
+                .long("repeat")
+                .value_name("NUM")
+                .conflicts_with("interactive")
+                .help("Run each file NUM times and print a load test summary")
+                .takes_value(true),
+        )
+        .arg(
+            clap::Arg::new("resolve")
+                .long("resolve")
+                .value_name("HOST:PORT:ADDR")
+                .multiple_occurrences(true)
+                .number_of_values(1)
+                .help("Provide a custom address for a specific host and port pair")
+                .takes_value(true),
+        )
+        .arg(
+            clap::Arg::new("retry")
+                .long("retry")
+                .value_name("NUM")
+                .help("Maximum number of retries when asserts fail")
+                .takes_value(true),
+        )
+        .arg(
+            clap::Arg::new("retry_interval")
+                .long("retry-interval")
+                .value_name("MILLISECONDS")
+                .help("Interval in milliseconds before a retry")
+                .takes_value(true),
+        )
+        .arg(
+            clap::Arg::new("secret")
+                .long("secret")
+                .value_name("NAME=VALUE")
+                .multiple_occurrences(true)
+                .number_of_values(1)
+                .help("Define a variable whose value is redacted from logs and reports")
+                .takes_value(true),
+        )
         .arg(
             clap::Arg::new("summary")
                 .long("summary")
@@ -280,14 +412,14 @@ pub fn app(version: &str) -> App {
                 .value_name("NAME=VALUE")
                 .multiple_occurrences(true)
                 .number_of_values(1)
-                .help("Define a variable")
+                .help("Define a variable, overriding HURL_NAME environment variable and variables file")
                 .takes_value(true),
         )
         .arg(
             clap::Arg::new("variables_file")
                 .long("variables-file")
                 .value_name("FILE")
-                .help("Define a properties file in which you define your variables")
+                .help("Define a properties file in which you define your variables (lowest precedence)")
                 .takes_value(true),
         )
         .arg(
@@ -299,17 +431,9 @@ pub fn app(version: &str) -> App {
 }
 
 pub fn parse_options(matches: ArgMatches) -> Result<CliOptions, CliError> {
-    let cacert_file = match matches.value_of("cacert_file") {
-        None => None,
-        Some(filename) => {
-            if !Path::new(filename).is_file() {
-                let message = format!("File {} does not exist", filename);
-                return Err(CliError { message });
-            } else {
-                Some(filename.to_string())
-            }
-        }
-    };
+    let cacert_file = input_file(matches.clone(), "cacert_file")?;
+    let client_cert_file = input_file(matches.clone(), "client_cert_file")?;
+    let client_key_file = input_file(matches.clone(), "client_key_file")?;
     let color = output_color(matches.clone());
     let compressed = matches.is_present("compressed");
     let connect_timeout = match matches.value_of("connect_timeout") {
@@ -323,16 +447,31 @@ pub fn parse_options(matches: ArgMatches) -> Result<CliOptions, CliError> {
             }
         },
     };
+    let connects_to = host_mappings(matches.clone(), "connect_to", "HOST1:PORT1:HOST2:PORT2")?;
     let cookie_input_file = matches
         .value_of("cookies_input_file")
         .map(|x| x.to_string());
     let cookie_output_file = matches
         .value_of("cookies_output_file")
         .map(|x| x.to_string());
+    let duration = match matches.value_of("duration") {
+        None => None,
+        Some(s) => match s.parse::<u64>() {
+            Ok(n) => Some(Duration::from_secs(n)),
+            Err(_) => {
+                return Err(CliError {
+                    message: "duration option can not be parsed".to_string(),
+                });
+            }
+        },
+    };
     let fail_fast = !matches.is_present("fail_at_end");
     let file_root = matches.value_of("file_root").map(|value| value.to_string());
-    let follow_location = matches.is_present("follow_location");
+    let follow_location =
+        matches.is_present("follow_location") || matches.is_present("follow_location_trusted");
+    let follow_location_trusted = matches.is_present("follow_location_trusted");
     let glob_files = match_glob_files(matches.clone())?;
+    let http_version = http_version(matches.clone());
     let report_html = matches.value_of("report_html");
     let html_dir = if let Some(dir) = report_html {
         let path = Path::new(dir);
@@ -359,6 +498,8 @@ pub fn parse_options(matches: ArgMatches) -> Result<CliOptions, CliError> {
     let include = matches.is_present("include");
     let insecure = matches.is_present("insecure");
     let interactive = matches.is_present("interactive");
+    let jobs = jobs(matches.clone())?;
+    let key_password = matches.value_of("key_password").map(|x| x.to_string());
     let junit_file = matches
         .value_of("junit")
         .map(|filename| filename.to_string());
@@ -387,6 +528,45 @@ pub fn parse_options(matches: ArgMatches) -> Result<CliOptions, CliError> {
     };
     let progress = matches.is_present("progress") || matches.is_present("test");
     let proxy = matches.value_of("proxy").map(|x| x.to_string());
+    let repeat = match matches.value_of("repeat") {
+        None => None,
+        Some(s) => match s.parse::<usize>() {
+            Ok(n) => Some(n),
+            Err(_) => {
+                return Err(CliError {
+                    message: "repeat option can not be parsed".to_string(),
+                });
+            }
+        },
+    };
+    let resolves = host_mappings(matches.clone(), "resolve", "HOST:PORT:ADDR")?;
+    let retry = match matches.value_of("retry") {
+        None => 0,
+        Some(s) => match s.parse::<usize>() {
+            Ok(n) => n,
+            Err(_) => {
+                return Err(CliError {
+                    message: "retry option can not be parsed".to_string(),
+                });
+            }
+        },
+    };
+    let retry_interval = match matches.value_of("retry_interval") {
+        None => Duration::from_millis(1000),
+        Some(s) => match s.parse::<u64>() {
+            Ok(n) => Duration::from_millis(n),
+            Err(_) => {
+                return Err(CliError {
+                    message: "retry-interval option can not be parsed".to_string(),
+                });
+            }
+        },
+    };
+    let mut secrets = secrets(matches.clone())?;
+    // the private key password is redacted like the secret variables
+    if let Some(key_password) = &key_password {
+        secrets.push(key_password.clone());
+    }
     let summary = matches.is_present("summary") || matches.is_present("test");
     let timeout = match matches.value_of("max_time") {
         None => ClientOptions::default().timeout,
@@ -407,27 +587,40 @@ pub fn parse_options(matches: ArgMatches) -> Result<CliOptions, CliError> {
 
     Ok(CliOptions {
         cacert_file,
+        client_cert_file,
+        client_key_file,
         color,
         compressed,
         connect_timeout,
+        connects_to,
         cookie_input_file,
         cookie_output_file,
+        duration,
         fail_fast,
         file_root,
         follow_location,
+        follow_location_trusted,
         glob_files,
+        http_version,
         html_dir,
         ignore_asserts,
         include,
         insecure,
         interactive,
+        jobs,
         junit_file,
+        key_password,
         max_redirect,
         no_proxy,
         output,
         output_type,
         progress,
         proxy,
+        repeat,
+        resolves,
+        retry,
+        retry_interval,
+        secrets,
         summary,
         timeout,
         to_entry,
@@ -448,6 +641,76 @@ pub fn output_color(matches: ArgMatches) -> bool {
     }
 }
 
+/// Returns the values of the option `name`, each one being checked against the
+/// colon separated `format` (like `HOST:PORT:ADDR` for `--resolve`).
+fn host_mappings(matches: ArgMatches, name: &str, format: &str) -> Result<Vec<String>, CliError> {
+    let mut mappings = vec![];
+    if let Some(values) = matches.values_of(name) {
+        for value in values {
+            if value.split(':').count() < format.split(':').count() {
+                return Err(CliError {
+                    message: format!(
+                        "Invalid value for option --{} - must be {}",
+                        name.replace('_', "-"),
+                        format
+                    ),
+                });
+            }
+            mappings.push(value.to_string());
+        }
+    }
+    Ok(mappings)
+}
+
+/// Returns the HTTP version forced on the command line, if any.
+fn http_version(matches: ArgMatches) -> Option<HttpVersion> {
+    if matches.is_present("http10") {
+        Some(HttpVersion::Http10)
+    } else if matches.is_present("http11") {
+        Some(HttpVersion::Http11)
+    } else if matches.is_present("http2") {
+        Some(HttpVersion::Http2)
+    } else if matches.is_present("http2_prior_knowledge") {
+        Some(HttpVersion::Http2PriorKnowledge)
+    } else {
+        None
+    }
+}
+
+/// Returns the file given by the option `name`, checking that it exists.
+fn input_file(matches: ArgMatches, name: &str) -> Result<Option<String>, CliError> {
+    match matches.value_of(name) {
+        None => Ok(None),
+        Some(filename) => {
+            if !Path::new(filename).is_file() {
+                let message = format!("File {} does not exist", filename);
+                Err(CliError { message })
+            } else {
+                Ok(Some(filename.to_string()))
+            }
+        }
+    }
+}
+
+/// Returns the number of files run in parallel, or None if files are run sequentially.
+/// With `--parallel` and no `--jobs`, the number of available CPUs is used.
+fn jobs(matches: ArgMatches) -> Result<Option<usize>, CliError> {
+    match matches.value_of("jobs") {
+        Some(value) => match value.parse::<usize>() {
+            Ok(n) if n > 0 => Ok(Some(n)),
+            _ => Err(CliError {
+                message: "Invalid value for option --jobs - must be a positive integer!"
+                    .to_string(),
+            }),
+        },
+        None if matches.is_present("parallel") => {
+            let n = std::thread::available_parallelism().map_or(1, |n| n.get());
+            Ok(Some(n))
+        }
+        None => Ok(None),
+    }
+}
+
 fn to_entry(matches: ArgMatches) -> Result<Option<usize>, CliError> {
     match matches.value_of("to_entry") {
         Some(value) => match value.parse() {
@@ -462,16 +725,10 @@ fn to_entry(matches: ArgMatches) -> Result<Option<usize>, CliError> {
 }
 
 fn variables(matches: ArgMatches) -> Result<HashMap<String, Value>, CliError> {
+    // Variables are defined in order of precedence:
+    // the variables file, then HURL_ environment variables, then --variable and --secret options.
     let mut variables = HashMap::new();
 
-    // use environment variables prefix by HURL_
-    for (env_name, env_value) in std::env::vars() {
-        if let Some(name) = env_name.strip_prefix("HURL_") {
-            let value = cli::parse_variable_value(env_value.as_str())?;
-            variables.insert(name.to_string(), value);
-        }
-    }
-
     if let Some(filename) = matches.value_of("variables_file") {
         let path = std::path::Path::new(filename);
         if !path.exists() {
@@ -500,6 +757,10 @@ fn variables(matches: ArgMatches) -> Result<HashMap<String, Value>, CliError> {
         }
     }
 
+    for (name, value) in cli::parse_env_variables(std::env::vars())? {
+        variables.insert(name, value);
+    }
+
     if matches.is_present("variable") {
         let input: Vec<_> = matches.values_of("variable").unwrap().collect();
         for s in input {
@@ -508,9 +769,42 @@ fn variables(matches: ArgMatches) -> Result<HashMap<String, Value>, CliError> {
         }
     }
 
+    if matches.is_present("secret") {
+        let input: Vec<_> = matches.values_of("secret").unwrap().collect();
+        for s in input {
+            let (name, value) = cli::parse_variable(s)?;
+            variables.insert(name.to_string(), value);
+        }
+    }
+
     Ok(variables)
 }
 
+/// Returns the values of the secret variables, to be redacted from logs and reports.
+///
+/// The values are kept as written on the command line (without their surrounding quotes).
+/// When a value is rendered differently in templates (`--secret pin=0042` being rendered as `42`),
+/// the rendered value is redacted too.
+fn secrets(matches: ArgMatches) -> Result<Vec<String>, CliError> {
+    let mut secrets = vec![];
+    if matches.is_present("secret") {
+        let input: Vec<_> = matches.values_of("secret").unwrap().collect();
+        for s in input {
+            let (_, value) = cli::parse_variable(s)?;
+            let (_, raw) = s.split_once('=').unwrap();
+            let raw = raw
+                .strip_prefix('"')
+                .and_then(|raw| raw.strip_suffix('"'))
+                .unwrap_or(raw);
+            secrets.push(raw.to_string());
+            if value.to_string() != raw {
+                secrets.push(value.to_string());
+            }
+        }
+    }
+    Ok(secrets)
+}
+
 pub fn match_glob_files(matches: ArgMatches) -> Result<Vec<String>, CliError> {
     let mut filenames = vec![];
     if matches.is_present("glob") {
@@ -0,0 +1,72 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2022 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// Destination of the messages written to the standard error.
+///
+/// Messages are written immediately, or buffered when Hurl files are run in parallel,
+/// so that the messages of a file are not interleaved with those of the other files.
+#[derive(Clone, Debug, Default)]
+pub struct Stderr {
+    buffer: Option<Rc<RefCell<String>>>,
+}
+
+impl Stderr {
+    pub fn buffered() -> Stderr {
+        Stderr {
+            buffer: Some(Rc::new(RefCell::new(String::new()))),
+        }
+    }
+
+    pub fn eprint(&self, message: &str) {
+        match &self.buffer {
+            None => eprint!("{}", message),
+            Some(buffer) => buffer.borrow_mut().push_str(message),
+        }
+    }
+
+    pub fn eprintln(&self, message: &str) {
+        self.eprint(format!("{}\n", message).as_str());
+    }
+
+    /// Returns the buffered messages (empty if messages are written immediately).
+    pub fn buffer(&self) -> String {
+        match &self.buffer {
+            None => String::new(),
+            Some(buffer) => buffer.borrow().clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_buffered() {
+        let stderr = Stderr::buffered();
+        let clone = stderr.clone();
+        stderr.eprint("* line1");
+        clone.eprintln("");
+        clone.eprintln("* line2");
+        assert_eq!(stderr.buffer(), "* line1\n* line2\n");
+        assert_eq!(Stderr::default().buffer(), "");
+    }
+}
@@ -32,6 +32,22 @@ pub fn parse(s: &str) -> Result<(String, Value), CliError> {
     }
 }
 
+/// Returns the variables defined by environment variables prefixed by `HURL_`,
+/// `HURL_name=value` defining the variable `name`.
+pub fn parse_env_vars<I>(vars: I) -> Result<Vec<(String, Value)>, CliError>
+where
+    I: IntoIterator<Item = (String, String)>,
+{
+    let mut variables = vec![];
+    for (env_name, env_value) in vars {
+        if let Some(name) = env_name.strip_prefix("HURL_") {
+            let value = parse_value(env_value.as_str())?;
+            variables.push((name.to_string(), value));
+        }
+    }
+    Ok(variables)
+}
+
 pub fn parse_value(s: &str) -> Result<Value, CliError> {
     if s == "true" {
         Ok(Value::Bool(true))
@@ -98,6 +114,22 @@ pub mod tests {
         );
     }
 
+    #[test]
+    fn test_parse_env_vars() {
+        let vars = vec![
+            ("HURL_name".to_string(), "Jennifer".to_string()),
+            ("HOME".to_string(), "/home/jennifer".to_string()),
+            ("HURL_age".to_string(), "30".to_string()),
+        ];
+        assert_eq!(
+            parse_env_vars(vars).unwrap(),
+            vec![
+                ("name".to_string(), Value::String("Jennifer".to_string())),
+                ("age".to_string(), Value::Integer(30)),
+            ]
+        );
+    }
+
     #[test]
     fn test_parse_value() {
         assert_eq!(
@@ -0,0 +1,163 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2022 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use chrono::{DateTime, NaiveDateTime, TimeZone, Utc};
+use std::collections::HashMap;
+use std::convert::TryFrom;
+
+/// Peer certificate of a TLS transfer, as reported by libcurl `CURLINFO_CERTINFO`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Certificate {
+    pub subject: String,
+    pub issuer: String,
+    pub start_date: DateTime<Utc>,
+    pub expire_date: DateTime<Utc>,
+    pub serial_number: String,
+}
+
+impl TryFrom<Vec<String>> for Certificate {
+    type Error = String;
+
+    /// Parses a certificate from the libcurl certinfo `Name:Value` attributes.
+    fn try_from(attributes: Vec<String>) -> Result<Self, Self::Error> {
+        let attributes = parse_attributes(&attributes);
+        let subject = parse_subject(&attributes)?;
+        let issuer = parse_issuer(&attributes)?;
+        let start_date = parse_start_date(&attributes)?;
+        let expire_date = parse_expire_date(&attributes)?;
+        let serial_number = parse_serial_number(&attributes)?;
+        Ok(Certificate {
+            subject,
+            issuer,
+            start_date,
+            expire_date,
+            serial_number,
+        })
+    }
+}
+
+fn parse_attributes(data: &[String]) -> HashMap<String, String> {
+    let mut map = HashMap::new();
+    for s in data.iter() {
+        if let Some((name, value)) = s.split_once(':') {
+            map.insert(name.to_lowercase(), value.to_string());
+        }
+    }
+    map
+}
+
+fn parse_subject(attributes: &HashMap<String, String>) -> Result<String, String> {
+    match attributes.get("subject") {
+        None => Err(format!("missing Subject attribute in {:?}", attributes)),
+        Some(value) => Ok(value.clone()),
+    }
+}
+
+fn parse_issuer(attributes: &HashMap<String, String>) -> Result<String, String> {
+    match attributes.get("issuer") {
+        None => Err(format!("missing Issuer attribute in {:?}", attributes)),
+        Some(value) => Ok(value.clone()),
+    }
+}
+
+fn parse_start_date(attributes: &HashMap<String, String>) -> Result<DateTime<Utc>, String> {
+    match attributes.get("start date") {
+        None => Err(format!("missing start date attribute in {:?}", attributes)),
+        Some(value) => parse_date(value),
+    }
+}
+
+fn parse_expire_date(attributes: &HashMap<String, String>) -> Result<DateTime<Utc>, String> {
+    match attributes.get("expire date") {
+        None => Err(format!("missing expire date attribute in {:?}", attributes)),
+        Some(value) => parse_date(value),
+    }
+}
+
+fn parse_serial_number(attributes: &HashMap<String, String>) -> Result<String, String> {
+    match attributes.get("serial number") {
+        None => Err(format!(
+            "missing serial number attribute in {:?}",
+            attributes
+        )),
+        Some(value) => Ok(value.clone()),
+    }
+}
+
+/// Parses a certificate date like `Jan  1 12:30:00 2023 GMT`.
+fn parse_date(value: &str) -> Result<DateTime<Utc>, String> {
+    let value = value.split_whitespace().collect::<Vec<&str>>().join(" ");
+    match NaiveDateTime::parse_from_str(value.as_str(), "%b %d %H:%M:%S %Y GMT") {
+        Ok(date) => Ok(Utc.from_utc_datetime(&date)),
+        Err(_) => Err(format!("can not parse date <{}>", value)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    fn date(year: i32, month: u32, day: u32, hour: u32, min: u32, sec: u32) -> DateTime<Utc> {
+        let date = NaiveDate::from_ymd_opt(year, month, day).unwrap();
+        Utc.from_utc_datetime(&date.and_hms_opt(hour, min, sec).unwrap())
+    }
+
+    #[test]
+    fn test_parse_date() {
+        assert_eq!(
+            parse_date("Jan 10 08:29:52 2023 GMT").unwrap(),
+            date(2023, 1, 10, 8, 29, 52)
+        );
+        assert_eq!(
+            parse_date("Jan  1 08:29:52 2023 GMT").unwrap(),
+            date(2023, 1, 1, 8, 29, 52)
+        );
+        assert!(parse_date("2023-01-10").is_err());
+    }
+
+    #[test]
+    fn test_certificate() {
+        let attributes = vec![
+            "Subject:C = US, ST = Denial, L = Springfield, O = Dis, CN = localhost".to_string(),
+            "Issuer:C = US, ST = Denial, L = Springfield, O = Dis, CN = localhost".to_string(),
+            "Version:2".to_string(),
+            "Serial Number:1e:e8:b1:7f:1b:64:d8:d6:b3:de:87:01:03:d2:a4:f5:33:38:ab:30".to_string(),
+            "Start date:Oct 19 14:34:06 2021 GMT".to_string(),
+            "Expire date:Oct 19 14:34:06 2022 GMT".to_string(),
+        ];
+        assert_eq!(
+            Certificate::try_from(attributes).unwrap(),
+            Certificate {
+                subject: "C = US, ST = Denial, L = Springfield, O = Dis, CN = localhost"
+                    .to_string(),
+                issuer: "C = US, ST = Denial, L = Springfield, O = Dis, CN = localhost".to_string(),
+                start_date: date(2021, 10, 19, 14, 34, 6),
+                expire_date: date(2022, 10, 19, 14, 34, 6),
+                serial_number: "1e:e8:b1:7f:1b:64:d8:d6:b3:de:87:01:03:d2:a4:f5:33:38:ab:30"
+                    .to_string(),
+            }
+        );
+
+        assert_eq!(
+            Certificate::try_from(vec!["Subject:CN = localhost".to_string()])
+                .err()
+                .unwrap(),
+            "missing Issuer attribute in {\"subject\": \"CN = localhost\"}".to_string()
+        );
+    }
+}
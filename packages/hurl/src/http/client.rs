@@ -15,19 +15,25 @@
  * limitations under the License.
  *
  */
-use std::io::Read;
+use std::fs::File;
+use std::io::{Read, Write};
+use std::rc::Rc;
 use std::str;
 
 use curl::easy;
 use encoding::all::ISO_8859_1;
 use encoding::{DecoderTrap, Encoding};
+use sha2::Digest;
 use std::time::Instant;
 
+use super::certificate::Certificate;
 use super::core::*;
-use super::options::ClientOptions;
+use super::options::{ClientOptions, HttpVersion};
 use super::request::*;
 use super::request_spec::*;
 use super::response::*;
+use crate::util;
+use std::convert::TryFrom;
 use std::str::FromStr;
 use url::Url;
 
@@ -37,13 +43,26 @@ pub enum HttpError {
     CouldNotParseResponse,
     TooManyRedirect,
     Libcurl { code: i32, description: String },
+    FileWriteAccess { path: String },
+}
+
+impl From<curl::Error> for HttpError {
+    fn from(e: curl::Error) -> Self {
+        let code = e.code() as i32; // due to windows build
+        let description = match e.extra_description() {
+            None => e.description().to_string(),
+            Some(s) => s.to_string(),
+        };
+        HttpError::Libcurl { code, description }
+    }
 }
 
-#[derive(Debug)]
 pub struct Client {
     pub options: ClientOptions,
     pub handle: Box<easy::Easy>,
     pub redirect_count: usize,
+    /// Writes the verbose messages and the warnings of the client (to the standard error by default).
+    pub log_stderr: Rc<dyn Fn(&str)>,
     // unfortunately, follow-location feature from libcurl can not be used
     // libcurl returns a single list of headers for the 2 responses
     // hurl needs to keep everything
@@ -74,6 +93,7 @@ impl Client {
             options,
             handle: Box::new(h),
             redirect_count: 0,
+            log_stderr: Rc::new(|message| eprint!("{}", message)),
         }
     }
 
@@ -83,26 +103,44 @@ impl Client {
     pub fn execute_with_redirect(
         &mut self,
         request: &RequestSpec,
+    ) -> Result<Vec<(Request, Response)>, HttpError> {
+        // Credentials may be removed from the options for cross-origin redirects,
+        // the original options are restored once the redirect chain is done.
+        let options = self.options.clone();
+        let calls = self.follow_redirects(request);
+        self.options = options;
+        calls
+    }
+
+    fn follow_redirects(
+        &mut self,
+        request: &RequestSpec,
     ) -> Result<Vec<(Request, Response)>, HttpError> {
         let mut calls = vec![];
 
         let mut request_spec = request.clone();
+        let mut redirects = vec![];
         self.redirect_count = 0;
         loop {
-            let (request, response) = self.execute(&request_spec)?;
+            let (request, mut response) = self.execute(&request_spec)?;
+            response.redirects = redirects.clone();
             calls.push((request, response.clone()));
-            if let Some(url) = self.get_follow_location(response.clone()) {
-                request_spec = RequestSpec {
-                    method: Method::Get,
+            if let Some(url) = self.get_follow_location(response.clone(), &request_spec.url) {
+                redirects.push(Redirect {
+                    url: response.url.clone(),
+                    status: response.status,
+                });
+                let same_origin = is_same_origin(&request_spec.url, &url);
+                if !same_origin && !self.options.follow_location_trusted {
+                    self.options.user = None;
+                }
+                request_spec = redirect_request_spec(
+                    &request_spec,
+                    response.status,
                     url,
-                    headers: vec![],
-                    querystring: vec![],
-                    form: vec![],
-                    multipart: vec![],
-                    cookies: vec![],
-                    body: Body::Binary(vec![]),
-                    content_type: None,
-                };
+                    same_origin,
+                    self.options.follow_location_trusted,
+                );
 
                 self.redirect_count += 1;
                 if let Some(max_redirect) = self.options.max_redirect {
@@ -128,12 +166,33 @@ impl Client {
         // to capture HTTP request headers in libcurl `debug_function`. That's the only
         // way to get access to the outgoing headers.
         self.handle.verbose(true).unwrap();
+        // Activate the collection of the certificate chain for TLS transfers.
+        self.handle.certinfo(true).unwrap();
         self.handle.ssl_verify_host(!self.options.insecure).unwrap();
         self.handle.ssl_verify_peer(!self.options.insecure).unwrap();
         if let Some(cacert_file) = self.options.cacert_file.clone() {
             self.handle.cainfo(cacert_file).unwrap();
             self.handle.ssl_cert_type("PEM").unwrap();
         }
+        if let Some(client_cert_file) = self.options.client_cert_file.clone() {
+            self.handle.ssl_cert(client_cert_file).unwrap();
+        }
+        if let Some(client_key_file) = self.options.client_key_file.clone() {
+            self.handle.ssl_key(client_key_file).unwrap();
+        }
+        if let Some(key_password) = self.options.key_password.clone() {
+            self.handle.key_password(key_password.as_str()).unwrap();
+        }
+        if let Some(http_version) = self.options.http_version {
+            let http_version = match http_version {
+                HttpVersion::Http10 => easy::HttpVersion::V10,
+                HttpVersion::Http11 => easy::HttpVersion::V11,
+                HttpVersion::Http2 => easy::HttpVersion::V2,
+                HttpVersion::Http2PriorKnowledge => easy::HttpVersion::V2PriorKnowledge,
+            };
+            // fails if libcurl has been built without HTTP/2 support
+            self.handle.http_version(http_version)?;
+        }
 
         if let Some(proxy) = self.options.proxy.clone() {
             self.handle.proxy(proxy.as_str()).unwrap();
@@ -141,6 +200,20 @@ impl Client {
         if let Some(s) = self.options.no_proxy.clone() {
             self.handle.noproxy(s.as_str()).unwrap();
         }
+        if !self.options.resolves.is_empty() {
+            let mut list = easy::List::new();
+            for resolve in self.options.resolves.iter() {
+                list.append(resolve).unwrap();
+            }
+            self.handle.resolve(list).unwrap();
+        }
+        if !self.options.connects_to.is_empty() {
+            let mut list = easy::List::new();
+            for connect_to in self.options.connects_to.iter() {
+                list.append(connect_to).unwrap();
+            }
+            self.handle.connect_to(list).unwrap();
+        }
         self.handle.timeout(self.options.timeout).unwrap();
         self.handle
             .connect_timeout(self.options.connect_timeout)
@@ -161,13 +234,27 @@ impl Client {
         self.set_headers(request);
 
         let verbose = self.options.verbose;
+        let secrets = self.options.secrets.clone();
+        let log_stderr = self.log_stderr.clone();
         let mut request_headers: Vec<Header> = vec![];
 
         let start = Instant::now();
         let mut status_lines = vec![];
         let mut headers = vec![];
         let mut body = Vec::<u8>::new();
-        {
+        // The body is streamed to the output file, if any, instead of being kept in memory.
+        let mut output = match self.options.output.clone() {
+            None => None,
+            Some(path) => match File::create(&path) {
+                Ok(file) => Some((path, file)),
+                Err(_) => return Err(HttpError::FileWriteAccess { path }),
+            },
+        };
+        let mut body_size = 0;
+        let mut body_sha256 = sha2::Sha256::new();
+        let mut body_md5 = md5::Context::new();
+        let mut output_write_error = false;
+        let result = {
             let mut transfer = self.handle.transfer();
             if !data.is_empty() {
                 transfer
@@ -181,7 +268,10 @@ impl Client {
                         let mut lines = split_lines(data);
                         if verbose {
                             for line in lines.clone() {
-                                eprintln!("> {}", line);
+                                log_stderr(
+                                    format!("> {}\n", util::redact(line.as_str(), &secrets))
+                                        .as_str(),
+                                );
                             }
                         }
 
@@ -196,7 +286,9 @@ impl Client {
                     easy::InfoType::HeaderIn => {
                         if let Some(s) = decode_header(data) {
                             if verbose {
-                                eprint!("< {}", s);
+                                log_stderr(
+                                    format!("< {}", util::redact(s.as_str(), &secrets)).as_str(),
+                                );
                             }
                         }
                     }
@@ -218,19 +310,30 @@ impl Client {
 
             transfer
                 .write_function(|data| {
-                    body.extend(data);
+                    if let Some((_, file)) = output.as_mut() {
+                        // a short write aborts the transfer with a libcurl write error
+                        if file.write_all(data).is_err() {
+                            output_write_error = true;
+                            return Ok(0);
+                        }
+                        body_size += data.len();
+                        body_sha256.update(data);
+                        body_md5.consume(data);
+                    } else {
+                        body.extend(data);
+                    }
                     Ok(data.len())
                 })
                 .unwrap();
 
-            if let Err(e) = transfer.perform() {
-                let code = e.code() as i32; // due to windows build
-                let description = match e.extra_description() {
-                    None => e.description().to_string(),
-                    Some(s) => s.to_string(),
-                };
-                return Err(HttpError::Libcurl { code, description });
-            }
+            transfer.perform()
+        };
+        if let Err(e) = result {
+            // the libcurl write error of an aborted transfer is reported as an output file error
+            return match output {
+                Some((path, _)) if output_write_error => Err(HttpError::FileWriteAccess { path }),
+                _ => Err(e.into()),
+            };
         }
 
         let status = self.handle.response_code().unwrap();
@@ -240,6 +343,28 @@ impl Client {
         };
         let headers = self.parse_response_headers(&headers);
         let duration = start.elapsed();
+        let timings = Timings {
+            name_lookup: self.handle.namelookup_time().unwrap(),
+            connect: self.handle.connect_time().unwrap(),
+            app_connect: self.handle.appconnect_time().unwrap(),
+            pre_transfer: self.handle.pretransfer_time().unwrap(),
+            start_transfer: self.handle.starttransfer_time().unwrap(),
+            total: self.handle.total_time().unwrap(),
+        };
+        let effective_url = self.handle.effective_url().unwrap().map(|s| s.to_string());
+        let primary_ip = self.handle.primary_ip().unwrap().map(|s| s.to_string());
+        let primary_port = self.handle.primary_port().unwrap();
+        let local_port = self.handle.local_port().unwrap();
+        let certificate = match self.get_certinfo() {
+            None => None,
+            Some(attributes) => Certificate::try_from(attributes).ok(),
+        };
+        let body_file = output.map(|(path, _)| BodyFile {
+            path,
+            size: body_size,
+            sha256: body_sha256.finalize().to_vec(),
+            md5: body_md5.compute().to_vec(),
+        });
         self.handle.reset();
 
         let request = Request {
@@ -253,6 +378,14 @@ impl Client {
             headers,
             body,
             duration,
+            timings,
+            url: effective_url.unwrap_or_else(|| request.url.clone()),
+            primary_ip,
+            primary_port,
+            local_port,
+            certificate,
+            body_file,
+            redirects: vec![],
         };
         Ok((request, response))
     }
@@ -384,6 +517,7 @@ impl Client {
                         filename,
                         data,
                         content_type,
+                        ..
                     }) => form
                         .part(name)
                         .buffer(filename, data.clone())
@@ -455,7 +589,7 @@ impl Client {
     /// 2. a 3xx response code
     /// 3. a header Location
     ///
-    fn get_follow_location(&mut self, response: Response) -> Option<String> {
+    fn get_follow_location(&mut self, response: Response, base_url: &str) -> Option<String> {
         if !self.options.follow_location {
             return None;
         }
@@ -469,9 +603,40 @@ impl Client {
         };
 
         if location.is_empty() {
-            None
-        } else {
-            Some(location)
+            return None;
+        }
+        // relative locations are resolved against the current request URL
+        match Url::parse(base_url).and_then(|url| url.join(location.as_str())) {
+            Ok(url) => Some(url.to_string()),
+            Err(_) => Some(location),
+        }
+    }
+
+    ///
+    /// Returns the attributes (`Name:Value` strings) of the peer certificate
+    /// collected by libcurl for the last transfer, if any.
+    ///
+    fn get_certinfo(&mut self) -> Option<Vec<String>> {
+        // The curl crate does not expose CURLINFO_CERTINFO, we have to use the raw handle.
+        let mut certinfo = std::ptr::null_mut::<curl_sys::curl_certinfo>();
+        unsafe {
+            let rc = curl_sys::curl_easy_getinfo(
+                self.handle.raw(),
+                curl_sys::CURLINFO_CERTINFO,
+                &mut certinfo,
+            );
+            if rc != curl_sys::CURLE_OK || certinfo.is_null() || (*certinfo).num_of_certs < 1 {
+                return None;
+            }
+            // The first certificate of the chain is the peer certificate.
+            let mut slist = *(*certinfo).certinfo;
+            let mut attributes = vec![];
+            while !slist.is_null() {
+                let data = std::ffi::CStr::from_ptr((*slist).data);
+                attributes.push(data.to_string_lossy().to_string());
+                slist = (*slist).next;
+            }
+            Some(attributes)
         }
     }
 
@@ -486,7 +651,9 @@ impl Client {
             if let Ok(cookie) = Cookie::from_str(line) {
                 cookies.push(cookie);
             } else {
-                eprintln!("warning: line <{}> can not be parsed as cookie", line);
+                (self.log_stderr)(
+                    format!("warning: line <{}> can not be parsed as cookie\n", line).as_str(),
+                );
             }
         }
         cookies
@@ -497,7 +664,13 @@ impl Client {
     ///
     pub fn add_cookie(&mut self, cookie: Cookie) {
         if self.options.verbose {
-            eprintln!("* add to cookie store: {}", cookie);
+            (self.log_stderr)(
+                format!(
+                    "* add to cookie store: {}\n",
+                    util::redact(cookie.to_string().as_str(), &self.options.secrets)
+                )
+                .as_str(),
+            );
         }
         self.handle
             .cookie_list(cookie.to_string().as_str())
@@ -509,7 +682,7 @@ impl Client {
     ///
     pub fn clear_cookie_storage(&mut self) {
         if self.options.verbose {
-            eprintln!("* clear cookie storage");
+            (self.log_stderr)("* clear cookie storage\n");
         }
         self.handle.cookie_list("ALL").unwrap();
     }
@@ -635,6 +808,86 @@ pub fn decode_header(data: &[u8]) -> Option<String> {
     }
 }
 
+/// Returns the method of the request following a redirect with this `status` code.
+///
+/// 307 and 308 redirects keep the original method, 303 redirects are followed
+/// with a GET (except for HEAD) and a POST is changed to a GET on 301 and 302 redirects.
+fn redirect_method(status: u32, method: &Method) -> Method {
+    match (status, method) {
+        (303, Method::Head) => Method::Head,
+        (303, _) => Method::Get,
+        (301 | 302, Method::Post) => Method::Get,
+        _ => method.clone(),
+    }
+}
+
+/// Returns true if the two URLs share the same scheme, host and port.
+fn is_same_origin(url1: &str, url2: &str) -> bool {
+    match (Url::parse(url1), Url::parse(url2)) {
+        (Ok(url1), Ok(url2)) => url1.origin() == url2.origin(),
+        _ => false,
+    }
+}
+
+/// Returns the request to send to `url` when following a redirect of `request_spec`.
+///
+/// The body is only kept if the method is kept. Custom headers and cookies are only
+/// sent to the same origin; with `location_trusted`, the `Authorization` header is
+/// also sent to other origins.
+fn redirect_request_spec(
+    request_spec: &RequestSpec,
+    status: u32,
+    url: String,
+    same_origin: bool,
+    location_trusted: bool,
+) -> RequestSpec {
+    let method = redirect_method(status, &request_spec.method);
+    let keep_body = method == request_spec.method;
+    let headers = request_spec
+        .headers
+        .iter()
+        .filter(|h| {
+            let name = h.name.to_lowercase();
+            if !keep_body && (name == "content-type" || name == "content-length") {
+                false
+            } else {
+                same_origin || (location_trusted && name == "authorization")
+            }
+        })
+        .cloned()
+        .collect();
+    let cookies = if same_origin {
+        request_spec.cookies.clone()
+    } else {
+        vec![]
+    };
+    if keep_body {
+        RequestSpec {
+            method,
+            url,
+            headers,
+            querystring: vec![],
+            form: request_spec.form.clone(),
+            multipart: request_spec.multipart.clone(),
+            cookies,
+            body: request_spec.body.clone(),
+            content_type: request_spec.content_type.clone(),
+        }
+    } else {
+        RequestSpec {
+            method,
+            url,
+            headers,
+            querystring: vec![],
+            form: vec![],
+            multipart: vec![],
+            cookies,
+            body: Body::Binary(vec![]),
+            content_type: None,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -698,4 +951,81 @@ mod tests {
         assert!(match_cookie(&cookie, "http://sub.example.com/toto"));
         assert!(!match_cookie(&cookie, "http://example.com/tata"));
     }
+
+    #[test]
+    fn test_redirect_method() {
+        assert_eq!(redirect_method(301, &Method::Post), Method::Get);
+        assert_eq!(redirect_method(302, &Method::Post), Method::Get);
+        assert_eq!(redirect_method(302, &Method::Put), Method::Put);
+        assert_eq!(redirect_method(303, &Method::Put), Method::Get);
+        assert_eq!(redirect_method(303, &Method::Head), Method::Head);
+        assert_eq!(redirect_method(307, &Method::Post), Method::Post);
+        assert_eq!(redirect_method(308, &Method::Delete), Method::Delete);
+    }
+
+    #[test]
+    fn test_is_same_origin() {
+        assert!(is_same_origin(
+            "http://localhost:8000/redirect",
+            "http://localhost:8000/redirected"
+        ));
+        assert!(is_same_origin(
+            "https://example.org/a",
+            "https://example.org:443/b"
+        ));
+        assert!(!is_same_origin(
+            "http://localhost:8000/redirect",
+            "http://127.0.0.1:8000/redirected"
+        ));
+        assert!(!is_same_origin(
+            "http://example.org/a",
+            "https://example.org/a"
+        ));
+    }
+
+    #[test]
+    fn test_redirect_request_spec() {
+        let request_spec = RequestSpec {
+            method: Method::Post,
+            url: "http://localhost:8000/redirect".to_string(),
+            headers: vec![
+                Header {
+                    name: "Authorization".to_string(),
+                    value: "Bearer token".to_string(),
+                },
+                Header {
+                    name: "Content-Type".to_string(),
+                    value: "application/json".to_string(),
+                },
+            ],
+            querystring: vec![],
+            form: vec![],
+            multipart: vec![],
+            cookies: vec![],
+            body: Body::Text("{}".to_string()),
+            content_type: Some("application/json".to_string()),
+        };
+
+        // 307 keeps method, body and headers on the same origin
+        let url = "http://localhost:8000/redirected".to_string();
+        let redirected = redirect_request_spec(&request_spec, 307, url.clone(), true, false);
+        assert_eq!(redirected.method, Method::Post);
+        assert_eq!(redirected.url, url);
+        assert_eq!(redirected.headers, request_spec.headers);
+        assert_eq!(redirected.body, Body::Text("{}".to_string()));
+
+        // 302 switches to GET without body
+        let redirected = redirect_request_spec(&request_spec, 302, url, true, false);
+        assert_eq!(redirected.method, Method::Get);
+        assert_eq!(redirected.body, Body::Binary(vec![]));
+        assert_eq!(redirected.content_type, None);
+        assert_eq!(redirected.headers, request_spec.headers[..1].to_vec());
+
+        // headers are not sent to another origin, unless trusted for credentials
+        let url = "http://127.0.0.1:8000/redirected".to_string();
+        let redirected = redirect_request_spec(&request_spec, 308, url.clone(), false, false);
+        assert!(redirected.headers.is_empty());
+        let redirected = redirect_request_spec(&request_spec, 308, url, false, true);
+        assert_eq!(redirected.headers, request_spec.headers[..1].to_vec());
+    }
 }
@@ -16,18 +16,20 @@
  *
  */
 
+pub use self::certificate::Certificate;
 pub use self::client::{Client, HttpError};
 pub use self::core::{Cookie, Header, Param, RequestCookie};
-pub use self::options::ClientOptions;
+pub use self::options::{ClientOptions, HttpVersion};
 pub use self::request::Request;
 #[cfg(test)]
 pub use self::request_spec::tests::*;
 pub use self::request_spec::{Body, FileParam, Method, MultipartParam, RequestSpec};
 #[cfg(test)]
 pub use self::response::tests::*;
-pub use self::response::{Response, Version};
+pub use self::response::{BodyFile, Redirect, Response, Timings, Version};
 pub use self::version::libcurl_version_info;
 
+mod certificate;
 mod client;
 mod core;
 mod options;
@@ -18,15 +18,26 @@
 use std::path::PathBuf;
 use std::time::Duration;
 
+use super::request_spec_curl_args::encode_shell_string;
+
 #[derive(Debug, Clone)]
 pub struct ClientOptions {
     pub cacert_file: Option<String>,
+    pub client_cert_file: Option<String>,
+    pub client_key_file: Option<String>,
+    pub key_password: Option<String>,
+    pub connects_to: Vec<String>,
     pub follow_location: bool,
+    pub follow_location_trusted: bool,
+    pub http_version: Option<HttpVersion>,
     pub max_redirect: Option<usize>,
     pub cookie_input_file: Option<String>,
     pub proxy: Option<String>,
     pub no_proxy: Option<String>,
+    pub output: Option<String>,
+    pub resolves: Vec<String>,
     pub verbose: bool,
+    pub secrets: Vec<String>,
     pub insecure: bool,
     pub timeout: Duration,
     pub connect_timeout: Duration,
@@ -36,16 +47,46 @@ pub struct ClientOptions {
     pub context_dir: PathBuf,
 }
 
+/// HTTP version requested to libcurl, the version being negotiated by libcurl otherwise.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HttpVersion {
+    Http10,
+    Http11,
+    Http2,
+    Http2PriorKnowledge,
+}
+
+impl HttpVersion {
+    /// Returns the curl command line option forcing this version.
+    pub fn curl_arg(&self) -> &str {
+        match self {
+            HttpVersion::Http10 => "--http1.0",
+            HttpVersion::Http11 => "--http1.1",
+            HttpVersion::Http2 => "--http2",
+            HttpVersion::Http2PriorKnowledge => "--http2-prior-knowledge",
+        }
+    }
+}
+
 impl Default for ClientOptions {
     fn default() -> Self {
         ClientOptions {
             cacert_file: None,
+            client_cert_file: None,
+            client_key_file: None,
+            key_password: None,
+            connects_to: vec![],
             follow_location: false,
+            follow_location_trusted: false,
+            http_version: None,
             max_redirect: Some(50),
             cookie_input_file: None,
             proxy: None,
             no_proxy: None,
+            output: None,
+            resolves: vec![],
             verbose: false,
+            secrets: vec![],
             insecure: false,
             timeout: Duration::from_secs(300),
             connect_timeout: Duration::from_secs(300),
@@ -66,6 +107,11 @@ impl ClientOptions {
             arguments.push(cacert_file.clone());
         }
 
+        if let Some(ref client_cert_file) = self.client_cert_file {
+            arguments.push("--cert".to_string());
+            arguments.push(client_cert_file.clone());
+        }
+
         if self.compressed {
             arguments.push("--compressed".to_string());
         }
@@ -74,16 +120,33 @@ impl ClientOptions {
             arguments.push("--connect-timeout".to_string());
             arguments.push(self.connect_timeout.as_secs().to_string());
         }
+        for connect_to in self.connects_to.iter() {
+            arguments.push("--connect-to".to_string());
+            arguments.push(connect_to.clone());
+        }
 
         if let Some(ref cookie_file) = self.cookie_input_file {
             arguments.push("--cookie".to_string());
             arguments.push(cookie_file.clone());
         }
 
+        if let Some(http_version) = self.http_version {
+            arguments.push(http_version.curl_arg().to_string());
+        }
         if self.insecure {
             arguments.push("--insecure".to_string());
         }
-        if self.follow_location {
+        if let Some(ref client_key_file) = self.client_key_file {
+            arguments.push("--key".to_string());
+            arguments.push(client_key_file.clone());
+        }
+        if let Some(ref key_password) = self.key_password {
+            arguments.push("--pass".to_string());
+            arguments.push(encode_shell_string(key_password));
+        }
+        if self.follow_location && self.follow_location_trusted {
+            arguments.push("--location-trusted".to_string());
+        } else if self.follow_location {
             arguments.push("-L".to_string());
         }
         if self.max_redirect != ClientOptions::default().max_redirect {
@@ -94,10 +157,18 @@ impl ClientOptions {
             arguments.push("--max-redirs".to_string());
             arguments.push(max_redirect.to_string());
         }
+        if let Some(ref output) = self.output {
+            arguments.push("--output".to_string());
+            arguments.push(output.clone());
+        }
         if let Some(ref proxy) = self.proxy {
             arguments.push("--proxy".to_string());
             arguments.push(format!("'{}'", proxy));
         }
+        for resolve in self.resolves.iter() {
+            arguments.push("--resolve".to_string());
+            arguments.push(resolve.clone());
+        }
         if self.timeout != ClientOptions::default().timeout {
             arguments.push("--timeout".to_string());
             arguments.push(self.timeout.as_secs().to_string());
@@ -125,12 +196,24 @@ mod tests {
         assert_eq!(
             ClientOptions {
                 cacert_file: None,
+                client_cert_file: Some("client.pem".to_string()),
+                client_key_file: Some("client.key".to_string()),
+                key_password: Some("pass'word".to_string()),
+                connects_to: vec!["example.com:443:host-47.example.com:443".to_string()],
                 follow_location: true,
+                follow_location_trusted: false,
+                http_version: Some(HttpVersion::Http2PriorKnowledge),
                 max_redirect: Some(10),
                 cookie_input_file: Some("cookie_file".to_string()),
                 proxy: Some("localhost:3128".to_string()),
                 no_proxy: None,
+                output: Some("data.bin".to_string()),
+                resolves: vec![
+                    "foo.com:80:192.168.0.1".to_string(),
+                    "bar.com:443:127.0.0.1".to_string()
+                ],
                 verbose: true,
+                secrets: vec![],
                 insecure: true,
                 timeout: Duration::from_secs(10),
                 connect_timeout: Duration::from_secs(20),
@@ -141,17 +224,32 @@ mod tests {
             }
             .curl_args(),
             [
+                "--cert".to_string(),
+                "client.pem".to_string(),
                 "--compressed".to_string(),
                 "--connect-timeout".to_string(),
                 "20".to_string(),
+                "--connect-to".to_string(),
+                "example.com:443:host-47.example.com:443".to_string(),
                 "--cookie".to_string(),
                 "cookie_file".to_string(),
+                "--http2-prior-knowledge".to_string(),
                 "--insecure".to_string(),
+                "--key".to_string(),
+                "client.key".to_string(),
+                "--pass".to_string(),
+                "$'pass\\'word'".to_string(),
                 "-L".to_string(),
                 "--max-redirs".to_string(),
                 "10".to_string(),
+                "--output".to_string(),
+                "data.bin".to_string(),
                 "--proxy".to_string(),
                 "'localhost:3128'".to_string(),
+                "--resolve".to_string(),
+                "foo.com:80:192.168.0.1".to_string(),
+                "--resolve".to_string(),
+                "bar.com:443:127.0.0.1".to_string(),
                 "--timeout".to_string(),
                 "10".to_string(),
                 "--user".to_string(),
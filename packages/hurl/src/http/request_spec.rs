@@ -58,6 +58,8 @@ pub struct FileParam {
     pub filename: String,
     pub data: Vec<u8>,
     pub content_type: String,
+    /// Content given in the Hurl file rather than read from `filename`
+    pub inline: bool,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
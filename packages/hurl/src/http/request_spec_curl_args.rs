@@ -93,7 +93,7 @@ impl RequestSpec {
         }
         for param in self.multipart.clone() {
             arguments.push("-F".to_string());
-            arguments.push(format!("'{}'", param.curl_arg(context_dir)));
+            arguments.push(param.curl_arg(context_dir));
         }
 
         if !self.body.bytes().is_empty() {
@@ -166,9 +166,24 @@ impl Param {
 }
 
 impl MultipartParam {
+    /// Returns the shell encoded argument of the `-F` option.
     pub fn curl_arg(&self, context_dir: &Path) -> String {
         match self {
-            MultipartParam::Param(param) => param.curl_arg(),
+            MultipartParam::Param(param) => format!("'{}'", param.curl_arg()),
+            // the inline content is encoded byte by byte, like a binary body
+            MultipartParam::FileParam(FileParam {
+                name,
+                filename,
+                data,
+                content_type,
+                inline: true,
+            }) => format!(
+                "$'{}={};filename={};type={}'",
+                escape_string(name),
+                encode_bytes(data.clone()),
+                escape_string(filename),
+                escape_string(content_type)
+            ),
             MultipartParam::FileParam(FileParam {
                 name,
                 filename,
@@ -178,7 +193,7 @@ impl MultipartParam {
                 let path = Path::new(&filename);
                 let path = context_dir.join(path);
                 let value = format!("@{};type={}", path.to_str().unwrap(), content_type);
-                format!("{}={}", name, value)
+                format!("'{}={}'", name, value)
             }
         }
     }
@@ -202,7 +217,8 @@ fn escape_url(s: String) -> String {
     percent_encoding::percent_encode(s.as_bytes(), percent_encoding::NON_ALPHANUMERIC).to_string()
 }
 
-fn encode_shell_string(s: &str) -> String {
+/// Encodes `s` as a shell string argument, in the `$'...'` form if it contains escaped sequences.
+pub fn encode_shell_string(s: &str) -> String {
     // $'...' form will be used to encode escaped sequence
     if escape_mode(s) {
         let escaped = escape_string(s);
@@ -294,6 +310,41 @@ pub mod tests {
         );
     }
 
+    #[test]
+    fn multipart_param_curl_arg() {
+        let context_dir = Path::new("/tmp");
+        assert_eq!(
+            MultipartParam::Param(Param {
+                name: "key1".to_string(),
+                value: "value1".to_string(),
+            })
+            .curl_arg(context_dir),
+            "'key1=value1'".to_string()
+        );
+        assert_eq!(
+            MultipartParam::FileParam(FileParam {
+                name: "upload1".to_string(),
+                filename: "data.txt".to_string(),
+                data: vec![],
+                content_type: "text/plain".to_string(),
+                inline: false,
+            })
+            .curl_arg(context_dir),
+            "'upload1=@/tmp/data.txt;type=text/plain'".to_string()
+        );
+        assert_eq!(
+            MultipartParam::FileParam(FileParam {
+                name: "upload2".to_string(),
+                filename: "it's.bin".to_string(),
+                data: vec![0, 255],
+                content_type: "application/octet-stream".to_string(),
+                inline: true,
+            })
+            .curl_arg(context_dir),
+            "$'upload2=\\x00\\xff;filename=it\\'s.bin;type=application/octet-stream'".to_string()
+        );
+    }
+
     #[test]
     fn param_curl_args() {
         assert_eq!(
@@ -19,6 +19,7 @@
 use core::fmt;
 use std::time::Duration;
 
+use super::certificate::Certificate;
 use super::core::*;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -28,6 +29,78 @@ pub struct Response {
     pub headers: Vec<Header>,
     pub body: Vec<u8>,
     pub duration: Duration,
+    pub timings: Timings,
+    /// Effective URL of the request that produced this response
+    pub url: String,
+    /// IP address of the most recent connection
+    pub primary_ip: Option<String>,
+    /// Destination port of the most recent connection
+    pub primary_port: u16,
+    /// Local port of the most recent connection
+    pub local_port: u16,
+    /// Peer certificate, for TLS transfers
+    pub certificate: Option<Certificate>,
+    /// Output file the body has been streamed to, the body being empty in this case
+    pub body_file: Option<BodyFile>,
+    /// Redirects followed before this response, in order
+    pub redirects: Vec<Redirect>,
+}
+
+impl Default for Response {
+    fn default() -> Self {
+        Response {
+            version: Version::Http11,
+            status: 200,
+            headers: vec![],
+            body: vec![],
+            duration: Default::default(),
+            timings: Default::default(),
+            url: "".to_string(),
+            primary_ip: None,
+            primary_port: 0,
+            local_port: 0,
+            certificate: None,
+            body_file: None,
+            redirects: vec![],
+        }
+    }
+}
+
+/// A redirect response followed by the client.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Redirect {
+    pub url: String,
+    pub status: u32,
+}
+
+/// A response body written to a file while being received.
+///
+/// Its size and digests are computed incrementally so that large bodies
+/// can be checked without being held in memory.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BodyFile {
+    pub path: String,
+    pub size: usize,
+    pub sha256: Vec<u8>,
+    pub md5: Vec<u8>,
+}
+
+/// Durations of the phases of a transfer, from the start of the transfer,
+/// as reported by libcurl (`CURLINFO_*_TIME`).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Timings {
+    /// Name resolving completed
+    pub name_lookup: Duration,
+    /// Connection to the remote host (or proxy) completed
+    pub connect: Duration,
+    /// SSL/TLS handshake completed
+    pub app_connect: Duration,
+    /// Transfer just about to begin
+    pub pre_transfer: Duration,
+    /// First byte received
+    pub start_transfer: Duration,
+    /// Whole transfer
+    pub total: Duration,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -100,6 +173,7 @@ pub mod tests {
             ],
             body: String::into_bytes(String::from("Hello World!")),
             duration: Default::default(),
+            ..Default::default()
         }
     }
 
@@ -115,6 +189,7 @@ pub mod tests {
                 "<html><head><meta charset=\"UTF-8\"></head><body><br></body></html>",
             )),
             duration: Default::default(),
+            ..Default::default()
         }
     }
 
@@ -139,6 +214,7 @@ xxx
                 .to_string(),
             ),
             duration: Default::default(),
+            ..Default::default()
         }
     }
 
@@ -167,6 +243,7 @@ xxx
                 .to_string(),
             ),
             duration: Default::default(),
+            ..Default::default()
         }
     }
 
@@ -196,6 +273,7 @@ xxx
                 .to_string(),
             ),
             duration: Default::default(),
+            ..Default::default()
         }
     }
 
@@ -218,6 +296,7 @@ xxx
                 .to_string(),
             ),
             duration: Default::default(),
+            ..Default::default()
         }
     }
 
@@ -237,6 +316,7 @@ xxx
             ],
             body: vec![255],
             duration: Default::default(),
+            ..Default::default()
         }
     }
 
@@ -251,6 +331,7 @@ xxx
             }],
             body: vec![],
             duration: Default::default(),
+            ..Default::default()
         };
         assert_eq!(
             response.get_header_values("Content-Length".to_string()),
@@ -16,7 +16,7 @@
  *
  */
 
-use crate::http::{Cookie, Header, Param, Request, RequestCookie, Response, Version};
+use crate::http::{Cookie, Header, Param, Request, RequestCookie, Response, Timings, Version};
 use crate::runner::{AssertResult, CaptureResult, EntryResult, HurlResult, ResponseCookie};
 
 impl HurlResult {
@@ -67,6 +67,10 @@ impl EntryResult {
             "time".to_string(),
             serde_json::Value::Number(serde_json::Number::from(self.time_in_ms as u64)),
         );
+        map.insert(
+            "attempts".to_string(),
+            serde_json::Value::Number(serde_json::Number::from(self.attempts)),
+        );
         serde_json::Value::Object(map)
     }
 }
@@ -122,6 +126,46 @@ impl Response {
             .map(|e| e.clone().to_json())
             .collect();
         map.insert("cookies".to_string(), serde_json::Value::Array(cookies));
+        map.insert("timings".to_string(), self.timings.to_json());
+        map.insert(
+            "url".to_string(),
+            serde_json::Value::String(self.url.clone()),
+        );
+        if let Some(primary_ip) = &self.primary_ip {
+            map.insert(
+                "primaryIp".to_string(),
+                serde_json::Value::String(primary_ip.clone()),
+            );
+        }
+        map.insert(
+            "primaryPort".to_string(),
+            serde_json::Value::Number(serde_json::Number::from(self.primary_port)),
+        );
+        map.insert(
+            "localPort".to_string(),
+            serde_json::Value::Number(serde_json::Number::from(self.local_port)),
+        );
+        serde_json::Value::Object(map)
+    }
+}
+
+impl Timings {
+    /// Serializes the timings in milliseconds, the unit of the `time` of an entry and of the `timing` query.
+    fn to_json(&self) -> serde_json::Value {
+        let mut map = serde_json::Map::new();
+        for (name, duration) in [
+            ("namelookup", self.name_lookup),
+            ("connect", self.connect),
+            ("appconnect", self.app_connect),
+            ("pretransfer", self.pre_transfer),
+            ("starttransfer", self.start_transfer),
+            ("total", self.total),
+        ] {
+            map.insert(
+                name.to_string(),
+                serde_json::Value::Number(serde_json::Number::from(duration.as_millis() as u64)),
+            );
+        }
         serde_json::Value::Object(map)
     }
 }
@@ -50,6 +50,7 @@ impl Value {
                 let encoded = base64::encode(v);
                 serde_json::Value::String(encoded)
             }
+            Value::Date(_) => serde_json::Value::String(self.to_string()),
             Value::Null => serde_json::Value::Null,
             Value::Regex(value) => serde_json::Value::String(value.to_string()),
             Value::Unit => todo!("how to serialize that in json?"),
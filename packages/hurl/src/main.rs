@@ -16,21 +16,27 @@
  *
  */
 
+use std::collections::HashMap;
 use std::io::prelude::*;
 use std::io::{self};
 use std::path::{Path, PathBuf};
+use std::rc::Rc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{mpsc, Arc};
+use std::thread;
 use std::time::Instant;
 
 use atty::Stream;
 use colored::*;
 
 use hurl::cli;
-use hurl::cli::{CliError, CliOptions, OutputType};
+use hurl::cli::{CliError, CliOptions, OutputType, Stderr};
 use hurl::http;
 use hurl::report;
 use hurl::runner;
 use hurl::runner::{HurlResult, RunnerOptions};
-use hurl_core::ast::{Pos, SourceInfo};
+use hurl::util;
+use hurl_core::ast::{HurlFile, Pos, SourceInfo};
 use hurl_core::error::Error;
 use hurl_core::parser;
 
@@ -81,151 +87,218 @@ struct Progress {
     pub total: usize,
 }
 
+/// Parses and runs a Hurl file.
+///
+/// Returns `None` if the file can not be parsed, the parsing error being written to `stderr`.
 fn execute(
     filename: &str,
     contents: String,
     current_dir: &Path,
     cli_options: CliOptions,
-    log_verbose: &impl Fn(&str),
-    log_error_message: &impl Fn(bool, &str),
     progress: Option<Progress>,
-) -> HurlResult {
+    stderr: &Stderr,
+) -> Option<HurlResult> {
     let lines: Vec<String> = regex::Regex::new(r"\n|\r\n")
         .unwrap()
         .split(&contents)
         .map(|l| l.to_string())
         .collect();
-    let optional_filename = if filename.is_empty() {
-        None
-    } else {
-        Some(filename.to_string())
-    };
 
     if let Some(Progress { current, total }) = progress {
-        eprintln!("{}: RUNNING [{}/{}]", filename, current + 1, total);
+        stderr.eprintln(format!("{}: RUNNING [{}/{}]", filename, current + 1, total).as_str());
     }
-    let log_parser_error =
-        cli::make_logger_parser_error(lines.clone(), cli_options.color, optional_filename.clone());
-    let log_runner_error =
-        cli::make_logger_runner_error(lines, cli_options.color, optional_filename);
+    let hurl_file = parse_hurl_file(filename, &contents, &lines, cli_options.color, stderr)?;
+    let color = cli_options.color;
+    let progress = cli_options.progress;
+    let result = run_hurl_file(hurl_file, filename, lines, current_dir, cli_options, stderr);
+    if progress {
+        let status = match (result.success, color) {
+            (true, true) => "SUCCESS".green().to_string(),
+            (true, false) => "SUCCESS".to_string(),
+            (false, true) => "FAILURE".red().to_string(),
+            (false, false) => "FAILURE".to_string(),
+        };
+        stderr.eprintln(format!("{}: {}", filename, status).as_str());
+    }
+    Some(result)
+}
 
-    match parser::parse_hurl_file(contents.as_str()) {
+/// Parses a Hurl file, writing the parsing error, if any, to `stderr`.
+fn parse_hurl_file(
+    filename: &str,
+    contents: &str,
+    lines: &[String],
+    color: bool,
+    stderr: &Stderr,
+) -> Option<HurlFile> {
+    match parser::parse_hurl_file(contents) {
+        Ok(hurl_file) => Some(hurl_file),
         Err(e) => {
+            let log_parser_error = cli::make_logger_parser_error(
+                lines.to_vec(),
+                color,
+                optional_filename(filename),
+                stderr.clone(),
+            );
             log_parser_error(&e, false);
-            std::process::exit(EXIT_ERROR_PARSING);
+            None
         }
-        Ok(hurl_file) => {
-            log_verbose(format!("fail fast: {}", cli_options.fail_fast).as_str());
-            log_verbose(format!("insecure: {}", cli_options.insecure).as_str());
-            log_verbose(format!("follow redirect: {}", cli_options.follow_location).as_str());
-            if let Some(n) = cli_options.max_redirect {
-                log_verbose(format!("max redirect: {}", n).as_str());
-            }
-            if let Some(proxy) = cli_options.proxy.clone() {
-                log_verbose(format!("proxy: {}", proxy).as_str());
-            }
+    }
+}
 
-            if !cli_options.variables.is_empty() {
-                log_verbose("variables:");
-                for (name, value) in cli_options.variables.clone() {
-                    log_verbose(format!("    {}={}", name, value).as_str());
-                }
-            }
+/// Runs a parsed Hurl file with its own HTTP client.
+fn run_hurl_file(
+    hurl_file: HurlFile,
+    filename: &str,
+    lines: Vec<String>,
+    current_dir: &Path,
+    cli_options: CliOptions,
+    stderr: &Stderr,
+) -> HurlResult {
+    let log_verbose = cli::make_logger_verbose(
+        cli_options.verbose,
+        cli_options.secrets.clone(),
+        stderr.clone(),
+    );
+    let log_error_message = cli::make_logger_error_message(
+        cli_options.color,
+        cli_options.secrets.clone(),
+        stderr.clone(),
+    );
+    let log_runner_error = cli::make_logger_runner_error(
+        lines,
+        cli_options.color,
+        optional_filename(filename),
+        cli_options.secrets.clone(),
+        stderr.clone(),
+    );
 
-            if let Some(to_entry) = cli_options.to_entry {
-                if to_entry < hurl_file.entries.len() {
-                    log_verbose(
-                        format!("executing {}/{} entries", to_entry, hurl_file.entries.len())
-                            .as_str(),
-                    );
-                } else {
-                    log_verbose("executing all entries");
-                }
-            }
+    log_verbose(format!("fail fast: {}", cli_options.fail_fast).as_str());
+    log_verbose(format!("insecure: {}", cli_options.insecure).as_str());
+    log_verbose(format!("follow redirect: {}", cli_options.follow_location).as_str());
+    if let Some(n) = cli_options.max_redirect {
+        log_verbose(format!("max redirect: {}", n).as_str());
+    }
+    if let Some(proxy) = cli_options.proxy.clone() {
+        log_verbose(format!("proxy: {}", proxy).as_str());
+    }
 
-            let cacert_file = cli_options.cacert_file;
-            let follow_location = cli_options.follow_location;
-            let verbose = cli_options.verbose;
-            let insecure = cli_options.insecure;
-            let max_redirect = cli_options.max_redirect;
-            let proxy = cli_options.proxy;
-            let no_proxy = cli_options.no_proxy;
-            let cookie_input_file = cli_options.cookie_input_file;
-
-            let timeout = cli_options.timeout;
-            let connect_timeout = cli_options.connect_timeout;
-            let user = cli_options.user;
-            let user_agent = cli_options.user_agent;
-            let compressed = cli_options.compressed;
-            let context_dir = match cli_options.file_root {
-                None => {
-                    if filename == "-" {
-                        current_dir
-                    } else {
-                        let path = Path::new(filename);
-                        path.parent().unwrap()
-                    }
-                }
-                Some(ref filename) => Path::new(filename),
-            };
-            let options = http::ClientOptions {
-                cacert_file,
-                follow_location,
-                max_redirect,
-                cookie_input_file,
-                proxy,
-                no_proxy,
-                verbose,
-                insecure,
-                timeout,
-                connect_timeout,
-                user,
-                user_agent,
-                compressed,
-                context_dir: context_dir.to_path_buf(),
-            };
+    if !cli_options.variables.is_empty() {
+        log_verbose("variables:");
+        for (name, value) in cli_options.variables.clone() {
+            log_verbose(format!("    {}={}", name, value).as_str());
+        }
+    }
 
-            let mut client = http::Client::init(options);
+    if let Some(to_entry) = cli_options.to_entry {
+        if to_entry < hurl_file.entries.len() {
+            log_verbose(
+                format!("executing {}/{} entries", to_entry, hurl_file.entries.len()).as_str(),
+            );
+        } else {
+            log_verbose("executing all entries");
+        }
+    }
 
-            let pre_entry = if cli_options.interactive {
-                cli::interactive::pre_entry
-            } else {
-                |_| false
-            };
-            let post_entry = if cli_options.interactive {
-                cli::interactive::post_entry
+    let cacert_file = cli_options.cacert_file;
+    let client_cert_file = cli_options.client_cert_file;
+    let client_key_file = cli_options.client_key_file;
+    let key_password = cli_options.key_password;
+    let connects_to = cli_options.connects_to;
+    let follow_location = cli_options.follow_location;
+    let follow_location_trusted = cli_options.follow_location_trusted;
+    let http_version = cli_options.http_version;
+    let verbose = cli_options.verbose;
+    let insecure = cli_options.insecure;
+    let max_redirect = cli_options.max_redirect;
+    let proxy = cli_options.proxy;
+    let no_proxy = cli_options.no_proxy;
+    let resolves = cli_options.resolves;
+    let cookie_input_file = cli_options.cookie_input_file;
+
+    let timeout = cli_options.timeout;
+    let connect_timeout = cli_options.connect_timeout;
+    let user = cli_options.user;
+    let user_agent = cli_options.user_agent;
+    let compressed = cli_options.compressed;
+    let context_dir = match cli_options.file_root {
+        None => {
+            if filename == "-" {
+                current_dir
             } else {
-                || false
-            };
-            let options = RunnerOptions {
-                fail_fast: cli_options.fail_fast,
-                variables: cli_options.variables,
-                to_entry: cli_options.to_entry,
-                context_dir: context_dir.to_path_buf(),
-                ignore_asserts: cli_options.ignore_asserts,
-                pre_entry,
-                post_entry,
-            };
-            let result = runner::run_hurl_file(
-                hurl_file,
-                &mut client,
-                filename.to_string(),
-                &options,
-                &log_verbose,
-                &log_error_message,
-                &log_runner_error,
-            );
-            if cli_options.progress {
-                let status = match (result.success, cli_options.color) {
-                    (true, true) => "SUCCESS".green().to_string(),
-                    (true, false) => "SUCCESS".to_string(),
-                    (false, true) => "FAILURE".red().to_string(),
-                    (false, false) => "FAILURE".to_string(),
-                };
-                eprintln!("{}: {}", filename, status);
+                let path = Path::new(filename);
+                path.parent().unwrap()
             }
-            result
         }
+        Some(ref filename) => Path::new(filename),
+    };
+    let options = http::ClientOptions {
+        cacert_file,
+        client_cert_file,
+        client_key_file,
+        key_password,
+        connects_to,
+        follow_location,
+        follow_location_trusted,
+        http_version,
+        max_redirect,
+        cookie_input_file,
+        proxy,
+        no_proxy,
+        output: None,
+        resolves,
+        verbose,
+        secrets: cli_options.secrets.clone(),
+        insecure,
+        timeout,
+        connect_timeout,
+        user,
+        user_agent,
+        compressed,
+        context_dir: context_dir.to_path_buf(),
+    };
+
+    let mut client = http::Client::init(options);
+    let client_stderr = stderr.clone();
+    client.log_stderr = Rc::new(move |message| client_stderr.eprint(message));
+
+    let pre_entry = if cli_options.interactive {
+        cli::interactive::pre_entry
+    } else {
+        |_| false
+    };
+    let post_entry = if cli_options.interactive {
+        cli::interactive::post_entry
+    } else {
+        || false
+    };
+    let options = RunnerOptions {
+        fail_fast: cli_options.fail_fast,
+        variables: cli_options.variables,
+        to_entry: cli_options.to_entry,
+        context_dir: context_dir.to_path_buf(),
+        ignore_asserts: cli_options.ignore_asserts,
+        retry: cli_options.retry,
+        retry_interval: cli_options.retry_interval,
+        pre_entry,
+        post_entry,
+    };
+    runner::run_hurl_file(
+        hurl_file,
+        &mut client,
+        filename.to_string(),
+        &options,
+        &log_verbose,
+        &log_error_message,
+        &log_runner_error,
+    )
+}
+
+fn optional_filename(filename: &str) -> Option<String> {
+    if filename.is_empty() {
+        None
+    } else {
+        Some(filename.to_string())
     }
 }
 
@@ -253,10 +326,13 @@ fn main() {
     init_colored();
 
     let verbose = matches.is_present("verbose") || matches.is_present("interactive");
-    let log_verbose = cli::make_logger_verbose(verbose);
     let color = cli::output_color(matches.clone());
-    let log_error_message = cli::make_logger_error_message(color);
+    let log_error_message = cli::make_logger_error_message(color, vec![], Stderr::default());
     let cli_options = unwrap_or_exit(&log_error_message, cli::parse_options(matches.clone()));
+    let log_verbose =
+        cli::make_logger_verbose(verbose, cli_options.secrets.clone(), Stderr::default());
+    let log_error_message =
+        cli::make_logger_error_message(color, cli_options.secrets.clone(), Stderr::default());
 
     let mut filenames = vec![];
     if let Some(values) = matches.values_of("INPUT") {
@@ -281,6 +357,29 @@ fn main() {
     let current_dir_buf = std::env::current_dir().unwrap();
     let current_dir = current_dir_buf.as_path();
 
+    if cli_options.repeat.is_some() || cli_options.duration.is_some() {
+        let mut failed_results = vec![];
+        for filename in &filenames {
+            let contents = match cli::read_to_string(filename) {
+                Ok(v) => v,
+                Err(e) => {
+                    log_error_message(false, e.message.as_str());
+                    std::process::exit(EXIT_ERROR_PARSING);
+                }
+            };
+            log_verbose(format!("Running load test for {}", filename).as_str());
+            let start = Instant::now();
+            let mut report = report::LoadReport::default();
+            if let Some(hurl_result) =
+                execute_load(filename, contents, current_dir, &cli_options, &mut report)
+            {
+                failed_results.push(hurl_result);
+            }
+            eprint!("{}", report.summary(filename, start.elapsed()));
+        }
+        std::process::exit(exit_code(failed_results));
+    }
+
     let mut hurl_results = vec![];
 
     let cookies_output_file = match cli_options.cookie_output_file.clone() {
@@ -296,115 +395,59 @@ fn main() {
 
     let start = Instant::now();
     let mut testcases = vec![];
+    // Exit code of an error stopping the run (file not readable or not parsable),
+    // the reports of the files already run being written before exiting.
+    let mut exit_error = None;
 
-    for (current, filename) in filenames.iter().enumerate() {
-        let contents = match cli::read_to_string(filename) {
-            Ok(v) => v,
-            Err(e) => {
-                log_error_message(false, e.message.as_str());
-                std::process::exit(EXIT_ERROR_PARSING);
-            }
-        };
-
-        let progress = if cli_options.progress {
-            Some(Progress {
-                current,
-                total: filenames.len(),
-            })
-        } else {
-            None
-        };
-        let hurl_result = execute(
+    let mut handle_result = |filename: &str, contents: String, hurl_result: HurlResult| {
+        if let Some(testcase) = output_hurl_result(
             filename,
-            contents.clone(),
-            current_dir,
-            cli_options.clone(),
-            &log_verbose,
+            &contents,
+            &hurl_result,
+            &cli_options,
             &log_error_message,
-            progress,
-        );
-        hurl_results.push(hurl_result.clone());
-
-        if matches!(cli_options.output_type, OutputType::ResponseBody)
-            && hurl_result.errors().is_empty()
-            && !cli_options.interactive
-        {
-            // default
-            // last entry + response + body
-            if let Some(entry_result) = hurl_result.entries.last() {
-                if let Some(response) = entry_result.response.clone() {
-                    let mut output = vec![];
-                    if cli_options.include {
-                        let status_line =
-                            format!("HTTP/{} {}\n", response.version, response.status);
-                        output.append(&mut status_line.into_bytes());
-                        for header in response.headers.clone() {
-                            let header_line = format!("{}: {}\n", header.name, header.value);
-                            output.append(&mut header_line.into_bytes());
-                        }
-                        output.append(&mut "\n".to_string().into_bytes());
+        ) {
+            testcases.push(testcase);
+        }
+        hurl_results.push(hurl_result);
+    };
+
+    match cli_options.jobs {
+        None => {
+            for (current, filename) in filenames.iter().enumerate() {
+                let contents = match cli::read_to_string(filename) {
+                    Ok(v) => v,
+                    Err(e) => {
+                        log_error_message(false, e.message.as_str());
+                        exit_error = Some(EXIT_ERROR_PARSING);
+                        break;
                     }
-                    let body = if cli_options.compressed {
-                        match response.uncompress_body() {
-                            Ok(bytes) => bytes,
-                            Err(e) => {
-                                log_error_message(
-                                    false,
-                                    runner::Error {
-                                        source_info: SourceInfo {
-                                            start: Pos { line: 0, column: 0 },
-                                            end: Pos { line: 0, column: 0 },
-                                        },
-                                        inner: e,
-                                        assert: false,
-                                    }
-                                    .fixme()
-                                    .as_str(),
-                                );
-                                std::process::exit(EXIT_ERROR_RUNTIME);
-                            }
-                        }
-                    } else {
-                        response.body
-                    };
-                    output.append(&mut body.clone());
-                    unwrap_or_exit(
-                        &log_error_message,
-                        write_output(output, cli_options.output.clone()),
-                    );
-                } else {
-                    cli::log_info("no response has been received");
-                }
-            } else {
-                let source = if filename.as_str() == "-" {
-                    "".to_string()
-                } else {
-                    format!("for file {}", filename).to_string()
                 };
-                log_error_message(
-                    true,
-                    format!("no entry have been executed {}", source).as_str(),
-                );
-            };
+                match execute(
+                    filename,
+                    contents.clone(),
+                    current_dir,
+                    cli_options.clone(),
+                    progress(&cli_options, current, filenames.len()),
+                    &Stderr::default(),
+                ) {
+                    Some(hurl_result) => handle_result(filename, contents, hurl_result),
+                    None => {
+                        exit_error = Some(EXIT_ERROR_PARSING);
+                        break;
+                    }
+                }
+            }
         }
-
-        let lines: Vec<String> = regex::Regex::new(r"\n|\r\n")
-            .unwrap()
-            .split(&contents)
-            .map(|l| l.to_string())
-            .collect();
-        if matches!(cli_options.output_type, OutputType::Json) {
-            let json_result = hurl_result.to_json(&lines);
-            let serialized = serde_json::to_string(&json_result).unwrap();
-            let s = format!("{}\n", serialized);
-            unwrap_or_exit(
+        Some(jobs) => {
+            exit_error = execute_parallel(
+                &filenames,
+                current_dir,
+                &cli_options,
+                jobs,
                 &log_error_message,
-                write_output(s.into_bytes(), cli_options.output.clone()),
-            );
-        }
-        if cli_options.junit_file.is_some() {
-            let testcase = report::Testcase::from_hurl_result(&hurl_result, &lines);
-            testcases.push(testcase);
+                handle_result,
+            )
         }
     }
 
@@ -445,7 +488,269 @@ fn main() {
         eprintln!("{}", summary.as_str());
     }
 
-    std::process::exit(exit_code(hurl_results));
+    std::process::exit(exit_error.unwrap_or_else(|| exit_code(hurl_results)));
+}
+
+/// Writes the output of a Hurl file run: the last response body or the JSON result.
+/// Returns the JUnit testcase of the run when a JUnit report is requested.
+fn output_hurl_result(
+    filename: &str,
+    contents: &str,
+    hurl_result: &HurlResult,
+    cli_options: &CliOptions,
+    log_error_message: &impl Fn(bool, &str),
+) -> Option<report::Testcase> {
+    if matches!(cli_options.output_type, OutputType::ResponseBody)
+        && hurl_result.errors().is_empty()
+        && !cli_options.interactive
+    {
+        // default
+        // last entry + response + body
+        if let Some(entry_result) = hurl_result.entries.last() {
+            if let Some(response) = entry_result.response.clone() {
+                let mut output = vec![];
+                if cli_options.include {
+                    let status_line = format!("HTTP/{} {}\n", response.version, response.status);
+                    output.append(&mut status_line.into_bytes());
+                    for header in response.headers.clone() {
+                        let header_line = format!("{}: {}\n", header.name, header.value);
+                        output.append(&mut header_line.into_bytes());
+                    }
+                    output.append(&mut "\n".to_string().into_bytes());
+                }
+                let body = if cli_options.compressed {
+                    match response.uncompress_body() {
+                        Ok(bytes) => bytes,
+                        Err(e) => {
+                            log_error_message(
+                                false,
+                                runner::Error {
+                                    source_info: SourceInfo {
+                                        start: Pos { line: 0, column: 0 },
+                                        end: Pos { line: 0, column: 0 },
+                                    },
+                                    inner: e,
+                                    assert: false,
+                                }
+                                .fixme()
+                                .as_str(),
+                            );
+                            std::process::exit(EXIT_ERROR_RUNTIME);
+                        }
+                    }
+                } else {
+                    response.body
+                };
+                output.append(&mut body.clone());
+                unwrap_or_exit(
+                    &log_error_message,
+                    write_output(output, cli_options.output.clone()),
+                );
+            } else {
+                cli::log_info("no response has been received");
+            }
+        } else {
+            let source = if filename == "-" {
+                "".to_string()
+            } else {
+                format!("for file {}", filename).to_string()
+            };
+            log_error_message(
+                true,
+                format!("no entry have been executed {}", source).as_str(),
+            );
+        };
+    }
+
+    let lines: Vec<String> = regex::Regex::new(r"\n|\r\n")
+        .unwrap()
+        .split(contents)
+        .map(|l| l.to_string())
+        .collect();
+    if matches!(cli_options.output_type, OutputType::Json) {
+        let mut json_result = hurl_result.to_json(&lines);
+        util::redact_json(&mut json_result, &cli_options.secrets);
+        let serialized = serde_json::to_string(&json_result).unwrap();
+        let s = format!("{}\n", serialized);
+        unwrap_or_exit(
+            &log_error_message,
+            write_output(s.into_bytes(), cli_options.output.clone()),
+        );
+    }
+    if cli_options.junit_file.is_some() {
+        let testcase =
+            report::Testcase::from_hurl_result(hurl_result, &lines, &cli_options.secrets);
+        Some(testcase)
+    } else {
+        None
+    }
+}
+
+fn progress(cli_options: &CliOptions, current: usize, total: usize) -> Option<Progress> {
+    if cli_options.progress {
+        Some(Progress { current, total })
+    } else {
+        None
+    }
+}
+
+/// Runs a Hurl file repeatedly (load test mode) until the number of runs reaches `--repeat`,
+/// or the elapsed time reaches `--duration`. The runs are executed by `--jobs` threads
+/// (1 by default), each with its own HTTP client, and added to the load test `report`.
+///
+/// Returns the first failed run, if any. Messages of the runs are only printed in verbose mode.
+fn execute_load(
+    filename: &str,
+    contents: String,
+    current_dir: &Path,
+    cli_options: &CliOptions,
+    report: &mut report::LoadReport,
+) -> Option<HurlResult> {
+    let lines: Vec<String> = regex::Regex::new(r"\n|\r\n")
+        .unwrap()
+        .split(&contents)
+        .map(|l| l.to_string())
+        .collect();
+    let hurl_file = match parse_hurl_file(
+        filename,
+        &contents,
+        &lines,
+        cli_options.color,
+        &Stderr::default(),
+    ) {
+        Some(hurl_file) => Arc::new(hurl_file),
+        None => std::process::exit(EXIT_ERROR_PARSING),
+    };
+    let runs = Arc::new(AtomicUsize::new(0));
+    let start = Instant::now();
+    let (sender, receiver) = mpsc::channel();
+    for _ in 0..cli_options.jobs.unwrap_or(1) {
+        let hurl_file = hurl_file.clone();
+        let runs = runs.clone();
+        let sender = sender.clone();
+        let filename = filename.to_string();
+        let lines = lines.clone();
+        let current_dir = current_dir.to_path_buf();
+        let cli_options = cli_options.clone();
+        thread::spawn(move || loop {
+            if let Some(repeat) = cli_options.repeat {
+                if runs.fetch_add(1, Ordering::SeqCst) >= repeat {
+                    break;
+                }
+            }
+            if let Some(duration) = cli_options.duration {
+                if start.elapsed() >= duration {
+                    break;
+                }
+            }
+            let stderr = Stderr::buffered();
+            let hurl_result = run_hurl_file(
+                hurl_file.as_ref().clone(),
+                &filename,
+                lines.clone(),
+                &current_dir,
+                cli_options.clone(),
+                &stderr,
+            );
+            if sender.send((hurl_result, stderr.buffer())).is_err() {
+                break;
+            }
+        });
+    }
+    drop(sender);
+
+    let mut failed_result = None;
+    for (hurl_result, stderr) in receiver {
+        if cli_options.verbose {
+            eprint!("{}", stderr);
+        }
+        report.add(&hurl_result);
+        if !hurl_result.success && failed_result.is_none() {
+            failed_result = Some(hurl_result);
+        }
+    }
+    failed_result
+}
+
+/// Runs the Hurl files on a pool of `jobs` threads, each file with its own HTTP client
+/// and cookie store.
+///
+/// The standard error of each file is buffered, and the results are handled in the order
+/// of the input files, as soon as a file and all the preceding ones have been run.
+///
+/// Returns the exit code of the error stopping the run (a file not readable or not parsable), if any.
+fn execute_parallel(
+    filenames: &[String],
+    current_dir: &Path,
+    cli_options: &CliOptions,
+    jobs: usize,
+    log_error_message: &impl Fn(bool, &str),
+    mut handle_result: impl FnMut(&str, String, HurlResult),
+) -> Option<i32> {
+    let filenames = Arc::new(filenames.to_vec());
+    let next = Arc::new(AtomicUsize::new(0));
+    let (sender, receiver) = mpsc::channel();
+    for _ in 0..jobs.min(filenames.len()) {
+        let filenames = filenames.clone();
+        let next = next.clone();
+        let sender = sender.clone();
+        let current_dir = current_dir.to_path_buf();
+        let cli_options = cli_options.clone();
+        thread::spawn(move || loop {
+            let current = next.fetch_add(1, Ordering::SeqCst);
+            let filename = match filenames.get(current) {
+                None => break,
+                Some(filename) => filename,
+            };
+            let result = cli::read_to_string(filename).map(|contents| {
+                let stderr = Stderr::buffered();
+                let hurl_result = execute(
+                    filename,
+                    contents.clone(),
+                    &current_dir,
+                    cli_options.clone(),
+                    progress(&cli_options, current, filenames.len()),
+                    &stderr,
+                );
+                (contents, hurl_result, stderr.buffer())
+            });
+            if sender.send((current, result)).is_err() {
+                break;
+            }
+        });
+    }
+    drop(sender);
+
+    let mut done = HashMap::new();
+    let mut current = 0;
+    for (index, result) in receiver {
+        done.insert(index, result);
+        while let Some(result) = done.remove(&current) {
+            match result {
+                Ok((contents, Some(hurl_result), stderr)) => {
+                    eprint!("{}", stderr);
+                    handle_result(&filenames[current], contents, hurl_result);
+                }
+                Ok((_, None, stderr)) => {
+                    eprint!("{}", stderr);
+                    return Some(EXIT_ERROR_PARSING);
+                }
+                Err(e) => {
+                    log_error_message(false, e.message.as_str());
+                    return Some(EXIT_ERROR_PARSING);
+                }
+            }
+            current += 1;
+        }
+    }
+    if current < filenames.len() {
+        log_error_message(
+            false,
+            format!("file {} has not been run", filenames[current]).as_str(),
+        );
+        return Some(EXIT_ERROR_UNDEFINED);
+    }
+    None
 }
 
 fn exit_code(hurl_results: Vec<HurlResult>) -> i32 {
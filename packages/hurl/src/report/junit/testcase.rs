@@ -18,6 +18,7 @@
 
 use crate::cli;
 use crate::runner::HurlResult;
+use crate::util;
 
 use xmltree::{Element, XMLNode};
 
@@ -32,8 +33,13 @@ pub struct Testcase {
 impl Testcase {
     ///
     /// create an XML Junit <testcase> from an Hurl result
+    /// secrets values are redacted from the messages
     ///
-    pub fn from_hurl_result(hurl_result: &HurlResult, lines: &[String]) -> Testcase {
+    pub fn from_hurl_result(
+        hurl_result: &HurlResult,
+        lines: &[String],
+        secrets: &[String],
+    ) -> Testcase {
         let id = hurl_result.filename.clone();
         let time_in_ms = hurl_result.time_in_ms;
         let mut failures = vec![];
@@ -41,6 +47,7 @@ impl Testcase {
 
         for error in hurl_result.errors() {
             let message = cli::error_string(lines, hurl_result.filename.clone(), &error);
+            let message = util::redact(message.as_str(), secrets);
             if error.assert {
                 failures.push(message);
             } else {
@@ -115,7 +122,7 @@ mod test {
         };
 
         let mut buffer = Vec::new();
-        Testcase::from_hurl_result(&hurl_result, &lines)
+        Testcase::from_hurl_result(&hurl_result, &lines, &[])
             .to_xml()
             .write(&mut buffer)
             .unwrap();
@@ -134,6 +141,7 @@ mod test {
         let hurl_result = HurlResult {
             filename: "test.hurl".to_string(),
             entries: vec![EntryResult {
+                entry_index: 1,
                 request: None,
                 response: None,
                 captures: vec![],
@@ -146,13 +154,14 @@ mod test {
                     assert: true,
                 }],
                 time_in_ms: 0,
+                attempts: 1,
             }],
             time_in_ms: 230,
             success: true,
             cookies: vec![],
         };
         let mut buffer = Vec::new();
-        Testcase::from_hurl_result(&hurl_result, &lines)
+        Testcase::from_hurl_result(&hurl_result, &lines, &[])
             .to_xml()
             .write(&mut buffer)
             .unwrap();
@@ -173,6 +182,7 @@ mod test {
         let hurl_result = HurlResult {
             filename: "test.hurl".to_string(),
             entries: vec![EntryResult {
+                entry_index: 1,
                 request: None,
                 response: None,
                 captures: vec![],
@@ -186,13 +196,14 @@ mod test {
                     assert: false,
                 }],
                 time_in_ms: 0,
+                attempts: 1,
             }],
             time_in_ms: 230,
             success: true,
             cookies: vec![],
         };
         let mut buffer = Vec::new();
-        Testcase::from_hurl_result(&hurl_result, &lines)
+        Testcase::from_hurl_result(&hurl_result, &lines, &[])
             .to_xml()
             .write(&mut buffer)
             .unwrap();
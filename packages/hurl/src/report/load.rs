@@ -0,0 +1,230 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2022 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use hurl_core::error::Error;
+
+use crate::runner::HurlResult;
+
+/// Statistics of a load test, i.e. a Hurl file run repeatedly.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LoadReport {
+    runs: usize,
+    failures: usize,
+    requests: usize,
+    durations: BTreeMap<usize, Vec<Duration>>,
+    errors: BTreeMap<String, usize>,
+}
+
+impl LoadReport {
+    ///
+    /// Add the result of a run to the report
+    /// the response durations are grouped by entry, errors by kind.
+    /// The duration of an entry following redirects is the sum of the durations of its requests.
+    ///
+    pub fn add(&mut self, hurl_result: &HurlResult) {
+        self.runs += 1;
+        if !hurl_result.success {
+            self.failures += 1;
+        }
+        let mut durations = BTreeMap::new();
+        for entry_result in &hurl_result.entries {
+            if let Some(response) = &entry_result.response {
+                self.requests += 1;
+                *durations
+                    .entry(entry_result.entry_index)
+                    .or_insert_with(Duration::default) += response.duration;
+            }
+            for error in &entry_result.errors {
+                *self.errors.entry(error.description()).or_insert(0) += 1;
+            }
+        }
+        for (entry_index, duration) in durations {
+            self.durations
+                .entry(entry_index)
+                .or_default()
+                .push(duration);
+        }
+    }
+
+    ///
+    /// Text summary of the report for the file `filename`
+    /// `elapsed` being the total duration of the load test.
+    ///
+    pub fn summary(&self, filename: &str, elapsed: Duration) -> String {
+        let mut s =
+            "--------------------------------------------------------------------------------\n"
+                .to_string();
+        s.push_str(format!("File:      {}\n", filename).as_str());
+        s.push_str(format!("Runs:      {} ({} failed)\n", self.runs, self.failures).as_str());
+        let rate = if elapsed.is_zero() {
+            0.0
+        } else {
+            self.requests as f64 / elapsed.as_secs_f64()
+        };
+        s.push_str(format!("Requests:  {} ({:.1} req/s)\n", self.requests, rate).as_str());
+        s.push_str(format!("Duration:  {}ms\n", elapsed.as_millis()).as_str());
+        if !self.durations.is_empty() {
+            s.push_str(
+                format!(
+                    "{:<7}{:>8}{:>8}{:>8}{:>8}{:>8}{:>8}\n",
+                    "Entry", "Count", "Min", "Avg", "p50", "p95", "p99"
+                )
+                .as_str(),
+            );
+        }
+        for (entry_index, durations) in &self.durations {
+            let mut durations = durations.clone();
+            durations.sort();
+            let total: Duration = durations.iter().sum();
+            let avg = total / durations.len() as u32;
+            s.push_str(
+                format!(
+                    "{:<7}{:>8}{:>8}{:>8}{:>8}{:>8}{:>8}\n",
+                    entry_index,
+                    durations.len(),
+                    format_duration(durations[0]),
+                    format_duration(avg),
+                    format_duration(percentile(&durations, 50)),
+                    format_duration(percentile(&durations, 95)),
+                    format_duration(percentile(&durations, 99)),
+                )
+                .as_str(),
+            );
+        }
+        if !self.errors.is_empty() {
+            s.push_str("Errors:\n");
+        }
+        for (kind, count) in &self.errors {
+            s.push_str(format!("    {}: {}\n", kind, count).as_str());
+        }
+        s
+    }
+}
+
+/// Returns the `p`th percentile (nearest-rank method) of a non-empty list of sorted durations.
+// `usize::div_ceil` is not available with the minimum supported Rust version.
+#[allow(clippy::manual_div_ceil)]
+fn percentile(sorted: &[Duration], p: usize) -> Duration {
+    let rank = (p * sorted.len() + 99) / 100;
+    sorted[rank.max(1) - 1]
+}
+
+fn format_duration(duration: Duration) -> String {
+    format!("{}ms", duration.as_millis())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http;
+    use crate::runner::{EntryResult, Error, RunnerError};
+    use hurl_core::ast::SourceInfo;
+
+    fn hurl_result(durations: Vec<u64>, errors: Vec<RunnerError>) -> HurlResult {
+        let entries = durations
+            .iter()
+            .enumerate()
+            .map(|(index, duration)| EntryResult {
+                entry_index: index + 1,
+                request: None,
+                response: Some(http::Response {
+                    version: http::Version::Http11,
+                    status: 200,
+                    headers: vec![],
+                    body: vec![],
+                    duration: Duration::from_millis(*duration),
+                    ..Default::default()
+                }),
+                captures: vec![],
+                asserts: vec![],
+                errors: errors
+                    .iter()
+                    .map(|inner| Error {
+                        source_info: SourceInfo::init(1, 1, 1, 1),
+                        inner: inner.clone(),
+                        assert: true,
+                    })
+                    .collect(),
+                time_in_ms: 0,
+                attempts: 1,
+            })
+            .collect();
+        HurlResult {
+            filename: "test.hurl".to_string(),
+            entries,
+            time_in_ms: 0,
+            success: errors.is_empty(),
+            cookies: vec![],
+        }
+    }
+
+    #[test]
+    fn test_add_redirect() {
+        // an entry with a redirect, its two requests being counted as a single entry duration
+        let mut hurl_result = hurl_result(vec![10, 30], vec![]);
+        hurl_result.entries[1].entry_index = 1;
+        let mut report = LoadReport::default();
+        report.add(&hurl_result);
+        assert_eq!(report.requests, 2);
+        assert_eq!(
+            report.durations,
+            BTreeMap::from([(1, vec![Duration::from_millis(40)])])
+        );
+    }
+
+    #[test]
+    fn test_percentile() {
+        let durations = (1..=200).map(Duration::from_millis).collect::<Vec<_>>();
+        assert_eq!(percentile(&durations, 50), Duration::from_millis(100));
+        assert_eq!(percentile(&durations, 95), Duration::from_millis(190));
+        assert_eq!(percentile(&durations, 99), Duration::from_millis(198));
+        assert_eq!(
+            percentile(&[Duration::from_millis(7)], 99),
+            Duration::from_millis(7)
+        );
+    }
+
+    #[test]
+    fn test_summary() {
+        let mut report = LoadReport::default();
+        report.add(&hurl_result(vec![10, 100], vec![]));
+        report.add(&hurl_result(vec![30, 200], vec![]));
+        report.add(&hurl_result(
+            vec![20],
+            vec![RunnerError::AssertStatus {
+                actual: "500".to_string(),
+            }],
+        ));
+        assert_eq!(
+            report.summary("test.hurl", Duration::from_secs(2)),
+            r#"--------------------------------------------------------------------------------
+File:      test.hurl
+Runs:      3 (1 failed)
+Requests:  5 (2.5 req/s)
+Duration:  2000ms
+Entry     Count     Min     Avg     p50     p95     p99
+1             3    10ms    20ms    20ms    30ms    30ms
+2             2   100ms   150ms   100ms   200ms   200ms
+Errors:
+    Assert Status: 1
+"#
+        );
+    }
+}
@@ -23,9 +23,11 @@ use super::runner::HurlResult;
 
 mod html;
 mod junit;
+mod load;
 
 pub use junit::create_report as create_junit_report;
 pub use junit::Testcase;
+pub use load::LoadReport;
 
 pub fn parse_html(path: PathBuf) -> Result<Vec<HurlResult>, CliError> {
     if path.exists() {
@@ -201,6 +203,11 @@ fn create_html_table_header() -> html::Element {
                     attributes: vec![],
                     children: vec![html::Element::TextElement("duration".to_string())],
                 },
+                html::Element::NodeElement {
+                    name: "td".to_string(),
+                    attributes: vec![],
+                    children: vec![html::Element::TextElement("retries".to_string())],
+                },
             ],
         }],
     }
@@ -256,14 +263,68 @@ fn create_html_result(result: HurlResult) -> html::Element {
                     result.time_in_ms as f64 / 1000.0
                 ))],
             },
+            html::Element::NodeElement {
+                name: "td".to_string(),
+                attributes: vec![],
+                children: vec![html::Element::TextElement(retries_text(&result))],
+            },
         ],
     }
 }
 
+/// Returns the total number of retries of a Hurl file, followed by the retries of each
+/// retried entry, for instance "3 (entry 2: 1, entry 4: 2)".
+fn retries_text(result: &HurlResult) -> String {
+    let entry_retries = result.entry_retries();
+    if entry_retries.is_empty() {
+        return "0".to_string();
+    }
+    let details = entry_retries
+        .iter()
+        .map(|(index, retries)| format!("entry {}: {}", index, retries))
+        .collect::<Vec<String>>()
+        .join(", ");
+    format!("{} ({})", result.retries(), details)
+}
+
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use crate::runner::EntryResult;
+
+    #[test]
+    fn test_retries_text() {
+        let entry = |entry_index: usize, attempts: usize| EntryResult {
+            entry_index,
+            request: None,
+            response: None,
+            captures: vec![],
+            asserts: vec![],
+            errors: vec![],
+            time_in_ms: 0,
+            attempts,
+        };
+        let result = |entries: Vec<EntryResult>| HurlResult {
+            filename: "test.hurl".to_string(),
+            entries,
+            time_in_ms: 0,
+            success: true,
+            cookies: vec![],
+        };
+        assert_eq!(retries_text(&result(vec![entry(1, 1)])), "0");
+        // entries without asserts (--ignore-asserts, capture or http errors) are counted,
+        // and redirections share the attempts of their entry
+        assert_eq!(
+            retries_text(&result(vec![
+                entry(1, 1),
+                entry(2, 2),
+                entry(3, 3),
+                entry(3, 3),
+            ])),
+            "3 (entry 2: 1, entry 3: 2)"
+        );
+    }
 
     #[test]
     fn test_percentage() {
@@ -17,12 +17,14 @@
  */
 
 use std::collections::HashMap;
+use std::path::Path;
 
 use crate::http;
 use hurl_core::ast::*;
 
 use super::core::*;
 use super::core::{Error, RunnerError};
+use super::json_diff::json_diff;
 use super::predicate::eval_predicate;
 use super::query::eval_query;
 use super::value::Value;
@@ -39,6 +41,7 @@ impl AssertResult {
             AssertResult::Header { .. } => false,
             AssertResult::Explicit { .. } => true,
             AssertResult::Body { .. } => true,
+            AssertResult::JsonBody { .. } => true,
         }
     }
 
@@ -110,13 +113,55 @@ impl AssertResult {
                             let expected = expected.to_string();
                             Some(Error {
                                 source_info,
-                                inner: RunnerError::AssertBodyValueError { actual, expected },
+                                inner: RunnerError::AssertBodyValueError {
+                                    actual,
+                                    expected,
+                                    diff: vec![],
+                                },
                                 assert: false,
                             })
                         }
                     }
                 },
             },
+            AssertResult::JsonBody {
+                actual,
+                expected,
+                ignored_keys,
+                source_info,
+            } => match expected {
+                Err(e) => Some(e),
+                Ok(expected) => match actual {
+                    Err(e) => Some(e),
+                    Ok(actual) => {
+                        // Bodies are compared as JSON values, or as strings if the actual
+                        // body is not valid JSON.
+                        let diff = match (
+                            serde_json::from_str(expected.as_str()),
+                            serde_json::from_str(actual.as_str()),
+                        ) {
+                            (Ok(expected_value), Ok(actual_value)) => {
+                                let diff = json_diff(&expected_value, &actual_value, &ignored_keys);
+                                if diff.is_empty() {
+                                    return None;
+                                }
+                                diff
+                            }
+                            _ if actual == expected => return None,
+                            _ => vec![],
+                        };
+                        Some(Error {
+                            source_info,
+                            inner: RunnerError::AssertBodyValueError {
+                                actual,
+                                expected,
+                                diff,
+                            },
+                            assert: false,
+                        })
+                    }
+                },
+            },
 
             AssertResult::Explicit { actual: Err(e), .. } => Some(e),
             AssertResult::Explicit {
@@ -132,21 +177,52 @@ impl AssertResult {
             AssertResult::Status { source_info, .. } => source_info.start.line,
             AssertResult::Header { source_info, .. } => source_info.start.line,
             AssertResult::Body { source_info, .. } => source_info.start.line,
+            AssertResult::JsonBody { source_info, .. } => source_info.start.line,
             AssertResult::Explicit { source_info, .. } => source_info.start.line,
         }
     }
+
+    /// Returns the errors of the assert, a JSON schema error being split into
+    /// one error per violation.
+    pub fn errors(self) -> Vec<Error> {
+        match self.error() {
+            None => vec![],
+            Some(Error {
+                source_info,
+                inner: RunnerError::AssertJsonSchema { schema, violations },
+                assert,
+            }) => violations
+                .into_iter()
+                .map(|violation| Error {
+                    source_info: source_info.clone(),
+                    inner: RunnerError::AssertJsonSchema {
+                        schema: schema.clone(),
+                        violations: vec![violation],
+                    },
+                    assert,
+                })
+                .collect(),
+            Some(error) => vec![error],
+        }
+    }
 }
 
 pub fn eval_assert(
     assert: Assert,
     variables: &HashMap<String, Value>,
     http_response: http::Response,
+    context_dir: &Path,
 ) -> AssertResult {
     let actual = eval_query(assert.query.clone(), variables, http_response);
     let source_info = assert.predicate.clone().predicate_func.source_info;
     let predicate_result = match actual.clone() {
         Err(_) => None,
-        Ok(actual) => Some(eval_predicate(assert.predicate, variables, actual)),
+        Ok(actual) => Some(eval_predicate(
+            assert.predicate,
+            variables,
+            actual,
+            context_dir,
+        )),
     };
 
     AssertResult::Explicit {
@@ -204,7 +280,8 @@ pub mod tests {
             eval_assert(
                 assert_count_user(),
                 &variables,
-                http::xml_three_users_http_response()
+                http::xml_three_users_http_response(),
+                Path::new("")
             ),
             AssertResult::Explicit {
                 actual: Ok(Some(Value::Nodeset(3))),
@@ -213,4 +290,88 @@ pub mod tests {
             }
         );
     }
+
+    #[test]
+    fn test_json_schema_errors() {
+        let source_info = SourceInfo::init(1, 1, 1, 1);
+        let assert_result = AssertResult::Explicit {
+            actual: Ok(Some(Value::String(r#"{"id": "1"}"#.to_string()))),
+            source_info: source_info.clone(),
+            predicate_result: Some(Err(Error {
+                source_info: source_info.clone(),
+                inner: RunnerError::AssertJsonSchema {
+                    schema: "user.schema.json".to_string(),
+                    violations: vec![
+                        (
+                            "".to_string(),
+                            "\"name\" is a required property".to_string(),
+                        ),
+                        (
+                            "/id".to_string(),
+                            "\"1\" is not of type \"integer\"".to_string(),
+                        ),
+                    ],
+                },
+                assert: true,
+            })),
+        };
+        let pointers = assert_result
+            .errors()
+            .into_iter()
+            .map(|error| match error.inner {
+                RunnerError::AssertJsonSchema { violations, .. } => {
+                    assert_eq!(violations.len(), 1);
+                    violations[0].0.clone()
+                }
+                _ => panic!("expect a JSON schema error"),
+            })
+            .collect::<Vec<String>>();
+        assert_eq!(pointers, vec!["".to_string(), "/id".to_string()]);
+    }
+
+    #[test]
+    fn test_json_body_error() {
+        let assert_result = |actual: &str, ignored_keys: Vec<String>| AssertResult::JsonBody {
+            actual: Ok(actual.to_string()),
+            expected: Ok(r#"{"id": 1, "updatedAt": "2022-01-01"}"#.to_string()),
+            ignored_keys,
+            source_info: SourceInfo::init(1, 1, 1, 1),
+        };
+
+        assert_eq!(
+            assert_result(r#"{"updatedAt":"2022-01-01","id":1}"#, vec![]).error(),
+            None
+        );
+        assert_eq!(
+            assert_result(
+                r#"{"id": 1, "updatedAt": "2022-06-01"}"#,
+                vec!["updatedAt".to_string()]
+            )
+            .error(),
+            None
+        );
+
+        let error = assert_result(r#"{"id": 2, "updatedAt": "2022-01-01"}"#, vec![])
+            .error()
+            .unwrap();
+        assert_eq!(
+            error.inner,
+            RunnerError::AssertBodyValueError {
+                actual: r#"{"id": 2, "updatedAt": "2022-01-01"}"#.to_string(),
+                expected: r#"{"id": 1, "updatedAt": "2022-01-01"}"#.to_string(),
+                diff: vec![("$.id".to_string(), "expected <1>, actual <2>".to_string())],
+            }
+        );
+
+        // not a JSON response
+        let error = assert_result("<html>", vec![]).error().unwrap();
+        assert_eq!(
+            error.inner,
+            RunnerError::AssertBodyValueError {
+                actual: "<html>".to_string(),
+                expected: r#"{"id": 1, "updatedAt": "2022-01-01"}"#.to_string(),
+                diff: vec![],
+            }
+        );
+    }
 }
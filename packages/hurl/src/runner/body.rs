@@ -20,6 +20,7 @@ use std::collections::HashMap;
 use std::path::Path;
 
 use hurl_core::ast::*;
+use hurl_core::parser;
 
 use super::core::{Error, RunnerError};
 use super::json::eval_json_value;
@@ -56,34 +57,75 @@ pub fn eval_bytes(
         Bytes::Base64(Base64 { value, .. }) => Ok(http::Body::Binary(value)),
         Bytes::Hex(Hex { value, .. }) => Ok(http::Body::Binary(value)),
         Bytes::File(File { filename, .. }) => {
-            let f = filename.value.as_str();
-            let path = Path::new(f);
-            let absolute_path = context_dir.join(path);
-            // In order not to leak any private date, we check that the user provided file
-            // is a child of the context directory.
-            if !path::is_descendant(&absolute_path, context_dir) {
-                return Err(Error {
-                    source_info: filename.source_info,
-                    inner: RunnerError::UnauthorizedFileAccess {
-                        path: absolute_path,
-                    },
-                    assert: false,
-                });
-            }
-            match std::fs::read(&absolute_path) {
-                Ok(value) => Ok(http::Body::File(value, f.to_string())),
-                Err(_) => Err(Error {
+            let value = read_file(&filename, context_dir)?;
+            Ok(http::Body::File(value, filename.value))
+        }
+        Bytes::TemplateFile(TemplateFile { filename, .. }) => {
+            let value = read_file(&filename, context_dir)?;
+            let content = match String::from_utf8(value) {
+                Ok(content) => content,
+                Err(_) => {
+                    return Err(Error {
+                        source_info: filename.source_info,
+                        inner: RunnerError::InvalidDecoding {
+                            charset: "utf-8".to_string(),
+                        },
+                        assert: false,
+                    })
+                }
+            };
+            let template = match parser::parse_template(content.as_str()) {
+                Ok(template) => template,
+                Err(e) => {
+                    return Err(Error {
+                        source_info: filename.source_info,
+                        inner: RunnerError::InvalidTemplateFile {
+                            value: filename.value,
+                            pos: e.pos,
+                        },
+                        assert: false,
+                    })
+                }
+            };
+            // errors are reported on the filename, their positions being relative to the file
+            match eval_template(&template, variables) {
+                Ok(value) => Ok(http::Body::Text(value)),
+                Err(e) => Err(Error {
                     source_info: filename.source_info,
-                    inner: RunnerError::FileReadAccess {
-                        value: absolute_path.to_str().unwrap().to_string(),
-                    },
-                    assert: false,
+                    ..e
                 }),
             }
         }
     }
 }
 
+/// Reads a file of the context directory.
+pub fn read_file(filename: &Filename, context_dir: &Path) -> Result<Vec<u8>, Error> {
+    let path = Path::new(filename.value.as_str());
+    let absolute_path = context_dir.join(path);
+    // In order not to leak any private date, we check that the user provided file
+    // is a child of the context directory.
+    if !path::is_descendant(&absolute_path, context_dir) {
+        return Err(Error {
+            source_info: filename.source_info.clone(),
+            inner: RunnerError::UnauthorizedFileAccess {
+                path: absolute_path,
+            },
+            assert: false,
+        });
+    }
+    match std::fs::read(&absolute_path) {
+        Ok(value) => Ok(value),
+        Err(_) => Err(Error {
+            source_info: filename.source_info.clone(),
+            inner: RunnerError::FileReadAccess {
+                value: absolute_path.to_str().unwrap().to_string(),
+            },
+            assert: false,
+        }),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use hurl_core::ast::SourceInfo;
@@ -145,4 +187,41 @@ mod tests {
         );
         assert_eq!(error.source_info, SourceInfo::init(1, 7, 1, 15));
     }
+
+    #[test]
+    pub fn test_body_template_file() {
+        // template, template.json;
+        let whitespace = Whitespace {
+            value: String::from(" "),
+            source_info: SourceInfo::init(0, 0, 0, 0),
+        };
+
+        let bytes = Bytes::TemplateFile(TemplateFile {
+            space0: whitespace.clone(),
+            filename: Filename {
+                value: String::from("tests/template.json"),
+                source_info: SourceInfo::init(1, 11, 1, 30),
+            },
+            space1: whitespace,
+        });
+
+        let mut variables = HashMap::new();
+        variables.insert("id".to_string(), Value::Integer(42));
+        variables.insert("name".to_string(), Value::String("Bob".to_string()));
+        assert_eq!(
+            eval_bytes(bytes.clone(), &variables, Path::new("")).unwrap(),
+            http::Body::Text("{\n  \"id\": 42,\n  \"name\": \"Bob\"\n}\n".to_string())
+        );
+
+        // the error is reported on the filename
+        variables.remove("name");
+        let error = eval_bytes(bytes, &variables, Path::new("")).err().unwrap();
+        assert_eq!(
+            error.inner,
+            RunnerError::TemplateVariableNotDefined {
+                name: "name".to_string()
+            }
+        );
+        assert_eq!(error.source_info, SourceInfo::init(1, 11, 1, 30));
+    }
 }
@@ -178,7 +178,7 @@ pub mod tests {
                     },
                 },
 
-                subquery: None,
+                filters: vec![],
             },
             line_terminator0: LineTerminator {
                 space0: whitespace.clone(),
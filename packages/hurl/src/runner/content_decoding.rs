@@ -139,6 +139,7 @@ pub mod tests {
             headers: vec![],
             body: vec![],
             duration: Default::default(),
+            ..Default::default()
         };
         assert_eq!(response.content_encoding().unwrap(), vec![]);
 
@@ -151,6 +152,7 @@ pub mod tests {
             }],
             body: vec![],
             duration: Default::default(),
+            ..Default::default()
         };
         assert_eq!(
             response.content_encoding().err().unwrap(),
@@ -166,6 +168,7 @@ pub mod tests {
             }],
             body: vec![],
             duration: Default::default(),
+            ..Default::default()
         };
         assert_eq!(response.content_encoding().unwrap(), vec![Encoding::Brotli]);
     }
@@ -181,6 +184,7 @@ pub mod tests {
             }],
             body: vec![],
             duration: Default::default(),
+            ..Default::default()
         };
         assert_eq!(
             response.content_encoding().unwrap(),
@@ -202,6 +206,7 @@ pub mod tests {
                 0x64, 0x21,
             ],
             duration: Default::default(),
+            ..Default::default()
         };
         assert_eq!(response.uncompress_body().unwrap(), b"Hello World!");
 
@@ -217,6 +222,7 @@ pub mod tests {
                 0x64, 0x21,
             ],
             duration: Default::default(),
+            ..Default::default()
         };
         assert_eq!(response.uncompress_body().unwrap(), b"Hello World!");
 
@@ -226,6 +232,7 @@ pub mod tests {
             headers: vec![],
             body: b"Hello World!".to_vec(),
             duration: Default::default(),
+            ..Default::default()
         };
         assert_eq!(response.uncompress_body().unwrap(), b"Hello World!");
     }
@@ -17,9 +17,10 @@
  */
 use std::collections::HashMap;
 use std::path::PathBuf;
+use std::time::Duration;
 
 use crate::http;
-use hurl_core::ast::{Entry, SourceInfo};
+use hurl_core::ast::{Entry, Pos, SourceInfo};
 
 use super::value::Value;
 
@@ -30,6 +31,8 @@ pub struct RunnerOptions {
     pub to_entry: Option<usize>,
     pub context_dir: PathBuf,
     pub ignore_asserts: bool,
+    pub retry: usize,
+    pub retry_interval: Duration,
     pub pre_entry: fn(Entry) -> bool,
     pub post_entry: fn() -> bool,
 }
@@ -51,10 +54,38 @@ impl HurlResult {
     pub fn success(&self) -> bool {
         self.errors().is_empty()
     }
+
+    /// Returns the total number of retries of the entries.
+    pub fn retries(&self) -> usize {
+        self.entry_retries()
+            .iter()
+            .map(|(_, retries)| retries)
+            .sum()
+    }
+
+    /// Returns the entry index and the number of retries of each retried entry.
+    /// Redirections share the attempts of their entry, so each entry is counted once,
+    /// whatever the errors of its last attempt.
+    pub fn entry_retries(&self) -> Vec<(usize, usize)> {
+        let mut entry_retries: Vec<(usize, usize)> = vec![];
+        for entry in self.entries.iter().filter(|e| e.attempts > 1) {
+            let retries = entry.attempts - 1;
+            match entry_retries
+                .iter_mut()
+                .find(|(index, _)| *index == entry.entry_index)
+            {
+                Some((_, r)) => *r = (*r).max(retries),
+                None => entry_retries.push((entry.entry_index, retries)),
+            }
+        }
+        entry_retries
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct EntryResult {
+    /// Index of the entry in the Hurl file, starting at 1
+    pub entry_index: usize,
     pub request: Option<http::Request>,
     pub response: Option<http::Response>,
     //pub captures: Vec<(String, Value)>,
@@ -62,6 +93,7 @@ pub struct EntryResult {
     pub asserts: Vec<AssertResult>,
     pub errors: Vec<Error>,
     pub time_in_ms: u128,
+    pub attempts: usize,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -86,6 +118,12 @@ pub enum AssertResult {
         expected: Result<Value, Error>,
         source_info: SourceInfo,
     },
+    JsonBody {
+        actual: Result<String, Error>,
+        expected: Result<String, Error>,
+        ignored_keys: Vec<String>,
+        source_info: SourceInfo,
+    },
     Explicit {
         actual: Result<Option<Value>, Error>,
         source_info: SourceInfo,
@@ -117,6 +155,9 @@ pub enum RunnerError {
     TemplateVariableNotDefined {
         name: String,
     },
+    EnvironmentVariableNotDefined {
+        name: String,
+    },
     VariableNotDefined {
         name: String,
     },
@@ -143,6 +184,17 @@ pub enum RunnerError {
     FileReadAccess {
         value: String,
     },
+    FileWriteAccess {
+        value: String,
+    },
+    /// The response body has been streamed to the file `path` and is not available in memory
+    StreamedBody {
+        path: String,
+    },
+    InvalidTemplateFile {
+        value: String,
+        pos: Pos,
+    },
     InvalidDecoding {
         charset: String,
     },
@@ -161,7 +213,8 @@ pub enum RunnerError {
     QueryInvalidJson,
     NoQueryResult,
 
-    SubqueryInvalidInput,
+    FilterInvalidInput(String),
+    FilterMissingInput,
 
     // Predicate
     PredicateType,
@@ -172,6 +225,25 @@ pub enum RunnerError {
         type_mismatch: bool,
     },
     InvalidRegex(),
+    InvalidJsonSchema {
+        value: String,
+    },
+    AssertJsonSchema {
+        schema: String,
+        /// JSON pointer and message of each violation
+        /// (a single one in the errors of an entry, see `AssertResult::errors`)
+        violations: Vec<(String, String)>,
+    },
+    InvalidXmlSchema {
+        value: String,
+    },
+    AssertXmlSchema {
+        schema: String,
+        /// Line number and message of each validation error
+        violations: Vec<(usize, String)>,
+    },
+    /// An inconsistency of the runner itself, not caused by the Hurl file
+    Internal(String),
 
     AssertHeaderValueError {
         actual: String,
@@ -179,6 +251,8 @@ pub enum RunnerError {
     AssertBodyValueError {
         actual: String,
         expected: String,
+        /// Path and description of each difference between JSON bodies
+        diff: Vec<(String, String)>,
     },
     AssertVersion {
         actual: String,
@@ -16,15 +16,20 @@
  *
  */
 use std::collections::HashMap;
+use std::thread;
+use std::time::Duration;
 
 use crate::http;
 use crate::http::HttpError;
+use crate::util;
 use hurl_core::ast::*;
 
 use super::core::*;
 use super::core::{Error, RunnerError};
+use super::expr::take_generated_values;
 use super::request::eval_request;
 use super::response::{eval_asserts, eval_captures};
+use super::template::eval_template;
 use super::value::Value;
 use crate::runner::request::{cookie_storage_clear, cookie_storage_set};
 
@@ -56,6 +61,68 @@ pub fn run(
     log_error_message: &impl Fn(bool, &str),
     options: &RunnerOptions,
 ) -> Vec<EntryResult> {
+    let (retry, retry_interval) = get_entry_retry(entry.request.clone(), options);
+    retry_entry(entry_index, retry, retry_interval, log_verbose, || {
+        run_attempt(
+            entry.clone(),
+            http_client,
+            entry_index,
+            variables,
+            log_verbose,
+            log_error_message,
+            options,
+        )
+    })
+}
+
+/// Runs the attempts of an entry until its asserts pass, or `retry` retries have been done.
+/// Only the results of the last attempt are returned, with the number of attempts.
+fn retry_entry(
+    entry_index: usize,
+    retry: usize,
+    retry_interval: Duration,
+    log_verbose: &impl Fn(&str),
+    mut attempt: impl FnMut() -> Vec<EntryResult>,
+) -> Vec<EntryResult> {
+    let mut attempts = 1;
+    loop {
+        let mut entry_results = attempt();
+        let has_failed_asserts = entry_results
+            .iter()
+            .any(|entry_result| entry_result.errors.iter().any(|e| e.assert));
+        if !has_failed_asserts || attempts > retry {
+            for entry_result in entry_results.iter_mut() {
+                entry_result.attempts = attempts;
+            }
+            return entry_results;
+        }
+        log_verbose(
+            format!(
+                "retry entry {} in {} ms (attempt {}/{})",
+                entry_index + 1,
+                retry_interval.as_millis(),
+                attempts + 1,
+                retry + 1
+            )
+            .as_str(),
+        );
+        thread::sleep(retry_interval);
+        attempts += 1;
+    }
+}
+
+/// Run one attempt of an entry, without any retry.
+fn run_attempt(
+    entry: Entry,
+    http_client: &mut http::Client,
+    entry_index: usize,
+    variables: &mut HashMap<String, Value>,
+    log_verbose: &impl Fn(&str),
+    log_error_message: &impl Fn(bool, &str),
+    options: &RunnerOptions,
+) -> Vec<EntryResult> {
+    // Discards values generated before this entry.
+    take_generated_values();
     let http_request = match eval_request(
         entry.request.clone(),
         variables,
@@ -64,18 +131,70 @@ pub fn run(
         Ok(r) => r,
         Err(error) => {
             return vec![EntryResult {
+                entry_index: entry_index + 1,
                 request: None,
                 response: None,
                 captures: vec![],
                 asserts: vec![],
                 errors: vec![error],
                 time_in_ms: 0,
+                attempts: 1,
+            }];
+        }
+    };
+
+    let client_options = match get_entry_options(
+        entry.request.clone(),
+        &http_client.options,
+        variables,
+        options,
+    ) {
+        Ok(client_options) => client_options,
+        Err(error) => {
+            return vec![EntryResult {
+                entry_index: entry_index + 1,
+                request: None,
+                response: None,
+                captures: vec![],
+                asserts: vec![],
+                errors: vec![error],
+                time_in_ms: 0,
+                attempts: 1,
+            }];
+        }
+    };
+
+    let ignored_json_keys = match get_entry_ignored_json_keys(entry.request.clone(), variables) {
+        Ok(keys) => keys,
+        Err(error) => {
+            return vec![EntryResult {
+                entry_index: entry_index + 1,
+                request: None,
+                response: None,
+                captures: vec![],
+                asserts: vec![],
+                errors: vec![error],
+                time_in_ms: 0,
+                attempts: 1,
             }];
         }
     };
 
     log_verbose("------------------------------------------------------------------------------");
     log_verbose(format!("executing entry {}", entry_index + 1).as_str());
+    log_generated_values(log_verbose);
+
+    // Options from the [Options] section only apply to this entry:
+    // the file level options are restored once the entry has been executed.
+    let file_options = std::mem::replace(&mut http_client.options, client_options);
+    if !entry.request.clone().options().is_empty() {
+        log_verbose("");
+        log_verbose("Entry options:");
+        for option in entry.request.clone().options() {
+            let option = format!("{}: {}", option.kind.name(), option.kind);
+            log_verbose(util::redact(option.as_str(), &http_client.options.secrets).as_str());
+        }
+    }
 
     //
     // Experimental features
@@ -103,15 +222,18 @@ pub fn run(
     }
     log_verbose("");
     log_request(log_verbose, &http_request);
+    let curl_command_line = http_client.curl_command_line(&http_request);
     log_verbose(
         format!(
             "request can be run with the following curl command:\n* {}\n*",
-            http_client.curl_command_line(&http_request)
+            util::redact(curl_command_line.as_str(), &http_client.options.secrets)
         )
         .as_str(),
     );
 
-    let calls = match http_client.execute_with_redirect(&http_request) {
+    let result = http_client.execute_with_redirect(&http_request);
+    http_client.options = file_options;
+    let calls = match result {
         Ok(calls) => calls,
         Err(http_error) => {
             let runner_error = match http_error {
@@ -125,8 +247,10 @@ pub fn run(
                     message: format!("({}) {}", code, description),
                     url: http_request.url.clone(),
                 },
+                HttpError::FileWriteAccess { path } => RunnerError::FileWriteAccess { value: path },
             };
             return vec![EntryResult {
+                entry_index: entry_index + 1,
                 request: None,
                 response: None,
                 captures: vec![],
@@ -140,6 +264,7 @@ pub fn run(
                     assert: false,
                 }],
                 time_in_ms: 0,
+                attempts: 1,
             }];
         }
     };
@@ -159,12 +284,14 @@ pub fn run(
                     Ok(captures) => captures,
                     Err(e) => {
                         return vec![EntryResult {
+                            entry_index: entry_index + 1,
                             request: Some(http_request.clone()),
                             response: Some(http_response.clone()),
                             captures: vec![],
                             asserts: vec![],
                             errors: vec![e],
                             time_in_ms,
+                            attempts: 1,
                         }];
                     }
                 },
@@ -183,12 +310,13 @@ pub fn run(
                         variables,
                         http_response.clone(),
                         options.context_dir.as_path(),
+                        &ignored_json_keys,
                     ),
                 }
             };
             errors = asserts
                 .iter()
-                .filter_map(|assert| assert.clone().error())
+                .flat_map(|assert| assert.clone().errors())
                 .map(
                     |Error {
                          source_info, inner, ..
@@ -206,16 +334,19 @@ pub fn run(
                     log_verbose(format!("{}: {}", capture.name, capture.value).as_str());
                 }
             }
+            log_generated_values(log_verbose);
             log_verbose("");
         }
 
         let entry_result = EntryResult {
+            entry_index: entry_index + 1,
             request: Some(http_request.clone()),
             response: Some(http_response.clone()),
             captures,
             asserts,
             errors,
             time_in_ms,
+            attempts: 1,
         };
         entry_results.push(entry_result);
     }
@@ -223,6 +354,127 @@ pub fn run(
     entry_results
 }
 
+/// Returns the client options to use for this entry, i.e. the current
+/// client options overridden by the entry [Options] section.
+fn get_entry_options(
+    request: Request,
+    client_options: &http::ClientOptions,
+    variables: &HashMap<String, Value>,
+    options: &RunnerOptions,
+) -> Result<http::ClientOptions, Error> {
+    let mut client_options = client_options.clone();
+    for option in request.options() {
+        match option.kind {
+            OptionKind::CaCertificate(filename) => {
+                let path = options.context_dir.join(filename.value);
+                client_options.cacert_file = Some(path.to_string_lossy().to_string());
+            }
+            OptionKind::ClientCertificate(filename) => {
+                let path = options.context_dir.join(filename.value);
+                client_options.client_cert_file = Some(path.to_string_lossy().to_string());
+            }
+            OptionKind::ClientKey(filename) => {
+                let path = options.context_dir.join(filename.value);
+                client_options.client_key_file = Some(path.to_string_lossy().to_string());
+            }
+            OptionKind::Compressed(value) => client_options.compressed = value,
+            OptionKind::ConnectTimeout(value) => {
+                client_options.connect_timeout = Duration::from_secs(value)
+            }
+            OptionKind::FollowLocation(value) => client_options.follow_location = value,
+            OptionKind::Http10(value) => {
+                set_http_version(&mut client_options, http::HttpVersion::Http10, value)
+            }
+            OptionKind::Http11(value) => {
+                set_http_version(&mut client_options, http::HttpVersion::Http11, value)
+            }
+            OptionKind::Http2(value) => {
+                set_http_version(&mut client_options, http::HttpVersion::Http2, value)
+            }
+            OptionKind::Http2PriorKnowledge(value) => set_http_version(
+                &mut client_options,
+                http::HttpVersion::Http2PriorKnowledge,
+                value,
+            ),
+            OptionKind::IgnoreJsonKey(_) => {}
+            OptionKind::Insecure(value) => client_options.insecure = value,
+            OptionKind::KeyPassword(value) => {
+                let key_password = eval_template(&value, variables)?;
+                client_options.secrets.push(key_password.clone());
+                client_options.key_password = Some(key_password);
+            }
+            OptionKind::MaxRedirect(value) => client_options.max_redirect = Some(value as usize),
+            OptionKind::NoProxy(value) => {
+                client_options.no_proxy = Some(eval_template(&value, variables)?)
+            }
+            OptionKind::Output(filename) => {
+                let path = options.context_dir.join(filename.value);
+                client_options.output = Some(path.to_string_lossy().to_string());
+            }
+            OptionKind::Proxy(value) => {
+                client_options.proxy = Some(eval_template(&value, variables)?)
+            }
+            OptionKind::Retry(_) => {}
+            OptionKind::RetryInterval(_) => {}
+            OptionKind::Timeout(value) => client_options.timeout = Duration::from_secs(value),
+            OptionKind::UserAgent(value) => {
+                client_options.user_agent = Some(eval_template(&value, variables)?)
+            }
+        }
+    }
+    Ok(client_options)
+}
+
+/// Forces `version` on the client options if `value` is true, or gives the
+/// version negotiation back to libcurl if `value` is false and `version` was forced.
+fn set_http_version(
+    client_options: &mut http::ClientOptions,
+    version: http::HttpVersion,
+    value: bool,
+) {
+    if value {
+        client_options.http_version = Some(version);
+    } else if client_options.http_version == Some(version) {
+        client_options.http_version = None;
+    }
+}
+
+/// Returns the number of retries and the interval between retries for this entry,
+/// the entry [Options] section taking precedence over the runner options.
+fn get_entry_retry(request: Request, options: &RunnerOptions) -> (usize, Duration) {
+    let mut retry = options.retry;
+    let mut retry_interval = options.retry_interval;
+    for option in request.options() {
+        match option.kind {
+            OptionKind::Retry(value) => retry = value as usize,
+            OptionKind::RetryInterval(value) => retry_interval = Duration::from_millis(value),
+            _ => {}
+        }
+    }
+    (retry, retry_interval)
+}
+
+/// Returns the object keys ignored when comparing an expected JSON body with the response.
+fn get_entry_ignored_json_keys(
+    request: Request,
+    variables: &HashMap<String, Value>,
+) -> Result<Vec<String>, Error> {
+    let mut keys = vec![];
+    for option in request.options() {
+        if let OptionKind::IgnoreJsonKey(value) = option.kind {
+            keys.push(eval_template(&value, variables)?);
+        }
+    }
+    Ok(keys)
+}
+
+/// Logs the values generated by the template functions since the last call.
+fn log_generated_values(log_verbose: &impl Fn(&str)) {
+    for (name, value) in take_generated_values() {
+        log_verbose(format!("{}={}", name, value).as_str());
+    }
+}
+
 pub fn log_request(log_verbose: impl Fn(&str), request: &http::RequestSpec) {
     log_verbose("Request");
     log_verbose(format!("{} {}", request.method, request.url).as_str());
@@ -259,3 +511,121 @@ pub fn log_request(log_verbose: impl Fn(&str), request: &http::RequestSpec) {
     }
     log_verbose("");
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+    use std::path::PathBuf;
+
+    fn runner_options(retry: usize) -> RunnerOptions {
+        RunnerOptions {
+            fail_fast: false,
+            variables: HashMap::new(),
+            to_entry: None,
+            context_dir: PathBuf::new(),
+            ignore_asserts: false,
+            retry,
+            retry_interval: Duration::from_millis(1000),
+            pre_entry: |_| true,
+            post_entry: || true,
+        }
+    }
+
+    fn request(content: &str) -> Request {
+        let hurl_file = hurl_core::parser::parse_hurl_file(content).unwrap();
+        hurl_file.entries[0].request.clone()
+    }
+
+    fn entry_result(errors: Vec<Error>) -> EntryResult {
+        EntryResult {
+            entry_index: 1,
+            request: None,
+            response: None,
+            captures: vec![],
+            asserts: vec![],
+            errors,
+            time_in_ms: 0,
+            attempts: 1,
+        }
+    }
+
+    fn error(assert: bool) -> Error {
+        Error {
+            source_info: SourceInfo::init(1, 1, 1, 1),
+            inner: RunnerError::AssertStatus {
+                actual: "202".to_string(),
+            },
+            assert,
+        }
+    }
+
+    #[test]
+    fn test_get_entry_retry() {
+        let request_without_options = request("GET http://localhost:8000/hello\n");
+        assert_eq!(
+            get_entry_retry(request_without_options, &runner_options(2)),
+            (2, Duration::from_millis(1000))
+        );
+
+        let request_with_options =
+            request("GET http://localhost:8000/hello\n[Options]\nretry: 5\nretry-interval: 100\n");
+        assert_eq!(
+            get_entry_retry(request_with_options.clone(), &runner_options(2)),
+            (5, Duration::from_millis(100))
+        );
+        assert_eq!(
+            get_entry_retry(request_with_options, &runner_options(0)),
+            (5, Duration::from_millis(100))
+        );
+    }
+
+    #[test]
+    fn test_retry_entry_until_asserts_pass() {
+        let logs = RefCell::new(vec![]);
+        let log_verbose = |message: &str| logs.borrow_mut().push(message.to_string());
+        let mut count = 0;
+        let entry_results = retry_entry(0, 5, Duration::from_millis(0), &log_verbose, || {
+            count += 1;
+            if count < 3 {
+                vec![entry_result(vec![error(true)])]
+            } else {
+                vec![entry_result(vec![])]
+            }
+        });
+        assert_eq!(count, 3);
+        assert_eq!(entry_results.len(), 1);
+        assert_eq!(entry_results[0].attempts, 3);
+        assert!(entry_results[0].errors.is_empty());
+        assert_eq!(
+            logs.borrow().clone(),
+            vec![
+                "retry entry 1 in 0 ms (attempt 2/6)".to_string(),
+                "retry entry 1 in 0 ms (attempt 3/6)".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_retry_entry_exhausted() {
+        let mut count = 0;
+        let entry_results = retry_entry(0, 2, Duration::from_millis(0), &|_| {}, || {
+            count += 1;
+            vec![entry_result(vec![error(true)])]
+        });
+        assert_eq!(count, 3);
+        assert_eq!(entry_results[0].attempts, 3);
+        assert_eq!(entry_results[0].errors, vec![error(true)]);
+    }
+
+    #[test]
+    fn test_retry_entry_not_on_other_errors() {
+        let mut count = 0;
+        let entry_results = retry_entry(0, 2, Duration::from_millis(0), &|_| {}, || {
+            count += 1;
+            vec![entry_result(vec![error(false)])]
+        });
+        assert_eq!(count, 1);
+        assert_eq!(entry_results[0].attempts, 1);
+    }
+}
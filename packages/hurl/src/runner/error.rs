@@ -17,6 +17,9 @@ impl Error for runner::Error {
         match &self.inner {
             RunnerError::InvalidUrl(..) => "Invalid url".to_string(),
             RunnerError::TemplateVariableNotDefined { .. } => "Undefined Variable".to_string(),
+            RunnerError::EnvironmentVariableNotDefined { .. } => {
+                "Undefined Environment Variable".to_string()
+            }
             RunnerError::VariableNotDefined { .. } => "Undefined Variable".to_string(),
             RunnerError::HttpConnection { .. } => "Http Connection".to_string(),
             RunnerError::CouldNotResolveProxyName => "Http Connection".to_string(),
@@ -28,7 +31,14 @@ impl Error for runner::Error {
             RunnerError::SslCertificate { .. } => "SSL Certificate".to_string(),
             RunnerError::PredicateValue { .. } => "Assert - Predicate Value Failed".to_string(),
             RunnerError::InvalidRegex {} => "Invalid regex".to_string(),
+            RunnerError::InvalidJsonSchema { .. } => "Invalid JSON Schema".to_string(),
+            RunnerError::AssertJsonSchema { .. } => "Assert JSON Schema".to_string(),
+            RunnerError::InvalidXmlSchema { .. } => "Invalid XML Schema".to_string(),
+            RunnerError::AssertXmlSchema { .. } => "Assert XML Schema".to_string(),
             RunnerError::FileReadAccess { .. } => "File ReadAccess".to_string(),
+            RunnerError::FileWriteAccess { .. } => "File WriteAccess".to_string(),
+            RunnerError::StreamedBody { .. } => "Streamed body".to_string(),
+            RunnerError::InvalidTemplateFile { .. } => "Invalid template file".to_string(),
             RunnerError::QueryInvalidXml { .. } => "Invalid XML".to_string(),
             RunnerError::QueryInvalidXpathEval {} => "Invalid xpath expression".to_string(),
             RunnerError::QueryHeaderNotFound {} => "Header not Found".to_string(),
@@ -40,7 +50,9 @@ impl Error for runner::Error {
             RunnerError::QueryInvalidJson { .. } => "Invalid Json".to_string(),
             RunnerError::QueryInvalidJsonpathExpression { .. } => "Invalid jsonpath".to_string(),
             RunnerError::PredicateType { .. } => "Assert - Inconsistent predicate type".to_string(),
-            RunnerError::SubqueryInvalidInput { .. } => "Subquery error".to_string(),
+            RunnerError::Internal(..) => "Internal error".to_string(),
+            RunnerError::FilterInvalidInput(..) => "Filter error".to_string(),
+            RunnerError::FilterMissingInput => "Filter error".to_string(),
             RunnerError::InvalidDecoding { .. } => "Invalid Decoding".to_string(),
             RunnerError::InvalidCharset { .. } => "Invalid Charset".to_string(),
             RunnerError::AssertFailure { .. } => "Assert Failure".to_string(),
@@ -59,6 +71,9 @@ impl Error for runner::Error {
             RunnerError::TemplateVariableNotDefined { name } => {
                 format!("You must set the variable {}", name)
             }
+            RunnerError::EnvironmentVariableNotDefined { name } => {
+                format!("You must set the environment variable {}", name)
+            }
             RunnerError::HttpConnection { message, .. } => message.to_string(),
             RunnerError::CouldNotResolveProxyName => "Could not resolve proxy name".to_string(),
             RunnerError::CouldNotResolveHost(host) => format!("Could not resolve host <{}>", host),
@@ -74,6 +89,15 @@ impl Error for runner::Error {
             }
             RunnerError::InvalidRegex {} => "Regex expression is not valid".to_string(),
             RunnerError::FileReadAccess { value } => format!("File {} can not be read", value),
+            RunnerError::FileWriteAccess { value } => format!("File {} can not be written", value),
+            RunnerError::StreamedBody { path } => format!(
+                "the response body has been written to {}, only bytes count, sha256 and md5 can be queried",
+                path
+            ),
+            RunnerError::InvalidTemplateFile { value, pos } => format!(
+                "File {} is not a valid template (line {}, column {})",
+                value, pos.line, pos.column
+            ),
             RunnerError::QueryInvalidXml { .. } => {
                 "The Http response is not a valid XML".to_string()
             }
@@ -89,8 +113,16 @@ impl Error for runner::Error {
             RunnerError::AssertHeaderValueError { actual } => {
                 format!("actual value is <{}>", actual)
             }
-            RunnerError::AssertBodyValueError { actual, .. } => {
-                format!("actual value is <{}>", actual)
+            RunnerError::AssertBodyValueError { actual, diff, .. } => {
+                if diff.is_empty() {
+                    format!("actual value is <{}>", actual)
+                } else {
+                    let diff = diff
+                        .iter()
+                        .map(|(path, message)| format!("\n  {}: {}", path, message))
+                        .collect::<String>();
+                    format!("actual value differs from expected:{}", diff)
+                }
             }
             RunnerError::QueryInvalidJson { .. } => {
                 "The http response is not a valid json".to_string()
@@ -101,9 +133,11 @@ impl Error for runner::Error {
             RunnerError::PredicateType { .. } => {
                 "predicate type inconsistent with value return by query".to_string()
             }
-            RunnerError::SubqueryInvalidInput => {
-                "Type from query result and subquery do not match".to_string()
+            RunnerError::Internal(message) => format!("internal error: {}", message),
+            RunnerError::FilterInvalidInput(message) => {
+                format!("invalid filter input: {}", message)
             }
+            RunnerError::FilterMissingInput => "missing value to apply filter".to_string(),
             RunnerError::InvalidDecoding { charset } => {
                 format!("The body can not be decoded with charset '{}'", charset)
             }
@@ -139,6 +173,35 @@ impl Error for runner::Error {
             RunnerError::InvalidJson { value } => {
                 format!("actual value is <{}>", value)
             }
+            RunnerError::InvalidJsonSchema { value } => {
+                format!("the JSON schema is not valid: {}", value)
+            }
+            RunnerError::AssertJsonSchema { schema, violations } => {
+                let violations = violations
+                    .iter()
+                    .map(|(pointer, message)| {
+                        let pointer = if pointer.is_empty() { "/" } else { pointer };
+                        format!("\n  {}: {}", pointer, message)
+                    })
+                    .collect::<String>();
+                format!(
+                    "value is not valid against schema {}:{}",
+                    schema, violations
+                )
+            }
+            RunnerError::InvalidXmlSchema { value } => {
+                format!("the XML schema is not valid: {}", value)
+            }
+            RunnerError::AssertXmlSchema { schema, violations } => {
+                let violations = violations
+                    .iter()
+                    .map(|(line, message)| format!("\n  line {}: {}", line, message))
+                    .collect::<String>();
+                format!(
+                    "value is not valid against schema {}:{}",
+                    schema, violations
+                )
+            }
             RunnerError::UnauthorizedFileAccess { path } => {
                 format!(
                     "Unauthorized access to file {}, check --file-root option",
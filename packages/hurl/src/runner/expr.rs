@@ -15,23 +15,173 @@
  * limitations under the License.
  *
  */
+use std::cell::RefCell;
 use std::collections::HashMap;
 
-use hurl_core::ast::Expr;
+use chrono::Utc;
+use hurl_core::ast::{Expr, ExprKind, Function, FunctionValue};
 
 use super::core::{Error, RunnerError};
 use super::value::Value;
 
 pub fn eval_expr(expr: Expr, variables: &HashMap<String, Value>) -> Result<Value, Error> {
-    if let Some(value) = variables.get(expr.variable.name.as_str()) {
-        Ok(value.clone())
-    } else {
-        Err(Error {
-            source_info: expr.variable.source_info,
-            inner: RunnerError::TemplateVariableNotDefined {
-                name: expr.variable.name,
-            },
-            assert: false,
-        })
+    match expr.kind {
+        ExprKind::Variable(variable) => {
+            if let Some(value) = variables.get(variable.name.as_str()) {
+                Ok(value.clone())
+            } else {
+                Err(Error {
+                    source_info: variable.source_info,
+                    inner: RunnerError::TemplateVariableNotDefined {
+                        name: variable.name,
+                    },
+                    assert: false,
+                })
+            }
+        }
+        // A user variable takes precedence over a generator function of the same name.
+        ExprKind::Function(function) => match variables.get(function.value.name()) {
+            Some(value) if !matches!(function.value, FunctionValue::Env { .. }) => {
+                Ok(value.clone())
+            }
+            _ => eval_function(function),
+        },
+    }
+}
+
+thread_local! {
+    /// Values generated by the functions since the last call to [`take_generated_values`].
+    static GENERATED_VALUES: RefCell<Vec<(String, Value)>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Returns the values generated by `newDate`, `newUuid` and `randomInt` in the current thread,
+/// in evaluation order, and clears them.
+pub fn take_generated_values() -> Vec<(String, Value)> {
+    GENERATED_VALUES.with(|values| values.borrow_mut().drain(..).collect())
+}
+
+/// Evaluates a generator function, a new value being generated at each call.
+fn eval_function(function: Function) -> Result<Value, Error> {
+    let value = match &function.value {
+        FunctionValue::Env { name, .. } => {
+            return match std::env::var(name.as_str()) {
+                Ok(value) => Ok(Value::String(value)),
+                Err(_) => Err(Error {
+                    source_info: function.source_info,
+                    inner: RunnerError::EnvironmentVariableNotDefined { name: name.clone() },
+                    assert: false,
+                }),
+            }
+        }
+        FunctionValue::NewDate {} => Value::Date(Utc::now()),
+        FunctionValue::NewUuid {} => Value::String(uuid::Uuid::new_v4().to_string()),
+        FunctionValue::RandomInt {} => Value::Integer(rand::random::<u32>() as i64),
+    };
+    let name = function.value.name().to_string();
+    GENERATED_VALUES.with(|values| values.borrow_mut().push((name, value.clone())));
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hurl_core::ast::{SourceInfo, Variable, Whitespace};
+
+    fn whitespace() -> Whitespace {
+        Whitespace {
+            value: String::from(""),
+            source_info: SourceInfo::init(0, 0, 0, 0),
+        }
+    }
+
+    fn function_expr(value: FunctionValue) -> Expr {
+        Expr {
+            space0: whitespace(),
+            kind: ExprKind::Function(Function {
+                source_info: SourceInfo::init(1, 3, 1, 10),
+                value,
+            }),
+            space1: whitespace(),
+        }
+    }
+
+    #[test]
+    fn test_variable() {
+        let mut variables = HashMap::new();
+        variables.insert("name".to_string(), Value::String("Bob".to_string()));
+        let expr = Expr {
+            space0: whitespace(),
+            kind: ExprKind::Variable(Variable {
+                name: "name".to_string(),
+                source_info: SourceInfo::init(1, 3, 1, 7),
+            }),
+            space1: whitespace(),
+        };
+        assert_eq!(
+            eval_expr(expr, &variables).unwrap(),
+            Value::String("Bob".to_string())
+        );
+    }
+
+    #[test]
+    fn test_new_uuid() {
+        let variables = HashMap::new();
+        let uuid1 = eval_expr(function_expr(FunctionValue::NewUuid {}), &variables).unwrap();
+        let uuid2 = eval_expr(function_expr(FunctionValue::NewUuid {}), &variables).unwrap();
+        assert_eq!(uuid1.to_string().len(), 36);
+        assert_ne!(uuid1, uuid2);
+    }
+
+    #[test]
+    fn test_generated_values() {
+        let variables = HashMap::new();
+        take_generated_values();
+        let uuid = eval_expr(function_expr(FunctionValue::NewUuid {}), &variables).unwrap();
+        let int = eval_expr(function_expr(FunctionValue::RandomInt {}), &variables).unwrap();
+        assert_eq!(
+            take_generated_values(),
+            vec![
+                ("newUuid".to_string(), uuid),
+                ("randomInt".to_string(), int)
+            ]
+        );
+        assert!(take_generated_values().is_empty());
+    }
+
+    #[test]
+    fn test_variable_shadows_function() {
+        let mut variables = HashMap::new();
+        variables.insert("newUuid".to_string(), Value::String("my-id".to_string()));
+        assert_eq!(
+            eval_expr(function_expr(FunctionValue::NewUuid {}), &variables).unwrap(),
+            Value::String("my-id".to_string())
+        );
+    }
+
+    #[test]
+    fn test_env() {
+        let variables = HashMap::new();
+        std::env::set_var("HURL_TEST_EXPR_ENV", "value");
+        let expr = function_expr(FunctionValue::Env {
+            space0: whitespace(),
+            name: "HURL_TEST_EXPR_ENV".to_string(),
+        });
+        assert_eq!(
+            eval_expr(expr, &variables).unwrap(),
+            Value::String("value".to_string())
+        );
+
+        let expr = function_expr(FunctionValue::Env {
+            space0: whitespace(),
+            name: "HURL_TEST_EXPR_UNDEFINED".to_string(),
+        });
+        let error = eval_expr(expr, &variables).err().unwrap();
+        assert_eq!(error.source_info, SourceInfo::init(1, 3, 1, 10));
+        assert_eq!(
+            error.inner,
+            RunnerError::EnvironmentVariableNotDefined {
+                name: "HURL_TEST_EXPR_UNDEFINED".to_string()
+            }
+        );
     }
 }
@@ -0,0 +1,793 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2022 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use std::collections::HashMap;
+
+use chrono::format::{Item, StrftimeItems};
+use chrono::{DateTime, NaiveDate, NaiveDateTime, TimeZone, Utc};
+use percent_encoding::AsciiSet;
+use regex::Regex;
+
+use super::core::{Error, RunnerError};
+use super::template::eval_template;
+use super::value::Value;
+use hurl_core::ast::*;
+
+/// Characters that are not percent-encoded by the urlEncode filter
+/// (unreserved characters from RFC 3986).
+const URL_ENCODE_SET: &AsciiSet = &percent_encoding::NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'_')
+    .remove(b'~');
+
+/// Applies the chain of filters to a query value, in order.
+pub fn eval_filters(
+    filters: &[(Whitespace, Filter)],
+    value: Option<Value>,
+    variables: &HashMap<String, Value>,
+) -> Result<Option<Value>, Error> {
+    let mut value = value;
+    for (_, filter) in filters {
+        value = match value {
+            Some(value) => eval_filter(filter, value, variables)?,
+            None => {
+                return Err(Error {
+                    source_info: filter.source_info.clone(),
+                    inner: RunnerError::FilterMissingInput,
+                    assert: false,
+                })
+            }
+        };
+    }
+    Ok(value)
+}
+
+pub fn eval_filter(
+    filter: &Filter,
+    value: Value,
+    variables: &HashMap<String, Value>,
+) -> Result<Option<Value>, Error> {
+    let source_info = filter.source_info.clone();
+    match &filter.value {
+        FilterValue::Base64Decode {} => eval_base64_decode(value, source_info),
+        FilterValue::Count {} => eval_count(value, source_info),
+        FilterValue::DaysAfterNow {} => eval_days_after_now(value, source_info),
+        FilterValue::DaysBeforeNow {} => eval_days_before_now(value, source_info),
+        FilterValue::Format { fmt, .. } => eval_format(value, fmt, variables, source_info),
+        FilterValue::HtmlEscape {} => eval_html_escape(value, source_info),
+        FilterValue::HtmlUnescape {} => eval_html_unescape(value, source_info),
+        FilterValue::Nth { n, .. } => eval_nth(value, *n, source_info),
+        FilterValue::Regex { expr, .. } => eval_regex(value, expr, variables, source_info),
+        FilterValue::Replace {
+            old_value,
+            new_value,
+            ..
+        } => eval_replace(value, old_value, new_value, variables, source_info),
+        FilterValue::Split { sep, .. } => eval_split(value, sep, variables, source_info),
+        FilterValue::ToDate { fmt, .. } => eval_to_date(value, fmt, variables, source_info),
+        FilterValue::ToFloat {} => eval_to_float(value, source_info),
+        FilterValue::ToInt {} => eval_to_int(value, source_info),
+        FilterValue::UrlDecode {} => eval_url_decode(value, source_info),
+        FilterValue::UrlEncode {} => eval_url_encode(value, source_info),
+    }
+}
+
+fn invalid_input(value: &Value, source_info: SourceInfo) -> Error {
+    Error {
+        source_info,
+        inner: RunnerError::FilterInvalidInput(value._type()),
+        assert: false,
+    }
+}
+
+fn eval_base64_decode(value: Value, source_info: SourceInfo) -> Result<Option<Value>, Error> {
+    match value {
+        Value::String(s) => match base64::decode(s.as_str()) {
+            Ok(bytes) => Ok(Some(Value::Bytes(bytes))),
+            Err(_) => Err(Error {
+                source_info,
+                inner: RunnerError::FilterInvalidInput(format!("invalid base64 <{}>", s)),
+                assert: false,
+            }),
+        },
+        v => Err(invalid_input(&v, source_info)),
+    }
+}
+
+fn eval_count(value: Value, source_info: SourceInfo) -> Result<Option<Value>, Error> {
+    match value {
+        Value::List(values) => Ok(Some(Value::Integer(values.len() as i64))),
+        Value::Bytes(values) => Ok(Some(Value::Integer(values.len() as i64))),
+        Value::Nodeset(size) => Ok(Some(Value::Integer(size as i64))),
+        v => Err(invalid_input(&v, source_info)),
+    }
+}
+
+fn eval_days_after_now(value: Value, source_info: SourceInfo) -> Result<Option<Value>, Error> {
+    match value {
+        Value::Date(date) => Ok(Some(Value::Integer((date - Utc::now()).num_days()))),
+        v => Err(invalid_input(&v, source_info)),
+    }
+}
+
+fn eval_days_before_now(value: Value, source_info: SourceInfo) -> Result<Option<Value>, Error> {
+    match value {
+        Value::Date(date) => Ok(Some(Value::Integer((Utc::now() - date).num_days()))),
+        v => Err(invalid_input(&v, source_info)),
+    }
+}
+
+/// Formats a scalar value, each `{}` in the format being replaced by the value.
+/// Dates are formatted with a strftime format (`%Y-%m-%d` for instance).
+fn eval_format(
+    value: Value,
+    fmt: &Template,
+    variables: &HashMap<String, Value>,
+    source_info: SourceInfo,
+) -> Result<Option<Value>, Error> {
+    let fmt = eval_template(fmt, variables)?;
+    match value {
+        Value::String(_) | Value::Integer(_) | Value::Float(_) | Value::Bool(_) => Ok(Some(
+            Value::String(fmt.replace("{}", value.to_string().as_str())),
+        )),
+        Value::Date(date) => {
+            let items = StrftimeItems::new(fmt.as_str()).collect::<Vec<Item>>();
+            if items.contains(&Item::Error) {
+                return Err(Error {
+                    source_info,
+                    inner: RunnerError::FilterInvalidInput(format!(
+                        "invalid date format <{}>",
+                        fmt
+                    )),
+                    assert: false,
+                });
+            }
+            Ok(Some(Value::String(
+                date.format_with_items(items.into_iter()).to_string(),
+            )))
+        }
+        v => Err(invalid_input(&v, source_info)),
+    }
+}
+
+fn eval_html_escape(value: Value, source_info: SourceInfo) -> Result<Option<Value>, Error> {
+    match value {
+        Value::String(s) => Ok(Some(Value::String(html_escape(s.as_str())))),
+        v => Err(invalid_input(&v, source_info)),
+    }
+}
+
+fn eval_html_unescape(value: Value, source_info: SourceInfo) -> Result<Option<Value>, Error> {
+    match value {
+        Value::String(s) => Ok(Some(Value::String(html_unescape(s.as_str())))),
+        v => Err(invalid_input(&v, source_info)),
+    }
+}
+
+fn eval_nth(value: Value, n: u64, source_info: SourceInfo) -> Result<Option<Value>, Error> {
+    match value {
+        Value::List(values) => match values.get(n as usize) {
+            Some(value) => Ok(Some(value.clone())),
+            None => Err(Error {
+                source_info,
+                inner: RunnerError::FilterInvalidInput(format!(
+                    "index {} out of bounds for a list of size {}",
+                    n,
+                    values.len()
+                )),
+                assert: false,
+            }),
+        },
+        v => Err(invalid_input(&v, source_info)),
+    }
+}
+
+fn eval_regex(
+    value: Value,
+    expr: &Template,
+    variables: &HashMap<String, Value>,
+    source_info: SourceInfo,
+) -> Result<Option<Value>, Error> {
+    let templ = eval_template(expr, variables)?;
+    match value {
+        Value::String(s) => match Regex::new(templ.as_str()) {
+            Ok(re) => match re.captures(s.as_str()) {
+                Some(captures) => match captures.get(1) {
+                    Some(v) => Ok(Some(Value::String(v.as_str().to_string()))),
+                    None => Ok(None),
+                },
+                None => Ok(None),
+            },
+            Err(_) => Err(Error {
+                source_info: expr.source_info.clone(),
+                inner: RunnerError::InvalidRegex(),
+                assert: false,
+            }),
+        },
+        v => Err(invalid_input(&v, source_info)),
+    }
+}
+
+fn eval_replace(
+    value: Value,
+    old_value: &Template,
+    new_value: &Template,
+    variables: &HashMap<String, Value>,
+    source_info: SourceInfo,
+) -> Result<Option<Value>, Error> {
+    let old_value = eval_template(old_value, variables)?;
+    let new_value = eval_template(new_value, variables)?;
+    match value {
+        Value::String(s) => Ok(Some(Value::String(
+            s.replace(old_value.as_str(), new_value.as_str()),
+        ))),
+        v => Err(invalid_input(&v, source_info)),
+    }
+}
+
+fn eval_split(
+    value: Value,
+    sep: &Template,
+    variables: &HashMap<String, Value>,
+    source_info: SourceInfo,
+) -> Result<Option<Value>, Error> {
+    let sep = eval_template(sep, variables)?;
+    match value {
+        Value::String(s) => {
+            let values = s
+                .split(sep.as_str())
+                .map(|v| Value::String(v.to_string()))
+                .collect();
+            Ok(Some(Value::List(values)))
+        }
+        v => Err(invalid_input(&v, source_info)),
+    }
+}
+
+/// Parses a string to a date with a strftime format.
+/// Dates without timezone are considered as UTC, and dates without time at midnight.
+fn eval_to_date(
+    value: Value,
+    fmt: &Template,
+    variables: &HashMap<String, Value>,
+    source_info: SourceInfo,
+) -> Result<Option<Value>, Error> {
+    let fmt = eval_template(fmt, variables)?;
+    match value {
+        Value::String(s) => match parse_date(s.as_str(), fmt.as_str()) {
+            Some(date) => Ok(Some(Value::Date(date))),
+            None => Err(Error {
+                source_info,
+                inner: RunnerError::FilterInvalidInput(format!(
+                    "invalid date <{}> for format <{}>",
+                    s, fmt
+                )),
+                assert: false,
+            }),
+        },
+        v => Err(invalid_input(&v, source_info)),
+    }
+}
+
+fn parse_date(s: &str, fmt: &str) -> Option<DateTime<Utc>> {
+    if let Ok(date) = DateTime::parse_from_str(s, fmt) {
+        return Some(date.with_timezone(&Utc));
+    }
+    if let Ok(date) = NaiveDateTime::parse_from_str(s, fmt) {
+        return Some(Utc.from_utc_datetime(&date));
+    }
+    if let Ok(date) = NaiveDate::parse_from_str(s, fmt) {
+        return Some(Utc.from_utc_datetime(&date.and_hms_opt(0, 0, 0)?));
+    }
+    None
+}
+
+fn eval_to_float(value: Value, source_info: SourceInfo) -> Result<Option<Value>, Error> {
+    match value {
+        Value::Float(f) => Ok(Some(Value::Float(f))),
+        Value::Integer(i) => Ok(Some(Value::Float(i as f64))),
+        Value::String(s) => match s.trim().parse::<f64>() {
+            Ok(f) => Ok(Some(Value::Float(f))),
+            Err(_) => Err(Error {
+                source_info,
+                inner: RunnerError::FilterInvalidInput(format!("invalid float <{}>", s)),
+                assert: false,
+            }),
+        },
+        v => Err(invalid_input(&v, source_info)),
+    }
+}
+
+fn eval_to_int(value: Value, source_info: SourceInfo) -> Result<Option<Value>, Error> {
+    match value {
+        Value::Integer(i) => Ok(Some(Value::Integer(i))),
+        Value::Float(f) => Ok(Some(Value::Integer(f as i64))),
+        Value::String(s) => match s.trim().parse::<i64>() {
+            Ok(i) => Ok(Some(Value::Integer(i))),
+            Err(_) => Err(Error {
+                source_info,
+                inner: RunnerError::FilterInvalidInput(format!("invalid integer <{}>", s)),
+                assert: false,
+            }),
+        },
+        v => Err(invalid_input(&v, source_info)),
+    }
+}
+
+fn eval_url_decode(value: Value, source_info: SourceInfo) -> Result<Option<Value>, Error> {
+    match value {
+        Value::String(s) => match percent_encoding::percent_decode_str(s.as_str()).decode_utf8() {
+            Ok(decoded) => Ok(Some(Value::String(decoded.to_string()))),
+            Err(_) => Err(Error {
+                source_info,
+                inner: RunnerError::FilterInvalidInput(format!("invalid utf-8 in <{}>", s)),
+                assert: false,
+            }),
+        },
+        v => Err(invalid_input(&v, source_info)),
+    }
+}
+
+fn eval_url_encode(value: Value, source_info: SourceInfo) -> Result<Option<Value>, Error> {
+    match value {
+        Value::String(s) => {
+            let encoded = percent_encoding::utf8_percent_encode(s.as_str(), URL_ENCODE_SET);
+            Ok(Some(Value::String(encoded.to_string())))
+        }
+        v => Err(invalid_input(&v, source_info)),
+    }
+}
+
+fn html_escape(s: &str) -> String {
+    let mut escaped = String::new();
+    for c in s.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#x27;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Unescapes the basic named character references and
+/// the decimal/hexadecimal numeric character references.
+fn html_unescape(s: &str) -> String {
+    let mut unescaped = String::with_capacity(s.len());
+    let mut rest = s;
+    while let Some(start) = rest.find('&') {
+        unescaped.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let reference = rest[1..]
+            .find(';')
+            .and_then(|end| character_reference(&rest[1..end + 1]).map(|c| (c, end + 2)));
+        match reference {
+            Some((c, len)) => {
+                unescaped.push(c);
+                rest = &rest[len..];
+            }
+            None => {
+                unescaped.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    unescaped.push_str(rest);
+    unescaped
+}
+
+/// Returns the character of a reference (without its leading `&` and trailing `;`).
+fn character_reference(reference: &str) -> Option<char> {
+    match reference {
+        "amp" => Some('&'),
+        "lt" => Some('<'),
+        "gt" => Some('>'),
+        "quot" => Some('"'),
+        "apos" => Some('\''),
+        "nbsp" => Some('\u{a0}'),
+        _ => {
+            let (digits, radix) = match reference.strip_prefix('#') {
+                Some(hex) if hex.starts_with('x') || hex.starts_with('X') => (&hex[1..], 16),
+                Some(decimal) => (decimal, 10),
+                None => return None,
+            };
+            if digits.is_empty() || !digits.chars().all(|c| c.is_digit(radix)) {
+                return None;
+            }
+            u32::from_str_radix(digits, radix)
+                .ok()
+                .and_then(char::from_u32)
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use hurl_core::ast::SourceInfo;
+
+    fn whitespace() -> Whitespace {
+        Whitespace {
+            value: String::from(""),
+            source_info: SourceInfo::init(0, 0, 0, 0),
+        }
+    }
+
+    fn template(s: &str) -> Template {
+        Template {
+            quotes: false,
+            elements: vec![TemplateElement::String {
+                value: s.to_string(),
+                encoded: s.to_string(),
+            }],
+            source_info: SourceInfo::init(1, 7, 1, 20),
+        }
+    }
+
+    fn filter(value: FilterValue) -> Filter {
+        Filter {
+            source_info: SourceInfo::init(1, 1, 1, 20),
+            value,
+        }
+    }
+
+    #[test]
+    fn test_filter_regex() {
+        // regex "Hello (.*)!"
+        let variables = HashMap::new();
+        let filter = filter(FilterValue::Regex {
+            space0: whitespace(),
+            expr: template("Hello (.*)!"),
+        });
+        assert_eq!(
+            eval_filter(&filter, Value::String("Hello Bob!".to_string()), &variables)
+                .unwrap()
+                .unwrap(),
+            Value::String("Bob".to_string())
+        );
+
+        let error = eval_filter(&filter, Value::Bool(true), &variables)
+            .err()
+            .unwrap();
+        assert_eq!(error.source_info, SourceInfo::init(1, 1, 1, 20));
+        assert_eq!(
+            error.inner,
+            RunnerError::FilterInvalidInput("boolean".to_string())
+        );
+    }
+
+    #[test]
+    fn test_filter_invalid_regex() {
+        let variables = HashMap::new();
+        let filter = filter(FilterValue::Regex {
+            space0: whitespace(),
+            expr: template("???"),
+        });
+        let error = eval_filter(&filter, Value::String("Hello Bob!".to_string()), &variables)
+            .err()
+            .unwrap();
+        assert_eq!(error.source_info, SourceInfo::init(1, 7, 1, 20));
+        assert_eq!(error.inner, RunnerError::InvalidRegex {});
+    }
+
+    #[test]
+    fn test_filter_count() {
+        let variables = HashMap::new();
+        let filter = filter(FilterValue::Count {});
+        assert_eq!(
+            eval_filter(
+                &filter,
+                Value::List(vec![
+                    Value::Integer(1),
+                    Value::Integer(2),
+                    Value::Integer(3)
+                ]),
+                &variables
+            )
+            .unwrap()
+            .unwrap(),
+            Value::Integer(3)
+        );
+
+        let error = eval_filter(&filter, Value::Bool(true), &variables)
+            .err()
+            .unwrap();
+        assert_eq!(error.source_info, SourceInfo::init(1, 1, 1, 20));
+        assert_eq!(
+            error.inner,
+            RunnerError::FilterInvalidInput("boolean".to_string())
+        );
+    }
+
+    #[test]
+    fn test_filters_chain() {
+        // split "," nth 1 toInt
+        let variables = HashMap::new();
+        let filters = vec![
+            (
+                whitespace(),
+                filter(FilterValue::Split {
+                    space0: whitespace(),
+                    sep: template(","),
+                }),
+            ),
+            (
+                whitespace(),
+                filter(FilterValue::Nth {
+                    space0: whitespace(),
+                    n: 1,
+                }),
+            ),
+            (whitespace(), filter(FilterValue::ToInt {})),
+        ];
+        assert_eq!(
+            eval_filters(
+                &filters,
+                Some(Value::String("1,2,3".to_string())),
+                &variables
+            )
+            .unwrap()
+            .unwrap(),
+            Value::Integer(2)
+        );
+
+        let error = eval_filters(&filters, None, &variables).err().unwrap();
+        assert_eq!(error.inner, RunnerError::FilterMissingInput);
+    }
+
+    #[test]
+    fn test_filter_nth() {
+        let variables = HashMap::new();
+        let filter = filter(FilterValue::Nth {
+            space0: whitespace(),
+            n: 2,
+        });
+        let error = eval_filter(
+            &filter,
+            Value::List(vec![Value::Integer(1), Value::Integer(2)]),
+            &variables,
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            error.inner,
+            RunnerError::FilterInvalidInput(
+                "index 2 out of bounds for a list of size 2".to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn test_filter_url() {
+        let variables = HashMap::new();
+        assert_eq!(
+            eval_filter(
+                &filter(FilterValue::UrlEncode {}),
+                Value::String("https://mozilla.org/?x=шеллы".to_string()),
+                &variables
+            )
+            .unwrap()
+            .unwrap(),
+            Value::String(
+                "https%3A%2F%2Fmozilla.org%2F%3Fx%3D%D1%88%D0%B5%D0%BB%D0%BB%D1%8B".to_string()
+            )
+        );
+        assert_eq!(
+            eval_filter(
+                &filter(FilterValue::UrlDecode {}),
+                Value::String("https://mozilla.org/?x=%D1%88%D0%B5%D0%BB%D0%BB%D1%8B".to_string()),
+                &variables
+            )
+            .unwrap()
+            .unwrap(),
+            Value::String("https://mozilla.org/?x=шеллы".to_string())
+        );
+    }
+
+    #[test]
+    fn test_filter_html() {
+        let variables = HashMap::new();
+        assert_eq!(
+            eval_filter(
+                &filter(FilterValue::HtmlEscape {}),
+                Value::String("a > b && \"c\"".to_string()),
+                &variables
+            )
+            .unwrap()
+            .unwrap(),
+            Value::String("a &gt; b &amp;&amp; &quot;c&quot;".to_string())
+        );
+        assert_eq!(
+            eval_filter(
+                &filter(FilterValue::HtmlUnescape {}),
+                Value::String("a &gt; b &&amp; &#x27;c&#39; &unknown; &#xZZ; &#;".to_string()),
+                &variables
+            )
+            .unwrap()
+            .unwrap(),
+            Value::String("a > b && 'c' &unknown; &#xZZ; &#;".to_string())
+        );
+    }
+
+    #[test]
+    fn test_filter_base64_decode() {
+        let variables = HashMap::new();
+        assert_eq!(
+            eval_filter(
+                &filter(FilterValue::Base64Decode {}),
+                Value::String("SGVsbG8=".to_string()),
+                &variables
+            )
+            .unwrap()
+            .unwrap(),
+            Value::Bytes(b"Hello".to_vec())
+        );
+    }
+
+    #[test]
+    fn test_filter_to_number() {
+        let variables = HashMap::new();
+        assert_eq!(
+            eval_filter(
+                &filter(FilterValue::ToFloat {}),
+                Value::String("1.5".to_string()),
+                &variables
+            )
+            .unwrap()
+            .unwrap(),
+            Value::Float(1.5)
+        );
+        let error = eval_filter(
+            &filter(FilterValue::ToInt {}),
+            Value::String("1.5".to_string()),
+            &variables,
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            error.inner,
+            RunnerError::FilterInvalidInput("invalid integer <1.5>".to_string())
+        );
+    }
+
+    #[test]
+    fn test_filter_format_replace() {
+        let variables = HashMap::new();
+        assert_eq!(
+            eval_filter(
+                &filter(FilterValue::Format {
+                    space0: whitespace(),
+                    fmt: template("id-{}"),
+                }),
+                Value::Integer(42),
+                &variables
+            )
+            .unwrap()
+            .unwrap(),
+            Value::String("id-42".to_string())
+        );
+        assert_eq!(
+            eval_filter(
+                &filter(FilterValue::Replace {
+                    space0: whitespace(),
+                    old_value: template(","),
+                    space1: whitespace(),
+                    new_value: template(";"),
+                }),
+                Value::String("1,2,3".to_string()),
+                &variables
+            )
+            .unwrap()
+            .unwrap(),
+            Value::String("1;2;3".to_string())
+        );
+    }
+
+    #[test]
+    fn test_filter_to_date() {
+        let variables = HashMap::new();
+        let to_date = |fmt: &str, s: &str| {
+            eval_filter(
+                &filter(FilterValue::ToDate {
+                    space0: whitespace(),
+                    fmt: template(fmt),
+                }),
+                Value::String(s.to_string()),
+                &variables,
+            )
+        };
+        let expected = Value::Date(parse_date("2022-01-06T17:30:00Z", "%+").unwrap());
+        assert_eq!(
+            to_date("%a, %d %b %Y %H:%M:%S GMT", "Thu, 06 Jan 2022 17:30:00 GMT")
+                .unwrap()
+                .unwrap(),
+            expected
+        );
+        assert_eq!(
+            to_date("%Y-%m-%dT%H:%M:%S%z", "2022-01-06T18:30:00+0100")
+                .unwrap()
+                .unwrap(),
+            expected
+        );
+        assert_eq!(
+            to_date("%Y-%m-%d", "2022-01-06").unwrap().unwrap(),
+            Value::Date(parse_date("2022-01-06T00:00:00Z", "%+").unwrap())
+        );
+        assert_eq!(
+            to_date("%Y-%m-%d", "06/01/2022").err().unwrap().inner,
+            RunnerError::FilterInvalidInput(
+                "invalid date <06/01/2022> for format <%Y-%m-%d>".to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn test_filter_days_after_before_now() {
+        let variables = HashMap::new();
+        let date = Value::Date(Utc::now() + chrono::Duration::hours(24 * 10 + 1));
+        assert_eq!(
+            eval_filter(
+                &filter(FilterValue::DaysAfterNow {}),
+                date.clone(),
+                &variables
+            )
+            .unwrap()
+            .unwrap(),
+            Value::Integer(10)
+        );
+        assert_eq!(
+            eval_filter(&filter(FilterValue::DaysBeforeNow {}), date, &variables)
+                .unwrap()
+                .unwrap(),
+            Value::Integer(-10)
+        );
+        assert_eq!(
+            eval_filter(
+                &filter(FilterValue::DaysAfterNow {}),
+                Value::String("2022-01-06".to_string()),
+                &variables
+            )
+            .err()
+            .unwrap()
+            .inner,
+            RunnerError::FilterInvalidInput("string".to_string())
+        );
+    }
+
+    #[test]
+    fn test_filter_format_date() {
+        let variables = HashMap::new();
+        let date = Value::Date(parse_date("2022-01-06T17:30:00Z", "%+").unwrap());
+        assert_eq!(
+            eval_filter(
+                &filter(FilterValue::Format {
+                    space0: whitespace(),
+                    fmt: template("%d/%m/%Y"),
+                }),
+                date,
+                &variables
+            )
+            .unwrap()
+            .unwrap(),
+            Value::String("06/01/2022".to_string())
+        );
+    }
+}
@@ -121,6 +121,7 @@ pub mod tests {
             headers: vec![],
             body: b"Hello World!".to_vec(),
             duration: Default::default(),
+            ..Default::default()
         }
     }
 
@@ -134,6 +135,7 @@ pub mod tests {
             }],
             body: vec![0x63, 0x61, 0x66, 0xc3, 0xa9],
             duration: Default::default(),
+            ..Default::default()
         }
     }
 
@@ -147,6 +149,7 @@ pub mod tests {
             }],
             body: vec![0x63, 0x61, 0x66, 0xe9],
             duration: Default::default(),
+            ..Default::default()
         }
     }
 
@@ -194,7 +197,8 @@ pub mod tests {
                     value: "test/plain; charset=xxx".to_string()
                 }],
                 body: b"Hello World!".to_vec(),
-                duration: Default::default()
+                duration: Default::default(),
+                ..Default::default()
             }
             .encoding()
             .err()
@@ -213,7 +217,8 @@ pub mod tests {
                 status: 200,
                 headers: vec![],
                 body: vec![0x63, 0x61, 0x66, 0xe9],
-                duration: Default::default()
+                duration: Default::default(),
+                ..Default::default()
             }
             .text()
             .err()
@@ -232,7 +237,8 @@ pub mod tests {
                     value: "text/plain; charset=ISO-8859-1".to_string()
                 }],
                 body: vec![0x63, 0x61, 0x66, 0xc3, 0xa9],
-                duration: Default::default()
+                duration: Default::default(),
+                ..Default::default()
             }
             .text()
             .unwrap(),
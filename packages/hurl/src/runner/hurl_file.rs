@@ -30,6 +30,7 @@ use super::entry;
 ///
 /// ```
 /// use std::path::PathBuf;
+/// use std::time::Duration;
 /// use hurl_core::parser;
 /// use hurl::http;
 /// use hurl::runner;
@@ -59,6 +60,8 @@ use super::entry;
 ///        to_entry: None,
 ///        context_dir: PathBuf::new(),
 ///        ignore_asserts: false,
+///        retry: 0,
+///        retry_interval: Duration::from_millis(1000),
 ///        pre_entry: |_| true,
 ///        post_entry: || true,
 ///  };
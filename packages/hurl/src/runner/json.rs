@@ -71,7 +71,7 @@ pub fn eval_json_value(
                 return Ok(s);
             }
             Err(Error {
-                source_info: exp.variable.source_info,
+                source_info: exp.source_info(),
                 inner: RunnerError::InvalidJson { value: s },
                 assert: false,
             })
@@ -147,10 +147,10 @@ mod tests {
                         value: "".to_string(),
                         source_info: SourceInfo::init(1, 15, 1, 15),
                     },
-                    variable: Variable {
+                    kind: ExprKind::Variable(Variable {
                         name: "name".to_string(),
                         source_info: SourceInfo::init(1, 15, 1, 19),
-                    },
+                    }),
                     space1: Whitespace {
                         value: "".to_string(),
                         source_info: SourceInfo::init(1, 19, 1, 19),
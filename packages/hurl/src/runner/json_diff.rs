@@ -0,0 +1,267 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2022 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use serde_json::Value;
+
+/// Compares an expected JSON value with an actual one, regardless of object key order
+/// and formatting. Returns the path and a description of each difference, object members
+/// named in `ignored_keys` being skipped at any depth.
+pub fn json_diff(
+    expected: &Value,
+    actual: &Value,
+    ignored_keys: &[String],
+) -> Vec<(String, String)> {
+    let options = DiffOptions {
+        ignored_keys,
+        subset: false,
+    };
+    let mut diff = vec![];
+    diff_value("$", expected, actual, &options, &mut diff);
+    diff
+}
+
+/// Checks that an actual JSON value includes an expected one: objects may have additional
+/// members, and each element of an expected array must be included in an element of the
+/// actual array, in any order. Returns the path and a description of each difference.
+pub fn json_includes(expected: &Value, actual: &Value) -> Vec<(String, String)> {
+    let options = DiffOptions {
+        ignored_keys: &[],
+        subset: true,
+    };
+    let mut diff = vec![];
+    diff_value("$", expected, actual, &options, &mut diff);
+    diff
+}
+
+struct DiffOptions<'a> {
+    ignored_keys: &'a [String],
+    /// Only checks that the expected value is included in the actual one
+    subset: bool,
+}
+
+fn diff_value(
+    path: &str,
+    expected: &Value,
+    actual: &Value,
+    options: &DiffOptions,
+    diff: &mut Vec<(String, String)>,
+) {
+    match (expected, actual) {
+        (Value::Object(expected), Value::Object(actual)) => {
+            for (key, expected_value) in expected {
+                if options.ignored_keys.contains(key) {
+                    continue;
+                }
+                let path = member_path(path, key);
+                match actual.get(key) {
+                    Some(actual_value) => {
+                        diff_value(&path, expected_value, actual_value, options, diff)
+                    }
+                    None => diff.push((path, format!("missing, expected <{}>", expected_value))),
+                }
+            }
+            if options.subset {
+                return;
+            }
+            for (key, actual_value) in actual {
+                if options.ignored_keys.contains(key) || expected.contains_key(key) {
+                    continue;
+                }
+                diff.push((
+                    member_path(path, key),
+                    format!("unexpected <{}>", actual_value),
+                ));
+            }
+        }
+        (Value::Array(expected), Value::Array(actual)) if options.subset => {
+            for expected_value in expected {
+                let included = actual.iter().any(|actual_value| {
+                    let mut element_diff = vec![];
+                    diff_value(
+                        path,
+                        expected_value,
+                        actual_value,
+                        options,
+                        &mut element_diff,
+                    );
+                    element_diff.is_empty()
+                });
+                if !included {
+                    diff.push((
+                        path.to_string(),
+                        format!("missing element <{}>", expected_value),
+                    ));
+                }
+            }
+        }
+        (Value::Array(expected), Value::Array(actual)) => {
+            for (i, (expected_value, actual_value)) in expected.iter().zip(actual).enumerate() {
+                let path = format!("{}[{}]", path, i);
+                diff_value(&path, expected_value, actual_value, options, diff);
+            }
+            if expected.len() != actual.len() {
+                diff.push((
+                    path.to_string(),
+                    format!(
+                        "expected {} elements, actual {}",
+                        expected.len(),
+                        actual.len()
+                    ),
+                ));
+            }
+        }
+        (Value::Number(expected_number), Value::Number(actual_number)) => {
+            // 1 and 1.0 are the same number
+            let equal = if expected_number.is_f64() || actual_number.is_f64() {
+                expected_number.as_f64() == actual_number.as_f64()
+            } else {
+                expected_number == actual_number
+            };
+            if !equal {
+                diff.push((
+                    path.to_string(),
+                    format!("expected <{}>, actual <{}>", expected, actual),
+                ));
+            }
+        }
+        _ => {
+            if expected != actual {
+                diff.push((
+                    path.to_string(),
+                    format!("expected <{}>, actual <{}>", expected, actual),
+                ));
+            }
+        }
+    }
+}
+
+/// Returns the JSONPath of an object member.
+fn member_path(path: &str, key: &str) -> String {
+    if !key.is_empty() && key.chars().all(|c| c.is_alphanumeric() || c == '_') {
+        format!("{}.{}", path, key)
+    } else {
+        format!("{}['{}']", path, key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_json_diff_equal() {
+        let expected = json!({"id": 1, "name": "Bob", "tags": ["a", "b"], "score": 1.0});
+        let actual: Value =
+            serde_json::from_str(r#"{ "score": 1, "tags": ["a","b"], "name": "Bob", "id": 1 }"#)
+                .unwrap();
+        assert!(json_diff(&expected, &actual, &[]).is_empty());
+    }
+
+    #[test]
+    fn test_json_diff() {
+        let expected = json!({
+            "id": 1,
+            "name": "Bob",
+            "address": {"city": "Paris", "zip": "75001"},
+            "tags": ["a", "b"],
+            "created-at": "2022-01-01T00:00:00Z"
+        });
+        let actual = json!({
+            "id": 1,
+            "name": "Bill",
+            "address": {"city": "Paris", "country": "France"},
+            "tags": ["a", "c", "d"],
+            "created-at": "2022-06-01T00:00:00Z"
+        });
+        assert_eq!(
+            json_diff(&expected, &actual, &[]),
+            vec![
+                (
+                    "$.address.zip".to_string(),
+                    "missing, expected <\"75001\">".to_string()
+                ),
+                (
+                    "$.address.country".to_string(),
+                    "unexpected <\"France\">".to_string()
+                ),
+                (
+                    "$['created-at']".to_string(),
+                    "expected <\"2022-01-01T00:00:00Z\">, actual <\"2022-06-01T00:00:00Z\">"
+                        .to_string()
+                ),
+                (
+                    "$.name".to_string(),
+                    "expected <\"Bob\">, actual <\"Bill\">".to_string()
+                ),
+                (
+                    "$.tags[1]".to_string(),
+                    "expected <\"b\">, actual <\"c\">".to_string()
+                ),
+                (
+                    "$.tags".to_string(),
+                    "expected 2 elements, actual 3".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_json_includes() {
+        let actual = json!({
+            "id": 1,
+            "name": "Bob",
+            "tags": ["admin", "user"],
+            "orders": [{"id": 10, "total": 5.5}, {"id": 11, "total": 20}]
+        });
+        assert!(json_includes(&json!({"name": "Bob"}), &actual).is_empty());
+        assert!(json_includes(
+            &json!({"tags": ["user"], "orders": [{"total": 20.0}]}),
+            &actual
+        )
+        .is_empty());
+        assert_eq!(
+            json_includes(
+                &json!({"name": "Bill", "tags": ["guest"], "email": "bob@example.org"}),
+                &actual
+            ),
+            vec![
+                (
+                    "$.email".to_string(),
+                    "missing, expected <\"bob@example.org\">".to_string()
+                ),
+                (
+                    "$.name".to_string(),
+                    "expected <\"Bill\">, actual <\"Bob\">".to_string()
+                ),
+                (
+                    "$.tags".to_string(),
+                    "missing element <\"guest\">".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_json_diff_ignored_keys() {
+        let expected = json!({"id": 1, "user": {"name": "Bob", "updatedAt": "yesterday"}});
+        let actual =
+            json!({"id": 1, "createdAt": "now", "user": {"name": "Bob", "updatedAt": "today"}});
+        let ignored_keys = vec!["createdAt".to_string(), "updatedAt".to_string()];
+        assert!(json_diff(&expected, &actual, &ignored_keys).is_empty());
+    }
+}
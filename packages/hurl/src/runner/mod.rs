@@ -39,9 +39,11 @@ mod core;
 mod entry;
 mod error;
 mod expr;
+mod filter;
 mod http_response;
 mod hurl_file;
 mod json;
+mod json_diff;
 mod multipart;
 mod path;
 mod predicate;
@@ -49,7 +51,6 @@ mod predicate_value;
 mod query;
 mod request;
 mod response;
-mod subquery;
 mod template;
 mod value;
 mod xpath;
@@ -27,7 +27,8 @@ use crate::http;
 use hurl_core::ast::*;
 
 use super::core::{Error, RunnerError};
-use super::template::eval_template;
+use super::expr::eval_expr;
+use super::template::{eval_expression, eval_template};
 use super::value::Value;
 
 pub fn eval_multipart_param(
@@ -42,7 +43,7 @@ pub fn eval_multipart_param(
             Ok(http::MultipartParam::Param(http::Param { name, value }))
         }
         MultipartParam::FileParam(param) => {
-            let file_param = eval_file_param(param, context_dir)?;
+            let file_param = eval_file_param(param, variables, context_dir)?;
             Ok(http::MultipartParam::FileParam(file_param))
         }
     }
@@ -50,20 +51,41 @@ pub fn eval_multipart_param(
 
 pub fn eval_file_param(
     file_param: FileParam,
+    variables: &HashMap<String, Value>,
     context_dir: &Path,
 ) -> Result<http::FileParam, Error> {
     let name = file_param.key.value;
+    let filename = eval_template(&file_param.value.filename, variables)?;
+    let source_info = file_param.value.filename.source_info;
+
+    let (data, inline) = match file_param.value.content {
+        Some(content) => (eval_file_content(content, variables)?, true),
+        None => (
+            read_file(filename.as_str(), source_info, context_dir)?,
+            false,
+        ),
+    };
+
+    let content_type = file_value_content_type(file_param.value.content_type, filename.as_str());
+    Ok(http::FileParam {
+        name,
+        filename,
+        data,
+        content_type,
+        inline,
+    })
+}
 
-    let filename = file_param.value.filename.clone();
-    let path = Path::new(filename.value.as_str());
+fn read_file(
+    filename: &str,
+    source_info: SourceInfo,
+    context_dir: &Path,
+) -> Result<Vec<u8>, Error> {
+    let path = Path::new(filename);
     let absolute_filename = if path.is_absolute() {
-        filename.value.clone()
+        filename.to_string()
     } else {
-        context_dir
-            .join(filename.value.clone())
-            .to_str()
-            .unwrap()
-            .to_string()
+        context_dir.join(filename).to_str().unwrap().to_string()
     };
 
     let data = match File::open(absolute_filename.clone()) {
@@ -73,7 +95,7 @@ pub fn eval_file_param(
                 Ok(_) => bytes,
                 Err(_) => {
                     return Err(Error {
-                        source_info: filename.source_info,
+                        source_info,
                         inner: RunnerError::FileReadAccess {
                             value: absolute_filename,
                         },
@@ -84,7 +106,7 @@ pub fn eval_file_param(
         }
         Err(_) => {
             return Err(Error {
-                source_info: filename.source_info,
+                source_info,
                 inner: RunnerError::FileReadAccess {
                     value: absolute_filename,
                 },
@@ -95,29 +117,38 @@ pub fn eval_file_param(
 
     if !Path::new(&absolute_filename).exists() {
         return Err(Error {
-            source_info: filename.source_info,
+            source_info,
             inner: RunnerError::FileReadAccess {
-                value: filename.value.clone(),
+                value: filename.to_string(),
             },
             assert: false,
         });
     }
+    Ok(data)
+}
 
-    let content_type = file_value_content_type(file_param.value);
-    Ok(http::FileParam {
-        name,
-        filename: filename.value,
-        data,
-        content_type,
-    })
+/// Evaluates the inline content of a file part, a variable being sent as is
+/// when it holds bytes.
+fn eval_file_content(
+    content: FileContent,
+    variables: &HashMap<String, Value>,
+) -> Result<Vec<u8>, Error> {
+    match content {
+        FileContent::RawString(RawString { value, .. }) => {
+            Ok(eval_template(&value, variables)?.into_bytes())
+        }
+        FileContent::Base64(Base64 { value, .. }) => Ok(value),
+        FileContent::Hex(Hex { value, .. }) => Ok(value),
+        FileContent::Expression(expr) => match eval_expr(expr.clone(), variables)? {
+            Value::Bytes(value) => Ok(value),
+            _ => Ok(eval_expression(expr, variables)?.into_bytes()),
+        },
+    }
 }
 
-pub fn file_value_content_type(file_value: FileValue) -> String {
-    match file_value.content_type.clone() {
-        None => match Path::new(file_value.filename.value.as_str())
-            .extension()
-            .and_then(OsStr::to_str)
-        {
+pub fn file_value_content_type(content_type: Option<String>, filename: &str) -> String {
+    match content_type {
+        None => match Path::new(filename).extension().and_then(OsStr::to_str) {
             Some("gif") => "image/gif".to_string(),
             Some("jpg") => "image/jpeg".to_string(),
             Some("jpeg") => "image/jpeg".to_string(),
@@ -147,38 +178,54 @@ mod tests {
         }
     }
 
-    #[test]
-    pub fn test_eval_file_param() {
+    fn template(value: &str) -> Template {
+        Template {
+            quotes: false,
+            elements: vec![TemplateElement::String {
+                value: value.to_string(),
+                encoded: value.to_string(),
+            }],
+            source_info: SourceInfo::init(0, 0, 0, 0),
+        }
+    }
+
+    fn file_param(filename: Template, content: Option<FileContent>) -> FileParam {
         let line_terminator = LineTerminator {
             space0: whitespace(),
             comment: None,
             newline: whitespace(),
         };
+        FileParam {
+            line_terminators: vec![],
+            space0: whitespace(),
+            key: EncodedString {
+                value: "upload1".to_string(),
+                encoded: "upload1".to_string(),
+                quotes: false,
+                source_info: SourceInfo::init(0, 0, 0, 0),
+            },
+            space1: whitespace(),
+            space2: whitespace(),
+            value: FileValue {
+                space0: whitespace(),
+                filename,
+                space1: whitespace(),
+                space2: whitespace(),
+                content,
+                space3: whitespace(),
+                content_type: None,
+            },
+            line_terminator0: line_terminator,
+        }
+    }
+
+    #[test]
+    pub fn test_eval_file_param() {
+        let variables = HashMap::new();
         assert_eq!(
             eval_file_param(
-                FileParam {
-                    line_terminators: vec![],
-                    space0: whitespace(),
-                    key: EncodedString {
-                        value: "upload1".to_string(),
-                        encoded: "upload1".to_string(),
-                        quotes: false,
-                        source_info: SourceInfo::init(0, 0, 0, 0),
-                    },
-                    space1: whitespace(),
-                    space2: whitespace(),
-                    value: FileValue {
-                        space0: whitespace(),
-                        filename: Filename {
-                            value: "hello.txt".to_string(),
-                            source_info: SourceInfo::init(0, 0, 0, 0),
-                        },
-                        space1: whitespace(),
-                        space2: whitespace(),
-                        content_type: None,
-                    },
-                    line_terminator0: line_terminator,
-                },
+                file_param(template("hello.txt"), None),
+                &variables,
                 Path::new("tests")
             )
             .unwrap(),
@@ -187,65 +234,94 @@ mod tests {
                 filename: "hello.txt".to_string(),
                 data: b"Hello World!".to_vec(),
                 content_type: "text/plain".to_string(),
+                inline: false,
+            }
+        );
+    }
+
+    #[test]
+    pub fn test_eval_file_param_inline() {
+        // upload1: file,report-{{id}}.txt; {{data}}
+        let variable = |name: &str| Expr {
+            space0: Whitespace {
+                value: String::from(""),
+                source_info: SourceInfo::init(0, 0, 0, 0),
+            },
+            kind: ExprKind::Variable(Variable {
+                name: name.to_string(),
+                source_info: SourceInfo::init(0, 0, 0, 0),
+            }),
+            space1: Whitespace {
+                value: String::from(""),
+                source_info: SourceInfo::init(0, 0, 0, 0),
+            },
+        };
+        let filename = Template {
+            quotes: false,
+            elements: vec![
+                TemplateElement::String {
+                    value: "report-".to_string(),
+                    encoded: "report-".to_string(),
+                },
+                TemplateElement::Expression(variable("id")),
+                TemplateElement::String {
+                    value: ".txt".to_string(),
+                    encoded: ".txt".to_string(),
+                },
+            ],
+            source_info: SourceInfo::init(0, 0, 0, 0),
+        };
+        let mut variables = HashMap::new();
+        variables.insert("id".to_string(), Value::Integer(1));
+        variables.insert("data".to_string(), Value::Bytes(vec![1, 2, 3]));
+        assert_eq!(
+            eval_file_param(
+                file_param(
+                    filename.clone(),
+                    Some(FileContent::Expression(variable("data")))
+                ),
+                &variables,
+                Path::new("tests")
+            )
+            .unwrap(),
+            http::FileParam {
+                name: "upload1".to_string(),
+                filename: "report-1.txt".to_string(),
+                data: vec![1, 2, 3],
+                content_type: "text/plain".to_string(),
+                inline: true,
             }
         );
+
+        variables.insert("data".to_string(), Value::String("Hello".to_string()));
+        assert_eq!(
+            eval_file_param(
+                file_param(filename, Some(FileContent::Expression(variable("data")))),
+                &variables,
+                Path::new("tests")
+            )
+            .unwrap()
+            .data,
+            b"Hello".to_vec()
+        );
     }
 
     #[test]
     pub fn test_file_value_content_type() {
         assert_eq!(
-            file_value_content_type(FileValue {
-                space0: whitespace(),
-                filename: Filename {
-                    value: "hello.txt".to_string(),
-                    source_info: SourceInfo::init(0, 0, 0, 0),
-                },
-                space1: whitespace(),
-                space2: whitespace(),
-                content_type: None,
-            }),
+            file_value_content_type(None, "hello.txt"),
             "text/plain".to_string()
         );
-
         assert_eq!(
-            file_value_content_type(FileValue {
-                space0: whitespace(),
-                filename: Filename {
-                    value: "hello.html".to_string(),
-                    source_info: SourceInfo::init(0, 0, 0, 0),
-                },
-                space1: whitespace(),
-                space2: whitespace(),
-                content_type: None,
-            }),
+            file_value_content_type(None, "hello.html"),
             "text/html".to_string()
         );
-
         assert_eq!(
-            file_value_content_type(FileValue {
-                space0: whitespace(),
-                filename: Filename {
-                    value: "hello.txt".to_string(),
-                    source_info: SourceInfo::init(0, 0, 0, 0),
-                },
-                space1: whitespace(),
-                space2: whitespace(),
-                content_type: Some("text/html".to_string()),
-            }),
+            file_value_content_type(Some("text/html".to_string()), "hello.txt"),
             "text/html".to_string()
         );
-
         assert_eq!(
-            file_value_content_type(FileValue {
-                space0: whitespace(),
-                filename: Filename {
-                    value: "hello".to_string(),
-                    source_info: SourceInfo::init(0, 0, 0, 0),
-                },
-                space1: whitespace(),
-                space2: whitespace(),
-                content_type: None,
-            }),
+            file_value_content_type(None, "hello"),
             "application/octet-stream".to_string()
         );
     }
@@ -15,15 +15,23 @@
  * limitations under the License.
  *
  */
+use std::cmp::Ordering;
 use std::collections::HashMap;
+use std::path::Path;
 
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+use jsonschema::JSONSchema;
 use regex;
 
 use hurl_core::ast::*;
 
+use super::body::read_file;
 use super::core::Error;
+use super::json::eval_json_value;
+use super::json_diff::json_includes;
 use super::template::eval_template;
 use super::value::Value;
+use super::xpath::{self, XmlValidationError};
 use crate::runner::core::PredicateResult;
 use crate::runner::predicate_value::eval_predicate_value;
 use crate::runner::RunnerError;
@@ -32,8 +40,8 @@ pub fn eval_predicate(
     predicate: Predicate,
     variables: &HashMap<String, Value>,
     value: Option<Value>,
+    context_dir: &Path,
 ) -> PredicateResult {
-    let assert_result = eval_predicate_func(predicate.predicate_func.clone(), variables, value)?;
     let source_info = SourceInfo {
         start: Pos {
             line: predicate.space0.source_info.start.line,
@@ -44,6 +52,29 @@ pub fn eval_predicate(
             column: 0,
         },
     };
+    if let (PredicateFuncValue::JsonSchema { value: file, .. }, Some(value)) =
+        (&predicate.predicate_func.value, &value)
+    {
+        return eval_json_schema_predicate(
+            file,
+            value.clone(),
+            predicate.not,
+            source_info,
+            context_dir,
+        );
+    }
+    if let (PredicateFuncValue::XmlSchema { value: file, .. }, Some(value)) =
+        (&predicate.predicate_func.value, &value)
+    {
+        return eval_xml_schema_predicate(
+            file,
+            value.clone(),
+            predicate.not,
+            source_info,
+            context_dir,
+        );
+    }
+    let assert_result = eval_predicate_func(predicate.predicate_func.clone(), variables, value)?;
     if assert_result.type_mismatch {
         let not = if predicate.not { "not " } else { "" };
         let expected = format!("{}{}", not, assert_result.expected);
@@ -81,6 +112,169 @@ pub fn eval_predicate(
     }
 }
 
+/// Validates a value against a JSON schema file, each violation being reported
+/// with the JSON pointer of the invalid instance.
+fn eval_json_schema_predicate(
+    file: &File,
+    value: Value,
+    not: bool,
+    source_info: SourceInfo,
+    context_dir: &Path,
+) -> PredicateResult {
+    let schema_name = file.filename.value.clone();
+    let expected = format!("valid against schema {}", schema_name);
+    let instance = match json_instance(&value) {
+        Some(instance) => instance,
+        None => {
+            return Err(Error {
+                source_info,
+                inner: RunnerError::AssertFailure {
+                    actual: value.display(),
+                    expected,
+                    type_mismatch: true,
+                },
+                assert: true,
+            })
+        }
+    };
+
+    let schema = read_file(&file.filename, context_dir)?;
+    let schema: serde_json::Value = match serde_json::from_slice(&schema) {
+        Ok(schema) => schema,
+        Err(e) => {
+            return Err(Error {
+                source_info: file.filename.source_info.clone(),
+                inner: RunnerError::InvalidJsonSchema {
+                    value: e.to_string(),
+                },
+                assert: false,
+            })
+        }
+    };
+    let schema = match JSONSchema::compile(&schema) {
+        Ok(schema) => schema,
+        Err(e) => {
+            return Err(Error {
+                source_info: file.filename.source_info.clone(),
+                inner: RunnerError::InvalidJsonSchema {
+                    value: e.to_string(),
+                },
+                assert: false,
+            })
+        }
+    };
+
+    let violations = match schema.validate(&instance) {
+        Ok(_) => vec![],
+        Err(errors) => errors
+            .map(|e| (e.instance_path.to_string(), e.to_string()))
+            .collect(),
+    };
+    if !not && !violations.is_empty() {
+        Err(Error {
+            source_info,
+            inner: RunnerError::AssertJsonSchema {
+                schema: schema_name,
+                violations,
+            },
+            assert: true,
+        })
+    } else if not && violations.is_empty() {
+        Err(Error {
+            source_info,
+            inner: RunnerError::AssertFailure {
+                actual: expected.clone(),
+                expected: format!("not {}", expected),
+                type_mismatch: false,
+            },
+            assert: true,
+        })
+    } else {
+        Ok(())
+    }
+}
+
+fn eval_xml_schema_predicate(
+    file: &File,
+    value: Value,
+    not: bool,
+    source_info: SourceInfo,
+    context_dir: &Path,
+) -> PredicateResult {
+    let schema_name = file.filename.value.clone();
+    let expected = format!("valid against schema {}", schema_name);
+    let xml = match value {
+        Value::String(ref s) => Some(s.clone()),
+        Value::Bytes(ref bytes) => String::from_utf8(bytes.clone()).ok(),
+        _ => None,
+    };
+    let type_mismatch = Error {
+        source_info: source_info.clone(),
+        inner: RunnerError::AssertFailure {
+            actual: value.clone().display(),
+            expected: expected.clone(),
+            type_mismatch: true,
+        },
+        assert: true,
+    };
+    let xml = match xml {
+        Some(xml) => xml,
+        None => return Err(type_mismatch),
+    };
+
+    let schema = read_file(&file.filename, context_dir)?;
+    // A DTD is used instead of a XML Schema when the file has a .dtd extension
+    let result = if schema_name.ends_with(".dtd") {
+        xpath::validate_xml_dtd(&xml, &schema)
+    } else {
+        xpath::validate_xml_schema(&xml, &schema)
+    };
+    let violations = match result {
+        Ok(_) => vec![],
+        Err(XmlValidationError::Invalid(violations)) => violations,
+        Err(XmlValidationError::InvalidXml) => return Err(type_mismatch),
+        Err(XmlValidationError::InvalidSchema(value)) => {
+            return Err(Error {
+                source_info: file.filename.source_info.clone(),
+                inner: RunnerError::InvalidXmlSchema { value },
+                assert: false,
+            })
+        }
+    };
+    if !not && !violations.is_empty() {
+        Err(Error {
+            source_info,
+            inner: RunnerError::AssertXmlSchema {
+                schema: schema_name,
+                violations,
+            },
+            assert: true,
+        })
+    } else if not && violations.is_empty() {
+        Err(Error {
+            source_info,
+            inner: RunnerError::AssertFailure {
+                actual: expected.clone(),
+                expected: format!("not {}", expected),
+                type_mismatch: false,
+            },
+            assert: true,
+        })
+    } else {
+        Ok(())
+    }
+}
+
+/// Returns the JSON document of a value, strings and bytes being parsed as JSON text.
+fn json_instance(value: &Value) -> Option<serde_json::Value> {
+    match value {
+        Value::String(s) => serde_json::from_str(s).ok(),
+        Value::Bytes(bytes) => serde_json::from_slice(bytes).ok(),
+        Value::Nodeset(_) | Value::Regex(_) | Value::Unit => None,
+        v => Some(v.to_json()),
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 struct AssertResult {
     pub success: bool,
@@ -107,6 +301,7 @@ impl Value {
             Value::Nodeset(n) => format!("nodeset of size <{}>", n),
             Value::Object(_) => "object".to_string(),
             Value::Bytes(value) => format!("byte array <{}>", hex::encode(value)),
+            Value::Date(_) => format!("date <{}>", self),
             Value::Null => "null".to_string(),
             Value::Unit => "unit".to_string(),
             Value::Regex(value) => format!("regex <{}>", value.as_str()),
@@ -138,6 +333,7 @@ impl Value {
         match self {
             Value::Bool(value) => format!("bool <{}>", value),
             Value::Bytes(values) => format!("list of size {}", values.len()),
+            Value::Date(_) => format!("date <{}>", self),
             Value::Float(f) => format!("float <{}>", format_float(*f)),
             Value::Integer(value) => format!("integer <{}>", value),
             Value::List(value) => format!("list of size {}", value.len()),
@@ -223,11 +419,25 @@ fn expected(
             let expected = eval_predicate_value_template(expected, variables)?;
             Ok(format!("matches regex <{}>", expected))
         }
+        PredicateFuncValue::IncludeJson { value, .. }
+        | PredicateFuncValue::MatchJson { value, .. } => {
+            let value = eval_expected_json(value, variables, &predicate_func.source_info)?;
+            Ok(format!("includes json <{}>", value))
+        }
+        PredicateFuncValue::JsonSchema { value, .. }
+        | PredicateFuncValue::XmlSchema { value, .. } => {
+            Ok(format!("valid against schema {}", value.filename.value))
+        }
         PredicateFuncValue::IsInteger {} => Ok("integer".to_string()),
         PredicateFuncValue::IsFloat {} => Ok("float".to_string()),
         PredicateFuncValue::IsBoolean {} => Ok("boolean".to_string()),
         PredicateFuncValue::IsString {} => Ok("string".to_string()),
         PredicateFuncValue::IsCollection {} => Ok("collection".to_string()),
+        PredicateFuncValue::IsEmpty {} => Ok("empty".to_string()),
+        PredicateFuncValue::IsDate {} => Ok("date".to_string()),
+        PredicateFuncValue::IsIsoDate {} => Ok("string with format ISO 8601".to_string()),
+        PredicateFuncValue::IsNumber {} => Ok("number".to_string()),
+        PredicateFuncValue::IsList {} => Ok("list".to_string()),
         PredicateFuncValue::Exist {} => Ok("something".to_string()),
     }
 }
@@ -444,6 +654,28 @@ fn eval_something(
             }
         }
 
+        // matchesJson is an alias of includesJson
+        PredicateFuncValue::IncludeJson {
+            value: expected, ..
+        }
+        | PredicateFuncValue::MatchJson {
+            value: expected, ..
+        } => {
+            let expected = eval_expected_json(expected, variables, &predicate_func.source_info)?;
+            Ok(assert_json_includes(&value, &expected))
+        }
+
+        // evaluated with the file context in eval_predicate
+        PredicateFuncValue::JsonSchema { .. } | PredicateFuncValue::XmlSchema { .. } => {
+            Err(Error {
+                source_info: predicate_func.source_info,
+                inner: RunnerError::Internal(
+                    "schema predicates are evaluated with their context directory".to_string(),
+                ),
+                assert: false,
+            })
+        }
+
         // types
         PredicateFuncValue::IsInteger {} => Ok(AssertResult {
             success: matches!(value, Value::Integer(_)),
@@ -478,6 +710,49 @@ fn eval_something(
             expected: "collection".to_string(),
             type_mismatch: false,
         }),
+        PredicateFuncValue::IsEmpty {} => {
+            let empty = match value {
+                Value::Bytes(ref values) => Some(values.is_empty()),
+                Value::List(ref values) => Some(values.is_empty()),
+                Value::Nodeset(n) => Some(n == 0),
+                Value::Object(ref values) => Some(values.is_empty()),
+                Value::String(ref s) => Some(s.is_empty()),
+                _ => None,
+            };
+            Ok(AssertResult {
+                success: empty.unwrap_or(false),
+                actual: value.display(),
+                expected: "empty".to_string(),
+                type_mismatch: empty.is_none(),
+            })
+        }
+        PredicateFuncValue::IsDate {} => Ok(AssertResult {
+            success: matches!(value, Value::Date(_)),
+            actual: value.display(),
+            expected: "date".to_string(),
+            type_mismatch: false,
+        }),
+        PredicateFuncValue::IsIsoDate {} => Ok(AssertResult {
+            success: match value {
+                Value::String(ref s) => is_iso_date(s),
+                _ => false,
+            },
+            actual: value.display(),
+            expected: "string with format ISO 8601".to_string(),
+            type_mismatch: false,
+        }),
+        PredicateFuncValue::IsNumber {} => Ok(AssertResult {
+            success: matches!(value, Value::Integer(_)) || matches!(value, Value::Float(_)),
+            actual: value.display(),
+            expected: "number".to_string(),
+            type_mismatch: false,
+        }),
+        PredicateFuncValue::IsList {} => Ok(AssertResult {
+            success: matches!(value, Value::List(_)),
+            actual: value.display(),
+            expected: "list".to_string(),
+            type_mismatch: false,
+        }),
 
         // exists
         PredicateFuncValue::Exist {} => match value {
@@ -554,6 +829,26 @@ fn assert_values_equal(actual: Value, expected: Value) -> AssertResult {
             expected: expected.display(),
             type_mismatch: false,
         },
+        (Value::Date(value1), Value::Date(value2)) => AssertResult {
+            success: value1 == value2,
+            actual: actual.display(),
+            expected: expected.display(),
+            type_mismatch: false,
+        },
+        (Value::Date(value1), Value::String(s)) => match parse_date(s.as_str()) {
+            Some(value2) => AssertResult {
+                success: value1 == value2,
+                actual: actual.display(),
+                expected: expected.display(),
+                type_mismatch: false,
+            },
+            None => AssertResult {
+                success: false,
+                actual: actual.display(),
+                expected: expected.display(),
+                type_mismatch: true,
+            },
+        },
         (Value::Unit, _) => AssertResult {
             success: false,
             actual: actual.display(),
@@ -625,6 +920,26 @@ fn assert_values_not_equal(actual: Value, expected: Value) -> AssertResult {
             expected: expected.display(),
             type_mismatch: false,
         },
+        (Value::Date(value1), Value::Date(value2)) => AssertResult {
+            success: value1 != value2,
+            actual: actual.display(),
+            expected: expected.display(),
+            type_mismatch: false,
+        },
+        (Value::Date(value1), Value::String(s)) => match parse_date(s.as_str()) {
+            Some(value2) => AssertResult {
+                success: value1 != value2,
+                actual: actual.display(),
+                expected: expected.display(),
+                type_mismatch: false,
+            },
+            None => AssertResult {
+                success: false,
+                actual: actual.display(),
+                expected: expected.display(),
+                type_mismatch: true,
+            },
+        },
         (Value::Unit, _) => AssertResult {
             success: false,
             actual: actual.display(),
@@ -643,7 +958,7 @@ fn assert_values_not_equal(actual: Value, expected: Value) -> AssertResult {
 fn assert_values_greater(actual_value: Value, expected_value: Value) -> AssertResult {
     let actual = actual_value.clone().display();
     let expected = format!("greater than {}", expected_value.clone().display());
-    match compare_numbers(actual_value, expected_value) {
+    match compare_values(actual_value, expected_value) {
         Some(1) => AssertResult {
             success: true,
             actual,
@@ -668,7 +983,7 @@ fn assert_values_greater(actual_value: Value, expected_value: Value) -> AssertRe
 fn assert_values_greater_or_equal(actual_value: Value, expected_value: Value) -> AssertResult {
     let actual = actual_value.clone().display();
     let expected = format!("greater or equal than {}", expected_value.clone().display());
-    match compare_numbers(actual_value, expected_value) {
+    match compare_values(actual_value, expected_value) {
         Some(1) | Some(0) => AssertResult {
             success: true,
             actual,
@@ -693,7 +1008,7 @@ fn assert_values_greater_or_equal(actual_value: Value, expected_value: Value) ->
 fn assert_values_less(actual_value: Value, expected_value: Value) -> AssertResult {
     let actual = actual_value.clone().display();
     let expected = format!("less than {}", expected_value.clone().display());
-    match compare_numbers(actual_value, expected_value) {
+    match compare_values(actual_value, expected_value) {
         Some(-1) => AssertResult {
             success: true,
             actual,
@@ -718,7 +1033,7 @@ fn assert_values_less(actual_value: Value, expected_value: Value) -> AssertResul
 fn assert_values_less_or_equal(actual_value: Value, expected_value: Value) -> AssertResult {
     let actual = actual_value.clone().display();
     let expected = format!("less or equal than {}", expected_value.clone().display());
-    match compare_numbers(actual_value, expected_value) {
+    match compare_values(actual_value, expected_value) {
         Some(-1) | Some(0) => AssertResult {
             success: true,
             actual,
@@ -740,6 +1055,54 @@ fn assert_values_less_or_equal(actual_value: Value, expected_value: Value) -> As
     }
 }
 
+// return -1, 0 or 1
+// none if the values are neither two numbers nor two dates
+fn compare_values(actual: Value, expected: Value) -> Option<i32> {
+    match (actual, expected) {
+        (Value::Date(d1), Value::Date(d2)) => Some(compare_dates(d1, d2)),
+        (Value::Date(d1), Value::String(s)) => {
+            parse_date(s.as_str()).map(|d2| compare_dates(d1, d2))
+        }
+        (actual, expected) => compare_numbers(actual, expected),
+    }
+}
+
+fn compare_dates(d1: DateTime<Utc>, d2: DateTime<Utc>) -> i32 {
+    match d1.cmp(&d2) {
+        Ordering::Greater => 1,
+        Ordering::Less => -1,
+        Ordering::Equal => 0,
+    }
+}
+
+/// Parses the string an actual date is compared with, in RFC 3339 (`2022-01-06T17:30:00Z`).
+fn parse_date(s: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(s)
+        .ok()
+        .map(|d| d.with_timezone(&Utc))
+}
+
+/// Returns true if the string is an ISO 8601 calendar date (`2022-01-06`), or date and time
+/// with an optional UTC offset (`2022-01-06T10:00:00`, `2022-01-06T10:00:00+01:00`),
+/// in extended or basic (`20220106T100000Z`) format.
+fn is_iso_date(s: &str) -> bool {
+    let date_formats = ["%Y-%m-%d", "%Y%m%d"];
+    let time_formats = [
+        "%Y-%m-%dT%H:%M:%S%.f",
+        "%Y-%m-%dT%H:%M",
+        "%Y%m%dT%H%M%S%.f",
+        "%Y%m%dT%H%M",
+    ];
+    date_formats
+        .iter()
+        .any(|format| NaiveDate::parse_from_str(s, format).is_ok())
+        || time_formats.iter().any(|format| {
+            // `%#z` accepts `Z`, `±hh`, `±hhmm` and `±hh:mm` offsets
+            NaiveDateTime::parse_from_str(s, format).is_ok()
+                || DateTime::parse_from_str(s, &format!("{}%#z", format)).is_ok()
+        })
+}
+
 // return -1, 0 or 1
 // none if one of the value is not a number
 fn compare_numbers(actual: Value, expected: Value) -> Option<i32> {
@@ -790,6 +1153,50 @@ fn assert_include(value: Value, element: Value) -> AssertResult {
     }
 }
 
+fn eval_expected_json(
+    value: JsonValue,
+    variables: &HashMap<String, Value>,
+    source_info: &SourceInfo,
+) -> Result<serde_json::Value, Error> {
+    let s = eval_json_value(value, variables)?;
+    match serde_json::from_str(s.as_str()) {
+        Ok(value) => Ok(value),
+        Err(_) => Err(Error {
+            source_info: source_info.clone(),
+            inner: RunnerError::InvalidJson { value: s },
+            assert: false,
+        }),
+    }
+}
+
+/// Checks that a value includes an expected JSON document.
+/// Each difference is reported on its own line with its JSONPath.
+fn assert_json_includes(value: &Value, expected_json: &serde_json::Value) -> AssertResult {
+    let expected = format!("includes json <{}>", expected_json);
+    let actual = match json_instance(value) {
+        Some(actual) => actual,
+        None => {
+            return AssertResult {
+                success: false,
+                actual: value.clone().display(),
+                expected,
+                type_mismatch: true,
+            }
+        }
+    };
+    let diff = json_includes(expected_json, &actual);
+    let differences = diff
+        .iter()
+        .map(|(path, message)| format!("\n  {}: {}", path, message))
+        .collect::<String>();
+    AssertResult {
+        success: diff.is_empty(),
+        actual: format!("json <{}>", actual),
+        expected: format!("{}{}", expected, differences),
+        type_mismatch: false,
+    }
+}
+
 fn contains(haystack: &[u8], needle: &[u8]) -> bool {
     haystack
         .windows(needle.len())
@@ -843,11 +1250,22 @@ mod tests {
             },
         };
 
-        assert!(eval_predicate(predicate.clone(), &variables, Some(Value::Bool(true))).is_ok());
+        assert!(eval_predicate(
+            predicate.clone(),
+            &variables,
+            Some(Value::Bool(true)),
+            Path::new("")
+        )
+        .is_ok());
 
-        let error = eval_predicate(predicate.clone(), &variables, Some(Value::Integer(10)))
-            .err()
-            .unwrap();
+        let error = eval_predicate(
+            predicate.clone(),
+            &variables,
+            Some(Value::Integer(10)),
+            Path::new(""),
+        )
+        .err()
+        .unwrap();
         assert_eq!(
             error.inner,
             RunnerError::AssertFailure {
@@ -858,7 +1276,13 @@ mod tests {
         );
         assert_eq!(error.source_info, SourceInfo::init(1, 0, 1, 0));
 
-        assert!(eval_predicate(predicate, &variables, Some(Value::Integer(1))).is_ok());
+        assert!(eval_predicate(
+            predicate,
+            &variables,
+            Some(Value::Integer(1)),
+            Path::new("")
+        )
+        .is_ok());
     }
 
     #[test]
@@ -999,6 +1423,203 @@ mod tests {
         assert_eq!(assert_result.expected.as_str(), "something");
     }
 
+    #[test]
+    fn test_predicate_json_schema() {
+        let variables = HashMap::new();
+        let predicate = |not: bool| Predicate {
+            not,
+            space0: whitespace(),
+            predicate_func: PredicateFunc {
+                value: PredicateFuncValue::JsonSchema {
+                    space0: whitespace(),
+                    value: File {
+                        space0: whitespace(),
+                        filename: Filename {
+                            value: "user.schema.json".to_string(),
+                            source_info: SourceInfo::init(1, 1, 1, 1),
+                        },
+                        space1: whitespace(),
+                    },
+                },
+                source_info: SourceInfo::init(1, 1, 1, 1),
+            },
+        };
+        let context_dir = Path::new("tests");
+
+        let valid = r#"{"id": 1, "name": "Bob", "tags": ["admin"]}"#;
+        assert!(eval_predicate(
+            predicate(false),
+            &variables,
+            Some(Value::String(valid.to_string())),
+            context_dir
+        )
+        .is_ok());
+        assert!(eval_predicate(
+            predicate(true),
+            &variables,
+            Some(Value::String(valid.to_string())),
+            context_dir
+        )
+        .is_err());
+
+        let invalid = r#"{"id": "1", "tags": ["admin", 2]}"#;
+        let error = eval_predicate(
+            predicate(false),
+            &variables,
+            Some(Value::String(invalid.to_string())),
+            context_dir,
+        )
+        .err()
+        .unwrap();
+        assert!(error.assert);
+        match error.inner {
+            RunnerError::AssertJsonSchema { schema, violations } => {
+                assert_eq!(schema, "user.schema.json");
+                let pointers = violations
+                    .iter()
+                    .map(|(pointer, _)| pointer.as_str())
+                    .collect::<Vec<&str>>();
+                assert!(pointers.contains(&""));
+                assert!(pointers.contains(&"/id"));
+                assert!(pointers.contains(&"/tags/1"));
+            }
+            _ => panic!("expect a JSON schema error"),
+        }
+        assert!(eval_predicate(
+            predicate(true),
+            &variables,
+            Some(Value::String(invalid.to_string())),
+            context_dir
+        )
+        .is_ok());
+
+        // values from a jsonpath query are validated too
+        let value = Value::Object(vec![
+            ("id".to_string(), Value::Integer(1)),
+            ("name".to_string(), Value::String("Bob".to_string())),
+        ]);
+        assert!(eval_predicate(predicate(false), &variables, Some(value), context_dir).is_ok());
+
+        let error = eval_predicate(
+            predicate(false),
+            &variables,
+            Some(Value::String("<html>".to_string())),
+            context_dir,
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            error.inner,
+            RunnerError::AssertFailure {
+                actual: "string <<html>>".to_string(),
+                expected: "valid against schema user.schema.json".to_string(),
+                type_mismatch: true,
+            }
+        );
+
+        let error = eval_predicate(
+            predicate(false),
+            &variables,
+            Some(Value::String(valid.to_string())),
+            Path::new(""),
+        )
+        .err()
+        .unwrap();
+        assert!(matches!(error.inner, RunnerError::FileReadAccess { .. }));
+
+        // the schema can not be read without the context directory
+        let error = eval_something(
+            predicate(false).predicate_func,
+            &variables,
+            Value::String(valid.to_string()),
+        )
+        .err()
+        .unwrap();
+        assert!(matches!(error.inner, RunnerError::Internal(_)));
+    }
+
+    #[test]
+    fn test_predicate_xml_schema() {
+        let variables = HashMap::new();
+        let predicate = |filename: &str| Predicate {
+            not: false,
+            space0: whitespace(),
+            predicate_func: PredicateFunc {
+                value: PredicateFuncValue::XmlSchema {
+                    space0: whitespace(),
+                    value: File {
+                        space0: whitespace(),
+                        filename: Filename {
+                            value: filename.to_string(),
+                            source_info: SourceInfo::init(1, 1, 1, 1),
+                        },
+                        space1: whitespace(),
+                    },
+                },
+                source_info: SourceInfo::init(1, 1, 1, 1),
+            },
+        };
+        let context_dir = Path::new("tests");
+
+        let valid = "<order><id>1</id><price>9.99</price></order>";
+        let invalid = "<order>\n  <id>1</id>\n  <price>free</price>\n</order>";
+        for filename in ["order.xsd", "order.dtd"] {
+            assert!(eval_predicate(
+                predicate(filename),
+                &variables,
+                Some(Value::String(valid.to_string())),
+                context_dir
+            )
+            .is_ok());
+        }
+
+        let error = eval_predicate(
+            predicate("order.xsd"),
+            &variables,
+            Some(Value::String(invalid.to_string())),
+            context_dir,
+        )
+        .err()
+        .unwrap();
+        assert!(error.assert);
+        match error.inner {
+            RunnerError::AssertXmlSchema { schema, violations } => {
+                assert_eq!(schema, "order.xsd");
+                assert_eq!(violations.len(), 1);
+                assert_eq!(violations[0].0, 3);
+            }
+            _ => panic!("expect a XML schema error"),
+        }
+
+        let error = eval_predicate(
+            predicate("order.xsd"),
+            &variables,
+            Some(Value::Integer(1)),
+            context_dir,
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            error.inner,
+            RunnerError::AssertFailure {
+                actual: "int <1>".to_string(),
+                expected: "valid against schema order.xsd".to_string(),
+                type_mismatch: true,
+            }
+        );
+
+        let error = eval_predicate(
+            predicate("user.schema.json"),
+            &variables,
+            Some(Value::String(valid.to_string())),
+            context_dir,
+        )
+        .err()
+        .unwrap();
+        assert!(!error.assert);
+        assert!(matches!(error.inner, RunnerError::InvalidXmlSchema { .. }));
+    }
+
     #[test]
     fn test_predicate_value_equals_integers() {
         let variables = HashMap::new();
@@ -1148,10 +1769,10 @@ mod tests {
                     value: "".to_string(),
                     source_info: SourceInfo::init(1, 11, 1, 11),
                 },
-                variable: Variable {
+                kind: ExprKind::Variable(Variable {
                     name: String::from("base_url"),
                     source_info: SourceInfo::init(1, 11, 1, 19),
-                },
+                }),
                 space1: Whitespace {
                     value: "".to_string(),
                     source_info: SourceInfo::init(1, 19, 1, 19),
@@ -1331,6 +1952,39 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_assert_value_date() {
+        let date = Value::Date(parse_date("2022-01-06T17:30:00Z").unwrap());
+        assert_eq!(
+            assert_values_greater(
+                date.clone(),
+                Value::String("2022-01-01T00:00:00Z".to_string())
+            ),
+            AssertResult {
+                success: true,
+                type_mismatch: false,
+                actual: "date <2022-01-06T17:30:00Z>".to_string(),
+                expected: "greater than string <2022-01-01T00:00:00Z>".to_string(),
+            }
+        );
+        assert!(
+            !assert_values_less(
+                date.clone(),
+                Value::String("2022-01-06T18:00:00+01:00".to_string())
+            )
+            .success
+        );
+        assert!(assert_values_less_or_equal(date.clone(), Value::Date(Utc::now())).success);
+        assert!(
+            assert_values_equal(
+                date.clone(),
+                Value::String("2022-01-06T17:30:00Z".to_string())
+            )
+            .success
+        );
+        assert!(assert_values_greater(date, Value::String("2022-01-01".to_string())).type_mismatch);
+    }
+
     #[test]
     fn test_predicate_count_equals_error() {
         let variables = HashMap::new();
@@ -1465,6 +2119,222 @@ mod tests {
         assert_eq!(assert_result.expected.as_str(), "integer");
     }
 
+    #[test]
+    fn test_predicate_is_empty() {
+        let variables = HashMap::new();
+        let predicate_func = PredicateFunc {
+            value: PredicateFuncValue::IsEmpty {},
+            source_info: SourceInfo::init(0, 0, 0, 0),
+        };
+        for value in [
+            Value::String("".to_string()),
+            Value::List(vec![]),
+            Value::Object(vec![]),
+            Value::Nodeset(0),
+        ] {
+            let assert_result = eval_something(predicate_func.clone(), &variables, value).unwrap();
+            assert!(assert_result.success);
+            assert!(!assert_result.type_mismatch);
+        }
+
+        let assert_result = eval_something(
+            predicate_func.clone(),
+            &variables,
+            Value::List(vec![Value::Integer(1)]),
+        )
+        .unwrap();
+        assert!(!assert_result.success);
+        assert!(!assert_result.type_mismatch);
+        assert_eq!(assert_result.actual.as_str(), "[int <1>]");
+        assert_eq!(assert_result.expected.as_str(), "empty");
+
+        let assert_result = eval_something(predicate_func, &variables, Value::Integer(1)).unwrap();
+        assert!(!assert_result.success);
+        assert!(assert_result.type_mismatch);
+    }
+
+    #[test]
+    fn test_predicate_is_date() {
+        let variables = HashMap::new();
+        let date = DateTime::parse_from_rfc3339("2022-01-06T17:30:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let is_date = PredicateFunc {
+            value: PredicateFuncValue::IsDate {},
+            source_info: SourceInfo::init(0, 0, 0, 0),
+        };
+        let is_iso_date = PredicateFunc {
+            value: PredicateFuncValue::IsIsoDate {},
+            source_info: SourceInfo::init(0, 0, 0, 0),
+        };
+
+        assert!(
+            eval_something(is_date.clone(), &variables, Value::Date(date))
+                .unwrap()
+                .success
+        );
+        assert!(
+            !eval_something(
+                is_date,
+                &variables,
+                Value::String("2022-01-06T17:30:00Z".to_string())
+            )
+            .unwrap()
+            .success
+        );
+        assert!(
+            eval_something(
+                is_iso_date.clone(),
+                &variables,
+                Value::String("2022-01-06T17:30:00.123+01:00".to_string())
+            )
+            .unwrap()
+            .success
+        );
+        let assert_result = eval_something(
+            is_iso_date,
+            &variables,
+            Value::String("06/01/2022".to_string()),
+        )
+        .unwrap();
+        assert!(!assert_result.success);
+        assert_eq!(
+            assert_result.expected.as_str(),
+            "string with format ISO 8601"
+        );
+    }
+
+    #[test]
+    fn test_is_iso_date() {
+        for s in [
+            "2022-01-06",
+            "20220106",
+            "2022-01-06T10:00",
+            "2022-01-06T10:00:00",
+            "2022-01-06T10:00:00.123",
+            "2022-01-06T10:00:00Z",
+            "2022-01-06T10:00:00+01:00",
+            "2022-01-06T10:00:00+0100",
+            "2022-01-06T10:00:00-01",
+            "20220106T100000Z",
+            "20220106T1000",
+        ] {
+            assert!(is_iso_date(s), "{}", s);
+        }
+        for s in [
+            "",
+            "06/01/2022",
+            "2022-13-06",
+            "2022-01-06T25:00:00",
+            "2022-01-06 10:00:00",
+            "2022-01-06T10:00:00+1",
+        ] {
+            assert!(!is_iso_date(s), "{}", s);
+        }
+    }
+
+    #[test]
+    fn test_predicate_is_number_and_list() {
+        let variables = HashMap::new();
+        let is_number = PredicateFunc {
+            value: PredicateFuncValue::IsNumber {},
+            source_info: SourceInfo::init(0, 0, 0, 0),
+        };
+        let is_list = PredicateFunc {
+            value: PredicateFuncValue::IsList {},
+            source_info: SourceInfo::init(0, 0, 0, 0),
+        };
+        assert!(
+            eval_something(is_number.clone(), &variables, Value::Integer(1))
+                .unwrap()
+                .success
+        );
+        assert!(
+            eval_something(is_number.clone(), &variables, Value::Float(1.5))
+                .unwrap()
+                .success
+        );
+        assert!(
+            !eval_something(is_number, &variables, Value::String("1".to_string()))
+                .unwrap()
+                .success
+        );
+        assert!(
+            eval_something(is_list.clone(), &variables, Value::List(vec![]))
+                .unwrap()
+                .success
+        );
+        let assert_result = eval_something(is_list, &variables, Value::Nodeset(2)).unwrap();
+        assert!(!assert_result.success);
+        assert_eq!(assert_result.expected.as_str(), "list");
+    }
+
+    #[test]
+    fn test_predicate_json() {
+        let variables = HashMap::new();
+        let json_value = |s: &str| {
+            let mut reader = hurl_core::parser::Reader::init(s);
+            hurl_core::parser::parse_json(&mut reader).unwrap()
+        };
+        let include_json = PredicateFunc {
+            value: PredicateFuncValue::IncludeJson {
+                space0: whitespace(),
+                value: json_value(r#"{"name": "Bob", "tags": ["admin"]}"#),
+            },
+            source_info: SourceInfo::init(0, 0, 0, 0),
+        };
+        let match_json = PredicateFunc {
+            value: PredicateFuncValue::MatchJson {
+                space0: whitespace(),
+                value: json_value(r#"{"name": "Bob", "tags": ["admin"]}"#),
+            },
+            source_info: SourceInfo::init(0, 0, 0, 0),
+        };
+        let body = r#"{"id": 1, "name": "Bob", "tags": ["user", "admin"]}"#.to_string();
+
+        assert!(
+            eval_something(
+                include_json.clone(),
+                &variables,
+                Value::String(body.clone())
+            )
+            .unwrap()
+            .success
+        );
+        let assert_result =
+            eval_something(match_json, &variables, Value::String(body.clone())).unwrap();
+        assert!(assert_result.success);
+        assert_eq!(
+            assert_result.expected,
+            "includes json <{\"name\":\"Bob\",\"tags\":[\"admin\"]}>"
+        );
+
+        let assert_result = eval_something(
+            include_json.clone(),
+            &variables,
+            Value::Object(vec![(
+                "name".to_string(),
+                Value::String("Bill".to_string()),
+            )]),
+        )
+        .unwrap();
+        assert!(!assert_result.success);
+        assert_eq!(assert_result.actual, "json <{\"name\":\"Bill\"}>");
+        assert_eq!(
+            assert_result.expected,
+            "includes json <{\"name\":\"Bob\",\"tags\":[\"admin\"]}>\n  $.name: expected <\"Bob\">, actual <\"Bill\">\n  $.tags: missing, expected <[\"admin\"]>"
+        );
+
+        let assert_result = eval_something(
+            include_json,
+            &variables,
+            Value::String("<html></html>".to_string()),
+        )
+        .unwrap();
+        assert!(!assert_result.success);
+        assert!(assert_result.type_mismatch);
+    }
+
     #[test]
     fn test_predicate_not_with_different_types() {
         // equals predicate does not generate a type error with an integer value
@@ -1482,7 +2352,13 @@ mod tests {
         };
 
         let variables = HashMap::new();
-        assert!(eval_predicate(predicate, &variables, Some(Value::Integer(1))).is_ok());
+        assert!(eval_predicate(
+            predicate,
+            &variables,
+            Some(Value::Integer(1)),
+            Path::new("")
+        )
+        .is_ok());
         //assert!(predicate.eval(&variables, None).is_ok());
 
         // startswith predicate generates a type error with an integer value
@@ -1504,9 +2380,14 @@ mod tests {
                 },
             },
         };
-        let error = eval_predicate(predicate, &variables, Some(Value::Integer(1)))
-            .err()
-            .unwrap();
+        let error = eval_predicate(
+            predicate,
+            &variables,
+            Some(Value::Integer(1)),
+            Path::new(""),
+        )
+        .err()
+        .unwrap();
         assert_eq!(
             error.inner,
             RunnerError::AssertFailure {
@@ -1533,7 +2414,9 @@ mod tests {
         };
 
         let variables = HashMap::new();
-        let error = eval_predicate(predicate, &variables, None).err().unwrap();
+        let error = eval_predicate(predicate, &variables, None, Path::new(""))
+            .err()
+            .unwrap();
         assert_eq!(
             error.inner,
             RunnerError::AssertFailure {
@@ -1557,7 +2440,7 @@ mod tests {
         };
 
         let variables = HashMap::new();
-        assert!(eval_predicate(predicate, &variables, None).is_ok());
+        assert!(eval_predicate(predicate, &variables, None, Path::new("")).is_ok());
     }
 
     #[test]
@@ -26,7 +26,7 @@ use super::value::Value;
 use super::xpath;
 use crate::http;
 use crate::jsonpath;
-use crate::runner::subquery::eval_subquery;
+use crate::runner::filter::eval_filters;
 use hurl_core::ast::*;
 use sha2::Digest;
 
@@ -37,20 +37,44 @@ pub fn eval_query(
     variables: &HashMap<String, Value>,
     http_response: http::Response,
 ) -> QueryResult {
-    let value = eval_query_value(query.clone(), variables, http_response)?;
-    if let Some((_, subquery)) = query.subquery {
-        if let Some(value) = value {
-            eval_subquery(subquery, value, variables)
-        } else {
-            Err(Error {
-                source_info: subquery.source_info,
-                inner: RunnerError::SubqueryInvalidInput,
-                assert: false,
-            })
+    // A body streamed to a file is not kept in memory: only `bytes count` (its size), `sha256`
+    // and `md5` (its digests, computed while receiving it) are streaming-safe, the other queries
+    // on the body content fail.
+    if let Some(body_file) = &http_response.body_file {
+        match &query.value {
+            QueryValue::Bytes {}
+                if matches!(
+                    query.filters.first(),
+                    Some((
+                        _,
+                        Filter {
+                            value: FilterValue::Count {},
+                            ..
+                        },
+                    ))
+                ) =>
+            {
+                let value = Value::Integer(body_file.size as i64);
+                return eval_filters(&query.filters[1..], Some(value), variables);
+            }
+            QueryValue::Bytes {}
+            | QueryValue::Body {}
+            | QueryValue::Xpath { .. }
+            | QueryValue::Jsonpath { .. }
+            | QueryValue::Regex { .. } => {
+                return Err(Error {
+                    source_info: query.source_info,
+                    inner: RunnerError::StreamedBody {
+                        path: body_file.path.clone(),
+                    },
+                    assert: false,
+                });
+            }
+            _ => {}
         }
-    } else {
-        Ok(value)
     }
+    let value = eval_query_value(query.clone(), variables, http_response)?;
+    eval_filters(&query.filters, value, variables)
 }
 pub fn eval_query_value(
     query: Query,
@@ -221,21 +245,70 @@ pub fn eval_query_value(
                 Ok(None)
             }
         }
+        QueryValue::Url {} => Ok(Some(Value::String(http_response.url))),
+        QueryValue::Ip {} => Ok(http_response.primary_ip.map(Value::String)),
+        QueryValue::Redirects {} => {
+            let redirects = http_response
+                .redirects
+                .iter()
+                .map(|redirect| {
+                    Value::Object(vec![
+                        ("url".to_string(), Value::String(redirect.url.clone())),
+                        ("status".to_string(), Value::Integer(redirect.status as i64)),
+                    ])
+                })
+                .collect();
+            Ok(Some(Value::List(redirects)))
+        }
         QueryValue::Duration {} => Ok(Some(Value::Integer(
             http_response.duration.as_millis() as i64
         ))),
-        QueryValue::Bytes {} => Ok(Some(Value::Bytes(http_response.body))),
-        QueryValue::Sha256 {} => {
-            let mut hasher = sha2::Sha256::new();
-            hasher.update(http_response.body);
-            let result = hasher.finalize();
-            let bytes = Value::Bytes(result[..].to_vec());
-            Ok(Some(bytes))
+        QueryValue::Timing { name, .. } => {
+            let timings = http_response.timings;
+            let duration = match name {
+                TimingName::NameLookup => timings.name_lookup,
+                TimingName::Connect => timings.connect,
+                TimingName::AppConnect => timings.app_connect,
+                TimingName::PreTransfer => timings.pre_transfer,
+                TimingName::StartTransfer => timings.start_transfer,
+                TimingName::Total => timings.total,
+            };
+            Ok(Some(Value::Integer(duration.as_millis() as i64)))
         }
-        QueryValue::Md5 {} => {
-            let bytes = md5::compute(http_response.body).to_vec();
-            Ok(Some(Value::Bytes(bytes)))
+        QueryValue::Certificate { attribute_name, .. } => {
+            if let Some(certificate) = http_response.certificate {
+                let value = match attribute_name {
+                    CertificateAttributeName::Subject => Value::String(certificate.subject),
+                    CertificateAttributeName::Issuer => Value::String(certificate.issuer),
+                    CertificateAttributeName::StartDate => Value::Date(certificate.start_date),
+                    CertificateAttributeName::ExpireDate => Value::Date(certificate.expire_date),
+                    CertificateAttributeName::SerialNumber => {
+                        Value::String(certificate.serial_number)
+                    }
+                };
+                Ok(Some(value))
+            } else {
+                Ok(None)
+            }
         }
+        QueryValue::Bytes {} => Ok(Some(Value::Bytes(http_response.body))),
+        QueryValue::Sha256 {} => match http_response.body_file {
+            Some(body_file) => Ok(Some(Value::Bytes(body_file.sha256))),
+            None => {
+                let mut hasher = sha2::Sha256::new();
+                hasher.update(http_response.body);
+                let result = hasher.finalize();
+                let bytes = Value::Bytes(result[..].to_vec());
+                Ok(Some(bytes))
+            }
+        },
+        QueryValue::Md5 {} => match http_response.body_file {
+            Some(body_file) => Ok(Some(Value::Bytes(body_file.md5))),
+            None => {
+                let bytes = md5::compute(http_response.body).to_vec();
+                Ok(Some(Value::Bytes(bytes)))
+            }
+        },
     }
 }
 
@@ -298,8 +371,10 @@ impl Value {
 #[cfg(test)]
 pub mod tests {
     use super::*;
+    use chrono::{DateTime, Utc};
     use hex_literal::hex;
     use hurl_core::ast::{Pos, SourceInfo};
+    use std::time::Duration;
 
     pub fn xpath_invalid_query() -> Query {
         // xpath ???
@@ -320,7 +395,7 @@ pub mod tests {
                     source_info: SourceInfo::init(1, 7, 1, 10),
                 },
             },
-            subquery: None,
+            filters: vec![],
         }
     }
 
@@ -342,7 +417,7 @@ pub mod tests {
                     source_info: SourceInfo::init(0, 0, 0, 0),
                 },
             },
-            subquery: None,
+            filters: vec![],
         }
     }
 
@@ -364,7 +439,7 @@ pub mod tests {
                     source_info: SourceInfo::init(0, 0, 0, 0),
                 },
             },
-            subquery: None,
+            filters: vec![],
         }
     }
 
@@ -386,6 +461,7 @@ pub mod tests {
                 .to_string(),
             ),
             duration: Default::default(),
+            ..Default::default()
         }
     }
 
@@ -408,7 +484,7 @@ pub mod tests {
                     source_info: SourceInfo::init(1, 10, 1, 19),
                 },
             },
-            subquery: None,
+            filters: vec![],
         }
     }
 
@@ -431,7 +507,7 @@ pub mod tests {
                     source_info: SourceInfo::init(1, 10, 1, 18),
                 },
             },
-            subquery: None,
+            filters: vec![],
         }
     }
 
@@ -454,16 +530,16 @@ pub mod tests {
                     source_info: SourceInfo::init(1, 10, 1, 18),
                 },
             },
-            subquery: Some((
+            filters: vec![(
                 Whitespace {
                     value: "".to_string(),
                     source_info: SourceInfo::init(0, 0, 0, 0),
                 },
-                Subquery {
+                Filter {
                     source_info: SourceInfo::init(0, 0, 0, 0),
-                    value: SubqueryValue::Count {},
+                    value: FilterValue::Count {},
                 },
-            )),
+            )],
         }
     }
 
@@ -486,7 +562,7 @@ pub mod tests {
                     source_info: SourceInfo::init(1, 10, 1, 18),
                 },
             },
-            subquery: None,
+            filters: vec![],
         }
     }
 
@@ -508,7 +584,7 @@ pub mod tests {
                     source_info: SourceInfo::init(1, 7, 1, 26),
                 },
             },
-            subquery: None,
+            filters: vec![],
         }
     }
 
@@ -530,7 +606,7 @@ pub mod tests {
                     source_info: SourceInfo::init(1, 7, 1, 10),
                 },
             },
-            subquery: None,
+            filters: vec![],
         }
     }
 
@@ -542,7 +618,7 @@ pub mod tests {
                 Query {
                     source_info: SourceInfo::init(0, 0, 0, 0),
                     value: QueryValue::Status {},
-                    subquery: None
+                    filters: vec![]
                 },
                 &variables,
                 http::hello_http_response(),
@@ -573,7 +649,7 @@ pub mod tests {
                     source_info: SourceInfo::init(2, 8, 2, 14),
                 },
             },
-            subquery: None,
+            filters: vec![],
         };
         //    let error = query_header.eval(http::hello_http_response()).err().unwrap();
         //    assert_eq!(error.source_info.start, Pos { line: 1, column: 8 });
@@ -604,7 +680,7 @@ pub mod tests {
                     source_info: SourceInfo::init(1, 8, 1, 16),
                 },
             },
-            subquery: None,
+            filters: vec![],
         };
         assert_eq!(
             eval_query(query_header, &variables, http::hello_http_response())
@@ -632,6 +708,7 @@ pub mod tests {
             ],
             body: vec![],
             duration: Default::default(),
+            ..Default::default()
         };
 
         // cookie "LSID"
@@ -651,7 +728,7 @@ pub mod tests {
                     attribute: None,
                 },
             },
-            subquery: None,
+            filters: vec![],
         };
         assert_eq!(
             eval_query(query, &variables, response.clone())
@@ -681,7 +758,7 @@ pub mod tests {
                     }),
                 },
             },
-            subquery: None,
+            filters: vec![],
         };
         assert_eq!(
             eval_query(query, &variables, response.clone())
@@ -711,7 +788,7 @@ pub mod tests {
                     }),
                 },
             },
-            subquery: None,
+            filters: vec![],
         };
         assert_eq!(
             eval_query(query, &variables, response.clone())
@@ -741,7 +818,7 @@ pub mod tests {
                     }),
                 },
             },
-            subquery: None,
+            filters: vec![],
         };
         assert_eq!(eval_query(query, &variables, response).unwrap(), None);
     }
@@ -832,7 +909,7 @@ pub mod tests {
                 Query {
                     source_info: SourceInfo::init(0, 0, 0, 0),
                     value: QueryValue::Body {},
-                    subquery: None
+                    filters: vec![]
                 },
                 &variables,
                 http::hello_http_response(),
@@ -845,7 +922,7 @@ pub mod tests {
             Query {
                 source_info: SourceInfo::init(1, 1, 1, 2),
                 value: QueryValue::Body {},
-                subquery: None,
+                filters: vec![],
             },
             &variables,
             http::bytes_http_response(),
@@ -870,6 +947,7 @@ pub mod tests {
             headers: vec![],
             body: vec![200],
             duration: Default::default(),
+            ..Default::default()
         };
         let error = eval_query(xpath_users(), &variables, http_response)
             .err()
@@ -903,7 +981,7 @@ pub mod tests {
                     source_info: SourceInfo::init(1, 7, 1, 10),
                 },
             },
-            subquery: None,
+            filters: vec![],
         };
         let error = eval_query(query, &variables, http::xml_two_users_http_response())
             .err()
@@ -958,7 +1036,7 @@ pub mod tests {
                     source_info: SourceInfo::init(0, 0, 0, 0),
                 },
             },
-            subquery: None,
+            filters: vec![],
         }
     }
 
@@ -995,7 +1073,7 @@ pub mod tests {
                     source_info: SourceInfo::init(1, 10, 1, 13),
                 },
             },
-            subquery: None,
+            filters: vec![],
         };
 
         let error = eval_query(jsonpath_query, &variables, json_http_response())
@@ -1025,6 +1103,7 @@ pub mod tests {
             headers: vec![],
             body: String::into_bytes(String::from("xxx")),
             duration: Default::default(),
+            ..Default::default()
         };
         let error = eval_query(jsonpath_success(), &variables, http_response)
             .err()
@@ -1042,6 +1121,7 @@ pub mod tests {
             headers: vec![],
             body: String::into_bytes(String::from("{}")),
             duration: Default::default(),
+            ..Default::default()
         };
         //assert_eq!(jsonpath_success().eval(http_response).unwrap(), Value::List(vec![]));
         assert_eq!(
@@ -1108,7 +1188,7 @@ pub mod tests {
                 Query {
                     source_info: SourceInfo::init(0, 0, 0, 0),
                     value: QueryValue::Bytes {},
-                    subquery: None
+                    filters: vec![]
                 },
                 &variables,
                 http::hello_http_response(),
@@ -1127,7 +1207,7 @@ pub mod tests {
                 Query {
                     source_info: SourceInfo::init(0, 0, 0, 0),
                     value: QueryValue::Sha256 {},
-                    subquery: None
+                    filters: vec![]
                 },
                 &variables,
                 http::Response {
@@ -1136,6 +1216,7 @@ pub mod tests {
                     headers: vec![],
                     body: vec![0xff],
                     duration: Default::default(),
+                    ..Default::default()
                 },
             )
             .unwrap()
@@ -1145,4 +1226,275 @@ pub mod tests {
             )
         );
     }
+
+    #[test]
+    fn test_query_timing() {
+        let variables = HashMap::new();
+        let http_response = http::Response {
+            version: http::Version::Http10,
+            status: 200,
+            headers: vec![],
+            body: vec![],
+            duration: Duration::from_millis(120),
+            timings: http::Timings {
+                name_lookup: Duration::from_micros(1500),
+                connect: Duration::from_millis(20),
+                app_connect: Duration::from_millis(50),
+                pre_transfer: Duration::from_millis(51),
+                start_transfer: Duration::from_millis(110),
+                total: Duration::from_millis(118),
+            },
+            url: "http://localhost".to_string(),
+            primary_ip: Some("127.0.0.1".to_string()),
+            primary_port: 80,
+            local_port: 0,
+            certificate: None,
+            body_file: None,
+            redirects: vec![],
+        };
+        let timing_query = |name| Query {
+            source_info: SourceInfo::init(0, 0, 0, 0),
+            value: QueryValue::Timing {
+                space0: Whitespace {
+                    value: String::from(" "),
+                    source_info: SourceInfo::init(0, 0, 0, 0),
+                },
+                name,
+            },
+            filters: vec![],
+        };
+        for (name, expected) in [
+            (TimingName::NameLookup, 1),
+            (TimingName::Connect, 20),
+            (TimingName::AppConnect, 50),
+            (TimingName::PreTransfer, 51),
+            (TimingName::StartTransfer, 110),
+            (TimingName::Total, 118),
+        ] {
+            assert_eq!(
+                eval_query(timing_query(name), &variables, http_response.clone())
+                    .unwrap()
+                    .unwrap(),
+                Value::Integer(expected)
+            );
+        }
+    }
+
+    #[test]
+    fn test_query_url_ip_redirects() {
+        let variables = HashMap::new();
+        let http_response = http::Response {
+            url: "http://localhost:8000/redirected".to_string(),
+            primary_ip: Some("127.0.0.1".to_string()),
+            redirects: vec![
+                http::Redirect {
+                    url: "http://localhost:8000/redirect".to_string(),
+                    status: 302,
+                },
+                http::Redirect {
+                    url: "http://localhost:8000/redirecting".to_string(),
+                    status: 301,
+                },
+            ],
+            ..http::hello_http_response()
+        };
+        let query = |value| Query {
+            source_info: SourceInfo::init(0, 0, 0, 0),
+            value,
+            filters: vec![],
+        };
+        assert_eq!(
+            eval_query(query(QueryValue::Url {}), &variables, http_response.clone())
+                .unwrap()
+                .unwrap(),
+            Value::String("http://localhost:8000/redirected".to_string())
+        );
+        assert_eq!(
+            eval_query(query(QueryValue::Ip {}), &variables, http_response.clone())
+                .unwrap()
+                .unwrap(),
+            Value::String("127.0.0.1".to_string())
+        );
+        assert_eq!(
+            eval_query(query(QueryValue::Redirects {}), &variables, http_response)
+                .unwrap()
+                .unwrap(),
+            Value::List(vec![
+                Value::Object(vec![
+                    (
+                        "url".to_string(),
+                        Value::String("http://localhost:8000/redirect".to_string())
+                    ),
+                    ("status".to_string(), Value::Integer(302)),
+                ]),
+                Value::Object(vec![
+                    (
+                        "url".to_string(),
+                        Value::String("http://localhost:8000/redirecting".to_string())
+                    ),
+                    ("status".to_string(), Value::Integer(301)),
+                ]),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_query_certificate() {
+        let variables = HashMap::new();
+        let query = |attribute_name| Query {
+            source_info: SourceInfo::init(0, 0, 0, 0),
+            value: QueryValue::Certificate {
+                space0: Whitespace {
+                    value: String::from(" "),
+                    source_info: SourceInfo::init(0, 0, 0, 0),
+                },
+                attribute_name,
+            },
+            filters: vec![],
+        };
+        assert!(eval_query(
+            query(CertificateAttributeName::Subject),
+            &variables,
+            http::hello_http_response()
+        )
+        .unwrap()
+        .is_none());
+
+        let expire_date = DateTime::parse_from_rfc3339("2023-02-14T09:52:33Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let http_response = http::Response {
+            certificate: Some(http::Certificate {
+                subject: "C=US, CN=localhost".to_string(),
+                issuer: "C=US, CN=localhost".to_string(),
+                start_date: DateTime::parse_from_rfc3339("2022-01-15T09:52:33Z")
+                    .unwrap()
+                    .with_timezone(&Utc),
+                expire_date,
+                serial_number: "1e:e8:b1:7f:1b:64:d8:d6:b3:de:87:01:03:8a:b4:0f".to_string(),
+            }),
+            ..http::hello_http_response()
+        };
+        assert_eq!(
+            eval_query(
+                query(CertificateAttributeName::Subject),
+                &variables,
+                http_response.clone()
+            )
+            .unwrap()
+            .unwrap(),
+            Value::String("C=US, CN=localhost".to_string())
+        );
+        assert_eq!(
+            eval_query(
+                query(CertificateAttributeName::ExpireDate),
+                &variables,
+                http_response.clone()
+            )
+            .unwrap()
+            .unwrap(),
+            Value::Date(expire_date)
+        );
+        assert_eq!(
+            eval_query(
+                query(CertificateAttributeName::SerialNumber),
+                &variables,
+                http_response
+            )
+            .unwrap()
+            .unwrap(),
+            Value::String("1e:e8:b1:7f:1b:64:d8:d6:b3:de:87:01:03:8a:b4:0f".to_string())
+        );
+    }
+
+    #[test]
+    fn test_query_body_file() {
+        let variables = HashMap::new();
+        let http_response = http::Response {
+            body: vec![],
+            body_file: Some(http::BodyFile {
+                path: "data.bin".to_string(),
+                size: 3,
+                sha256: hex!("039058c6f2c0cb492c533b0a4d14ef77cc0f78abccced5287d84a1a2011cfb81")
+                    .to_vec(),
+                md5: hex!("5289df737df57326fcdd22597afb1fac").to_vec(),
+            }),
+            ..http::hello_http_response()
+        };
+        let query = |value, filters| Query {
+            source_info: SourceInfo::init(0, 0, 0, 0),
+            value,
+            filters,
+        };
+        let count = Filter {
+            source_info: SourceInfo::init(0, 0, 0, 0),
+            value: FilterValue::Count {},
+        };
+        let whitespace = Whitespace {
+            value: String::from(" "),
+            source_info: SourceInfo::init(0, 0, 0, 0),
+        };
+
+        // the body file is not read to count its bytes
+        assert_eq!(
+            eval_query(
+                query(QueryValue::Bytes {}, vec![(whitespace, count)]),
+                &variables,
+                http_response.clone()
+            )
+            .unwrap()
+            .unwrap(),
+            Value::Integer(3)
+        );
+        assert_eq!(
+            eval_query(
+                query(QueryValue::Sha256 {}, vec![]),
+                &variables,
+                http_response.clone()
+            )
+            .unwrap()
+            .unwrap(),
+            Value::Bytes(
+                hex!("039058c6f2c0cb492c533b0a4d14ef77cc0f78abccced5287d84a1a2011cfb81").to_vec()
+            )
+        );
+        assert_eq!(
+            eval_query(
+                query(QueryValue::Md5 {}, vec![]),
+                &variables,
+                http_response.clone()
+            )
+            .unwrap()
+            .unwrap(),
+            Value::Bytes(hex!("5289df737df57326fcdd22597afb1fac").to_vec())
+        );
+
+        // the body content is not available
+        assert_eq!(
+            eval_query(
+                query(QueryValue::Body {}, vec![]),
+                &variables,
+                http_response.clone()
+            )
+            .err()
+            .unwrap()
+            .inner,
+            RunnerError::StreamedBody {
+                path: "data.bin".to_string()
+            }
+        );
+        assert_eq!(
+            eval_query(
+                query(QueryValue::Bytes {}, vec![]),
+                &variables,
+                http_response
+            )
+            .err()
+            .unwrap()
+            .inner,
+            RunnerError::StreamedBody {
+                path: "data.bin".to_string()
+            }
+        );
+    }
 }
@@ -226,10 +226,10 @@ mod tests {
                 elements: vec![
                     TemplateElement::Expression(Expr {
                         space0: whitespace(),
-                        variable: Variable {
+                        kind: ExprKind::Variable(Variable {
                             name: String::from("base_url"),
                             source_info: SourceInfo::init(1, 7, 1, 15),
-                        },
+                        }),
                         space1: whitespace(),
                     }),
                     TemplateElement::String {
@@ -302,10 +302,10 @@ mod tests {
                             quotes: false,
                             elements: vec![TemplateElement::Expression(Expr {
                                 space0: whitespace(),
-                                variable: Variable {
+                                kind: ExprKind::Variable(Variable {
                                     name: String::from("param1"),
                                     source_info: SourceInfo::init(1, 7, 1, 15),
-                                },
+                                }),
                                 space1: whitespace(),
                             })],
                             source_info: SourceInfo::init(0, 0, 0, 0),
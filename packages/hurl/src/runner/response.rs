@@ -34,6 +34,7 @@ pub fn eval_asserts(
     variables: &HashMap<String, Value>,
     http_response: http::Response,
     context_dir: &Path,
+    ignored_json_keys: &[String],
 ) -> Vec<AssertResult> {
     let mut asserts = vec![];
 
@@ -112,113 +113,126 @@ pub fn eval_asserts(
 
     // implicit assert on body
     if let Some(body) = response.clone().body {
-        match body.value {
-            Bytes::Json { value } => {
-                let expected = match eval_json_value(value, variables) {
-                    Ok(s) => Ok(Value::String(s)),
-                    Err(e) => Err(e),
-                };
-                let actual = match http_response.text() {
-                    Ok(s) => Ok(Value::String(s)),
-                    Err(e) => Err(Error {
-                        source_info: SourceInfo {
-                            start: body.space0.source_info.end.clone(),
-                            end: body.space0.source_info.end.clone(),
-                        },
-                        inner: e,
-                        assert: true,
-                    }),
-                };
-                asserts.push(AssertResult::Body {
-                    actual,
-                    expected,
-                    source_info: body.space0.source_info.clone(),
-                })
-            }
-            Bytes::Xml { value } => {
-                let expected = Ok(Value::String(value));
-                let actual = match http_response.text() {
-                    Ok(s) => Ok(Value::String(s)),
-                    Err(e) => Err(Error {
-                        source_info: SourceInfo {
-                            start: body.space0.source_info.end.clone(),
-                            end: body.space0.source_info.end.clone(),
-                        },
-                        inner: e,
-                        assert: true,
-                    }),
-                };
-                asserts.push(AssertResult::Body {
-                    actual,
-                    expected,
-                    source_info: body.space0.source_info.clone(),
-                })
-            }
-            Bytes::RawString(RawString { value, .. }) => {
-                let expected = match eval_template(&value, variables) {
-                    Ok(s) => Ok(Value::String(s)),
-                    Err(e) => Err(e),
-                };
-                let actual = match http_response.text() {
-                    Ok(s) => Ok(Value::String(s)),
-                    Err(e) => Err(Error {
-                        source_info: SourceInfo {
-                            start: body.space0.source_info.end.clone(),
-                            end: body.space0.source_info.end.clone(),
-                        },
-                        inner: e,
-                        assert: true,
-                    }),
-                };
-                asserts.push(AssertResult::Body {
-                    actual,
-                    expected,
-                    source_info: value.source_info,
-                })
-            }
-            Bytes::Base64(Base64 {
-                value,
-                space0,
-                space1,
-                ..
-            }) => asserts.push(AssertResult::Body {
-                actual: Ok(Value::Bytes(http_response.body.clone())),
-                expected: Ok(Value::Bytes(value)),
-                source_info: SourceInfo {
-                    start: space0.source_info.end,
-                    end: space1.source_info.start,
-                },
-            }),
-            Bytes::Hex(Hex {
-                value,
-                space0,
-                space1,
-                ..
-            }) => asserts.push(AssertResult::Body {
-                actual: Ok(Value::Bytes(http_response.body.clone())),
-                expected: Ok(Value::Bytes(value)),
-                source_info: SourceInfo {
-                    start: space0.source_info.end,
-                    end: space1.source_info.start,
-                },
-            }),
-            Bytes::File { .. } => {
-                let expected = match eval_body(body.clone(), variables, context_dir) {
-                    Ok(body) => Ok(Value::Bytes(body.bytes())),
-                    Err(e) => Err(e),
-                };
-                let actual = Ok(Value::Bytes(http_response.body.clone()));
-                asserts.push(AssertResult::Body {
-                    actual,
-                    expected,
+        if let Some(body_file) = &http_response.body_file {
+            // a body streamed to a file is not kept in memory and can not be compared
+            asserts.push(AssertResult::Body {
+                actual: Err(Error {
                     source_info: body.space0.source_info.clone(),
-                })
-            }
-        };
+                    inner: RunnerError::StreamedBody {
+                        path: body_file.path.clone(),
+                    },
+                    assert: true,
+                }),
+                expected: Ok(Value::Unit),
+                source_info: body.space0.source_info.clone(),
+            });
+        } else {
+            match body.value {
+                Bytes::Json { value } => {
+                    let expected = eval_json_value(value, variables);
+                    let actual = match http_response.text() {
+                        Ok(s) => Ok(s),
+                        Err(e) => Err(Error {
+                            source_info: SourceInfo {
+                                start: body.space0.source_info.end.clone(),
+                                end: body.space0.source_info.end.clone(),
+                            },
+                            inner: e,
+                            assert: true,
+                        }),
+                    };
+                    asserts.push(AssertResult::JsonBody {
+                        actual,
+                        expected,
+                        ignored_keys: ignored_json_keys.to_vec(),
+                        source_info: body.space0.source_info.clone(),
+                    })
+                }
+                Bytes::Xml { value } => {
+                    let expected = Ok(Value::String(value));
+                    let actual = match http_response.text() {
+                        Ok(s) => Ok(Value::String(s)),
+                        Err(e) => Err(Error {
+                            source_info: SourceInfo {
+                                start: body.space0.source_info.end.clone(),
+                                end: body.space0.source_info.end.clone(),
+                            },
+                            inner: e,
+                            assert: true,
+                        }),
+                    };
+                    asserts.push(AssertResult::Body {
+                        actual,
+                        expected,
+                        source_info: body.space0.source_info.clone(),
+                    })
+                }
+                Bytes::RawString(RawString { value, .. }) => {
+                    let expected = match eval_template(&value, variables) {
+                        Ok(s) => Ok(Value::String(s)),
+                        Err(e) => Err(e),
+                    };
+                    let actual = match http_response.text() {
+                        Ok(s) => Ok(Value::String(s)),
+                        Err(e) => Err(Error {
+                            source_info: SourceInfo {
+                                start: body.space0.source_info.end.clone(),
+                                end: body.space0.source_info.end.clone(),
+                            },
+                            inner: e,
+                            assert: true,
+                        }),
+                    };
+                    asserts.push(AssertResult::Body {
+                        actual,
+                        expected,
+                        source_info: value.source_info,
+                    })
+                }
+                Bytes::Base64(Base64 {
+                    value,
+                    space0,
+                    space1,
+                    ..
+                }) => asserts.push(AssertResult::Body {
+                    actual: Ok(Value::Bytes(http_response.body.clone())),
+                    expected: Ok(Value::Bytes(value)),
+                    source_info: SourceInfo {
+                        start: space0.source_info.end,
+                        end: space1.source_info.start,
+                    },
+                }),
+                Bytes::Hex(Hex {
+                    value,
+                    space0,
+                    space1,
+                    ..
+                }) => asserts.push(AssertResult::Body {
+                    actual: Ok(Value::Bytes(http_response.body.clone())),
+                    expected: Ok(Value::Bytes(value)),
+                    source_info: SourceInfo {
+                        start: space0.source_info.end,
+                        end: space1.source_info.start,
+                    },
+                }),
+                Bytes::File { .. } | Bytes::TemplateFile { .. } => {
+                    let expected = match eval_body(body.clone(), variables, context_dir) {
+                        Ok(body) => Ok(Value::Bytes(body.bytes())),
+                        Err(e) => Err(e),
+                    };
+                    let actual = Ok(Value::Bytes(http_response.body.clone()));
+                    asserts.push(AssertResult::Body {
+                        actual,
+                        expected,
+                        source_info: body.space0.source_info.clone(),
+                    })
+                }
+            };
+        }
     }
 
     for assert in response.asserts() {
-        let assert_result = eval_assert(assert, variables, http_response.clone());
+        let assert_result = eval_assert(assert, variables, http_response.clone(), context_dir);
         asserts.push(assert_result);
     }
     asserts
@@ -300,6 +314,7 @@ mod tests {
                 &variables,
                 http::xml_two_users_http_response(),
                 context_dir,
+                &[],
             ),
             vec![
                 AssertResult::Version {
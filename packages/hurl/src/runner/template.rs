@@ -20,6 +20,7 @@ use std::collections::HashMap;
 use hurl_core::ast::*;
 
 use super::core::{Error, RunnerError};
+use super::expr::eval_expr;
 use super::value::Value;
 
 /// Renders to string a `template` given a map of variables.
@@ -49,27 +50,18 @@ fn eval_template_element(
 }
 
 pub fn eval_expression(expr: Expr, variables: &HashMap<String, Value>) -> Result<String, Error> {
-    let source_info = expr.variable.source_info;
-    let name = expr.variable.name;
-    match variables.get(name.as_str()) {
-        Some(value) => {
-            if value.is_renderable() {
-                Ok(value.clone().to_string())
-            } else {
-                Err(Error {
-                    source_info,
-                    inner: RunnerError::UnrenderableVariable {
-                        value: value.to_string(),
-                    },
-                    assert: false,
-                })
-            }
-        }
-        _ => Err(Error {
+    let source_info = expr.source_info();
+    let value = eval_expr(expr, variables)?;
+    if value.is_renderable() {
+        Ok(value.to_string())
+    } else {
+        Err(Error {
             source_info,
-            inner: RunnerError::TemplateVariableNotDefined { name },
+            inner: RunnerError::UnrenderableVariable {
+                value: value.to_string(),
+            },
             assert: false,
-        }),
+        })
     }
 }
 
@@ -77,7 +69,12 @@ impl Value {
     pub fn is_renderable(&self) -> bool {
         matches!(
             self,
-            Value::Integer(_) | Value::Bool(_) | Value::Float(_) | Value::String(_) | Value::Null
+            Value::Integer(_)
+                | Value::Bool(_)
+                | Value::Float(_)
+                | Value::String(_)
+                | Value::Date(_)
+                | Value::Null
         )
     }
 }
@@ -95,10 +92,10 @@ mod tests {
                 value: "".to_string(),
                 source_info: SourceInfo::init(1, 3, 1, 3),
             },
-            variable: Variable {
+            kind: ExprKind::Variable(Variable {
                 name: "name".to_string(),
                 source_info: SourceInfo::init(1, 3, 1, 7),
-            },
+            }),
             space1: Whitespace {
                 value: "".to_string(),
                 source_info: SourceInfo::init(1, 7, 1, 7),
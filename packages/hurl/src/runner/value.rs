@@ -17,6 +17,8 @@
  */
 use std::fmt;
 
+use chrono::{DateTime, SecondsFormat, Utc};
+
 ///
 /// Type system used in hurl
 /// Values are used by queries, captures, asserts and predicates
@@ -25,6 +27,7 @@ use std::fmt;
 pub enum Value {
     Bool(bool),
     Bytes(Vec<u8>),
+    Date(DateTime<Utc>),
     Float(f64),
     Integer(i64),
     List(Vec<Value>),
@@ -42,6 +45,7 @@ impl PartialEq for Value {
         match (self, other) {
             (Value::Bool(v1), Value::Bool(v2)) => v1 == v2,
             (Value::Bytes(v1), Value::Bytes(v2)) => v1 == v2,
+            (Value::Date(v1), Value::Date(v2)) => v1 == v2,
             (Value::Float(v1), Value::Float(v2)) => (v1 - v2).abs() < f64::EPSILON,
             (Value::Integer(v1), Value::Integer(v2)) => v1 == v2,
             (Value::List(v1), Value::List(v2)) => v1 == v2,
@@ -71,6 +75,7 @@ impl fmt::Display for Value {
             Value::Object(_) => "Object()".to_string(),
             Value::Nodeset(x) => format!("Nodeset{:?}", x),
             Value::Bytes(v) => format!("hex, {};", hex::encode(v)),
+            Value::Date(v) => format_date(v),
             Value::Null => "null".to_string(),
             Value::Unit => "Unit".to_string(),
             Value::Regex(x) => {
@@ -90,6 +95,11 @@ fn format_float(value: f64) -> String {
     }
 }
 
+/// Formats a date in RFC 3339, for instance `2022-01-06T17:30:00Z`.
+pub fn format_date(value: &DateTime<Utc>) -> String {
+    value.to_rfc3339_opts(SecondsFormat::AutoSi, true)
+}
+
 impl Value {
     pub fn _type(&self) -> String {
         match self {
@@ -101,6 +111,7 @@ impl Value {
             Value::Object(_) => "object".to_string(),
             Value::Nodeset(_) => "nodeset".to_string(),
             Value::Bytes(_) => "bytes".to_string(),
+            Value::Date(_) => "date".to_string(),
             Value::Null => "null".to_string(),
             Value::Unit => "unit".to_string(),
             Value::Regex(_) => "regex".to_string(),
@@ -130,5 +141,14 @@ mod tests {
     fn test_to_string() {
         assert_eq!(Value::Float(1.0).to_string(), "1.0".to_string());
         assert_eq!(Value::Float(1.1).to_string(), "1.1".to_string());
+        assert_eq!(
+            Value::Date(
+                DateTime::parse_from_rfc3339("2022-01-06T17:30:00Z")
+                    .unwrap()
+                    .with_timezone(&Utc)
+            )
+            .to_string(),
+            "2022-01-06T17:30:00Z".to_string()
+        );
     }
 }
@@ -19,6 +19,7 @@
 // Unique entry point to libxml2
 
 use std::ffi::CStr;
+use std::os::raw::{c_char, c_int, c_void};
 
 use super::value::Value;
 
@@ -30,6 +31,15 @@ pub enum XpathError {
     Unsupported,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum XmlValidationError {
+    InvalidXml,
+    /// The XML Schema or DTD can not be parsed
+    InvalidSchema(String),
+    /// Line number and message of each validation error
+    Invalid(Vec<(usize, String)>),
+}
+
 /// Eval a XPath 1.0 expression against a XML text.
 ///
 /// # Arguments
@@ -74,6 +84,147 @@ pub fn eval_html(html: &str, expr: &str) -> Result<Value, XpathError> {
     }
 }
 
+/// Validates a XML text against a XML Schema (XSD).
+///
+/// # Arguments
+///
+/// * `xml` - A string slice that holds a XML body
+/// * `schema`- The content of the XML Schema
+///
+pub fn validate_xml_schema(xml: &str, schema: &[u8]) -> Result<(), XmlValidationError> {
+    let doc = parse_xml(xml)?;
+    let mut errors: Vec<(usize, String)> = vec![];
+    let errors_ptr = &mut errors as *mut Vec<(usize, String)> as *mut c_void;
+
+    unsafe {
+        let parser = libxml::bindings::xmlSchemaNewMemParserCtxt(
+            schema.as_ptr() as *const c_char,
+            schema.len() as c_int,
+        );
+        libxml::bindings::xmlSchemaSetParserStructuredErrors(
+            parser,
+            structured_error_handler(),
+            errors_ptr,
+        );
+        let schema = libxml::bindings::xmlSchemaParse(parser);
+        libxml::bindings::xmlSchemaFreeParserCtxt(parser);
+        if schema.is_null() {
+            return Err(XmlValidationError::InvalidSchema(error_messages(&errors)));
+        }
+
+        let context = libxml::bindings::xmlSchemaNewValidCtxt(schema);
+        libxml::bindings::xmlSchemaSetValidStructuredErrors(
+            context,
+            structured_error_handler(),
+            errors_ptr,
+        );
+        let result = libxml::bindings::xmlSchemaValidateDoc(context, doc.doc_ptr());
+        libxml::bindings::xmlSchemaFreeValidCtxt(context);
+        libxml::bindings::xmlSchemaFree(schema);
+        if result == 0 {
+            Ok(())
+        } else {
+            Err(XmlValidationError::Invalid(errors))
+        }
+    }
+}
+
+/// Validates a XML text against a Document Type Definition (DTD).
+///
+/// # Arguments
+///
+/// * `xml` - A string slice that holds a XML body
+/// * `dtd`- The content of the DTD
+///
+pub fn validate_xml_dtd(xml: &str, dtd: &[u8]) -> Result<(), XmlValidationError> {
+    let doc = parse_xml(xml)?;
+    let mut errors: Vec<(usize, String)> = vec![];
+
+    // libxml2 doesn't offer a per-context error handler for DTD, so errors are
+    // collected with the (thread local) structured error handler.
+    unsafe {
+        libxml::bindings::xmlSetStructuredErrorFunc(
+            &mut errors as *mut Vec<(usize, String)> as *mut c_void,
+            structured_error_handler(),
+        );
+        let input = libxml::bindings::xmlParserInputBufferCreateMem(
+            dtd.as_ptr() as *const c_char,
+            dtd.len() as c_int,
+            libxml::bindings::xmlCharEncoding_XML_CHAR_ENCODING_NONE,
+        );
+        // the input buffer is freed by xmlIOParseDTD
+        let dtd = libxml::bindings::xmlIOParseDTD(
+            std::ptr::null_mut(),
+            input,
+            libxml::bindings::xmlCharEncoding_XML_CHAR_ENCODING_NONE,
+        );
+        if dtd.is_null() {
+            libxml::bindings::xmlSetStructuredErrorFunc(std::ptr::null_mut(), None);
+            return Err(XmlValidationError::InvalidSchema(error_messages(&errors)));
+        }
+        let context = libxml::bindings::xmlNewValidCtxt();
+        let valid = libxml::bindings::xmlValidateDtd(context, doc.doc_ptr(), dtd) == 1;
+        libxml::bindings::xmlSetStructuredErrorFunc(std::ptr::null_mut(), None);
+        libxml::bindings::xmlFreeValidCtxt(context);
+        libxml::bindings::xmlFreeDtd(dtd);
+        if valid {
+            Ok(())
+        } else {
+            Err(XmlValidationError::Invalid(errors))
+        }
+    }
+}
+
+fn parse_xml(xml: &str) -> Result<libxml::tree::Document, XmlValidationError> {
+    // libxml2 prints to sdtout warning and errors, so we mut it.
+    unsafe {
+        libxml::bindings::initGenericErrorDefaultFunc(&mut Some(silentErrorFunc));
+    }
+    let parser = libxml::parser::Parser::default();
+    match parser.parse_string(xml) {
+        Ok(doc) if doc.get_root_element().is_some() => Ok(doc),
+        _ => Err(XmlValidationError::InvalidXml),
+    }
+}
+
+fn error_messages(errors: &[(usize, String)]) -> String {
+    errors
+        .iter()
+        .map(|(_, message)| message.as_str())
+        .collect::<Vec<&str>>()
+        .join(", ")
+}
+
+/// Returns the structured error handler collecting libxml2 errors.
+///
+/// libxml2 reuses the same error structure for successive errors, so the line and the
+/// message are copied as soon as the error is raised (we can't keep the error pointers
+/// like `libxml::schemas` does).
+fn structured_error_handler() -> libxml::bindings::xmlStructuredErrorFunc {
+    // The libxml bindings declare the handler with the Rust ABI whereas libxml2 calls it
+    // with the C ABI, so the C function is only relabelled to match the declared type.
+    let handler = unsafe {
+        std::mem::transmute::<
+            unsafe extern "C" fn(*mut c_void, libxml::bindings::xmlErrorPtr),
+            unsafe fn(*mut c_void, libxml::bindings::xmlErrorPtr),
+        >(collect_error)
+    };
+    Some(handler)
+}
+
+/// Structured error handler pushing libxml2 errors to a vector of (line, message).
+unsafe extern "C" fn collect_error(errors: *mut c_void, error: libxml::bindings::xmlErrorPtr) {
+    let errors = &mut *(errors as *mut Vec<(usize, String)>);
+    let message = if (*error).message.is_null() {
+        "".to_string()
+    } else {
+        CStr::from_ptr((*error).message)
+            .to_string_lossy()
+            .to_string()
+    };
+    errors.push(((*error).line as usize, message.trim().to_string()));
+}
+
 extern "C" {
     pub fn silentErrorFunc(
         ctx: *mut ::std::os::raw::c_void,
@@ -239,6 +390,55 @@ mod tests {
         assert_eq!(eval_xml(xml, xpath).unwrap(), Value::from_f64(1.1));
     }
 
+    #[test]
+    fn test_validate_xml_schema() {
+        let schema = r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema">
+  <xs:element name="price" type="xs:decimal"/>
+</xs:schema>"#;
+        assert!(validate_xml_schema("<price>1.5</price>", schema.as_bytes()).is_ok());
+        assert_eq!(
+            validate_xml_schema(
+                "<?xml version=\"1.0\"?>\n<price>abc</price>",
+                schema.as_bytes()
+            )
+            .err()
+            .unwrap(),
+            XmlValidationError::Invalid(vec![(
+                2,
+                "Element 'price': 'abc' is not a valid value of the atomic type 'xs:decimal'."
+                    .to_string()
+            )])
+        );
+        assert_eq!(
+            validate_xml_schema("{}", schema.as_bytes()).err().unwrap(),
+            XmlValidationError::InvalidXml
+        );
+        assert!(matches!(
+            validate_xml_schema("<price>1.5</price>", b"<schema/>"),
+            Err(XmlValidationError::InvalidSchema(_))
+        ));
+    }
+
+    #[test]
+    fn test_validate_xml_dtd() {
+        let dtd = "<!ELEMENT note (to, body)>
+<!ELEMENT to (#PCDATA)>
+<!ELEMENT body (#PCDATA)>";
+        assert!(
+            validate_xml_dtd("<note><to>Bob</to><body>Hi</body></note>", dtd.as_bytes()).is_ok()
+        );
+        let error = validate_xml_dtd("<note>\n<body>Hi</body>\n</note>", dtd.as_bytes())
+            .err()
+            .unwrap();
+        match error {
+            XmlValidationError::Invalid(violations) => {
+                assert_eq!(violations.len(), 1);
+                assert_eq!(violations[0].0, 1);
+            }
+            _ => panic!("expect DTD validation errors"),
+        }
+    }
+
     #[test]
     fn test_error_eval() {
         assert_eq!(eval_xml("<a/>", "^^^").err().unwrap(), XpathError::Eval {});
@@ -0,0 +1,88 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2022 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use serde_json::Value;
+
+/// Replaces the values of the secrets in `s` by `***`.
+pub fn redact(s: &str, secrets: &[String]) -> String {
+    let mut s = s.to_string();
+    for secret in secrets.iter().filter(|secret| !secret.is_empty()) {
+        s = s.replace(secret.as_str(), "***");
+    }
+    s
+}
+
+/// Replaces the values of the secrets in the strings and numbers of a JSON `value` by `***`,
+/// a number containing a secret being turned into a string.
+pub fn redact_json(value: &mut Value, secrets: &[String]) {
+    match value {
+        Value::String(s) => *s = redact(s, secrets),
+        Value::Number(n) => {
+            let s = n.to_string();
+            let redacted = redact(s.as_str(), secrets);
+            if redacted != s {
+                *value = Value::String(redacted);
+            }
+        }
+        Value::Array(values) => {
+            for value in values {
+                redact_json(value, secrets);
+            }
+        }
+        Value::Object(map) => {
+            for value in map.values_mut() {
+                redact_json(value, secrets);
+            }
+        }
+        Value::Null | Value::Bool(_) => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_redact() {
+        let secrets = vec!["abc123".to_string(), "".to_string()];
+        assert_eq!(
+            redact("Authorization: Bearer abc123", &secrets),
+            "Authorization: Bearer ***"
+        );
+        assert_eq!(redact("abc123abc123", &secrets), "******");
+        assert_eq!(redact("hello", &secrets), "hello");
+    }
+
+    #[test]
+    fn test_redact_json() {
+        let secrets = vec!["s3cr3t".to_string(), "42".to_string(), "\"".to_string()];
+        let mut value = serde_json::json!({
+            "headers": [{"name": "Authorization", "value": "Bearer s3cr3t"}],
+            "captures": [{"name": "code", "value": 42}, {"name": "count", "value": 7}],
+            "success": true
+        });
+        redact_json(&mut value, &secrets);
+        assert_eq!(
+            value,
+            serde_json::json!({
+                "headers": [{"name": "Authorization", "value": "Bearer ***"}],
+                "captures": [{"name": "code", "value": "***"}, {"name": "count", "value": 7}],
+                "success": true
+            })
+        );
+    }
+}
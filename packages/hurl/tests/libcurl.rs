@@ -443,18 +443,21 @@ fn test_multipart_form_data() {
                 filename: "data.txt".to_string(),
                 data: b"Hello World!".to_vec(),
                 content_type: "text/plain".to_string(),
+                inline: false,
             }),
             MultipartParam::FileParam(FileParam {
                 name: "upload2".to_string(),
                 filename: "data.html".to_string(),
                 data: b"<div>Hello <b>World</b>!</div>".to_vec(),
                 content_type: "text/html".to_string(),
+                inline: false,
             }),
             MultipartParam::FileParam(FileParam {
                 name: "upload3".to_string(),
                 filename: "data.txt".to_string(),
                 data: b"Hello World!".to_vec(),
                 content_type: "text/html".to_string(),
+                inline: false,
             }),
         ],
         cookies: vec![],
@@ -23,6 +23,7 @@ use hurl_core::ast::*;
 use hurl_core::parser;
 use std::collections::HashMap;
 use std::path::PathBuf;
+use std::time::Duration;
 
 pub fn log_verbose(message: &str) {
     eprintln!("* {}", message);
@@ -56,6 +57,8 @@ fn test_hurl_file() {
         to_entry: None,
         context_dir: PathBuf::new(),
         ignore_asserts: false,
+        retry: 0,
+        retry_interval: Duration::from_millis(1000),
         pre_entry: |_| true,
         post_entry: || true,
     };
@@ -186,6 +189,8 @@ fn test_hello() {
         to_entry: None,
         context_dir: PathBuf::new(),
         ignore_asserts: false,
+        retry: 0,
+        retry_interval: Duration::from_millis(1000),
         pre_entry: |_| true,
         post_entry: || true,
     };
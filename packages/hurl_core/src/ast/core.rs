@@ -89,6 +89,15 @@ impl Request {
         }
         None
     }
+
+    pub fn options(self) -> Vec<EntryOption> {
+        for section in self.sections {
+            if let SectionValue::Options(options) = section.value {
+                return options;
+            }
+        }
+        vec![]
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -207,6 +216,7 @@ impl Section {
             SectionValue::Cookies(_) => "Cookies",
             SectionValue::Captures(_) => "Captures",
             SectionValue::MultipartFormData(_) => "MultipartFormData",
+            SectionValue::Options(_) => "Options",
         }
     }
 }
@@ -221,6 +231,7 @@ pub enum SectionValue {
     Cookies(Vec<Cookie>),
     Captures(Vec<Capture>),
     Asserts(Vec<Assert>),
+    Options(Vec<EntryOption>),
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -251,6 +262,7 @@ pub struct KeyValue {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[allow(clippy::large_enum_variant)]
 pub enum MultipartParam {
     Param(KeyValue),
     FileParam(FileParam),
@@ -270,12 +282,86 @@ pub struct FileParam {
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct FileValue {
     pub space0: Whitespace,
-    pub filename: Filename,
+    pub filename: Template,
     pub space1: Whitespace,
     pub space2: Whitespace,
+    /// Inline content of the part, the file being read from disk otherwise
+    pub content: Option<FileContent>,
+    pub space3: Whitespace,
     pub content_type: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FileContent {
+    RawString(RawString),
+    Base64(Base64),
+    Hex(Hex),
+    Expression(Expr),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EntryOption {
+    pub line_terminators: Vec<LineTerminator>,
+    pub space0: Whitespace,
+    pub space1: Whitespace,
+    pub space2: Whitespace,
+    pub kind: OptionKind,
+    pub line_terminator0: LineTerminator,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum OptionKind {
+    CaCertificate(Filename),
+    ClientCertificate(Filename),
+    ClientKey(Filename),
+    Compressed(bool),
+    ConnectTimeout(u64),
+    FollowLocation(bool),
+    Http10(bool),
+    Http11(bool),
+    Http2(bool),
+    Http2PriorKnowledge(bool),
+    IgnoreJsonKey(Template),
+    Insecure(bool),
+    KeyPassword(Template),
+    MaxRedirect(u64),
+    NoProxy(Template),
+    Output(Filename),
+    Proxy(Template),
+    Retry(u64),
+    RetryInterval(u64),
+    Timeout(u64),
+    UserAgent(Template),
+}
+
+impl OptionKind {
+    pub fn name(&self) -> &str {
+        match self {
+            OptionKind::CaCertificate(_) => "cacert",
+            OptionKind::ClientCertificate(_) => "cert",
+            OptionKind::ClientKey(_) => "key",
+            OptionKind::Compressed(_) => "compressed",
+            OptionKind::ConnectTimeout(_) => "connect-timeout",
+            OptionKind::FollowLocation(_) => "location",
+            OptionKind::Http10(_) => "http1.0",
+            OptionKind::Http11(_) => "http1.1",
+            OptionKind::Http2(_) => "http2",
+            OptionKind::Http2PriorKnowledge(_) => "http2-prior-knowledge",
+            OptionKind::IgnoreJsonKey(_) => "ignore-json-key",
+            OptionKind::Insecure(_) => "insecure",
+            OptionKind::KeyPassword(_) => "key-password",
+            OptionKind::MaxRedirect(_) => "max-redirs",
+            OptionKind::NoProxy(_) => "noproxy",
+            OptionKind::Output(_) => "output",
+            OptionKind::Proxy(_) => "proxy",
+            OptionKind::Retry(_) => "retry",
+            OptionKind::RetryInterval(_) => "retry-interval",
+            OptionKind::Timeout(_) => "max-time",
+            OptionKind::UserAgent(_) => "user-agent",
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Capture {
     pub line_terminators: Vec<LineTerminator>,
@@ -301,13 +387,16 @@ pub struct Assert {
 pub struct Query {
     pub source_info: SourceInfo,
     pub value: QueryValue,
-    pub subquery: Option<(Whitespace, Subquery)>,
+    pub filters: Vec<(Whitespace, Filter)>,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 #[allow(clippy::large_enum_variant)]
 pub enum QueryValue {
     Status {},
+    Url {},
+    Ip {},
+    Redirects {},
     Header {
         space0: Whitespace,
         name: Template,
@@ -334,11 +423,38 @@ pub enum QueryValue {
         name: Template,
     },
     Duration {},
+    Timing {
+        space0: Whitespace,
+        name: TimingName,
+    },
+    Certificate {
+        space0: Whitespace,
+        attribute_name: CertificateAttributeName,
+    },
     Bytes {},
     Sha256 {},
     Md5 {},
 }
 
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TimingName {
+    NameLookup,
+    Connect,
+    AppConnect,
+    PreTransfer,
+    StartTransfer,
+    Total,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CertificateAttributeName {
+    Subject,
+    Issuer,
+    StartDate,
+    ExpireDate,
+    SerialNumber,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct CookiePath {
     pub name: Template,
@@ -380,15 +496,49 @@ impl CookieAttributeName {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
-pub struct Subquery {
+pub struct Filter {
     pub source_info: SourceInfo,
-    pub value: SubqueryValue,
+    pub value: FilterValue,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
-pub enum SubqueryValue {
-    Regex { space0: Whitespace, expr: Template },
+pub enum FilterValue {
+    Base64Decode {},
     Count {},
+    DaysAfterNow {},
+    DaysBeforeNow {},
+    Format {
+        space0: Whitespace,
+        fmt: Template,
+    },
+    HtmlEscape {},
+    HtmlUnescape {},
+    Nth {
+        space0: Whitespace,
+        n: u64,
+    },
+    Regex {
+        space0: Whitespace,
+        expr: Template,
+    },
+    Replace {
+        space0: Whitespace,
+        old_value: Template,
+        space1: Whitespace,
+        new_value: Template,
+    },
+    Split {
+        space0: Whitespace,
+        sep: Template,
+    },
+    ToDate {
+        space0: Whitespace,
+        fmt: Template,
+    },
+    ToFloat {},
+    ToInt {},
+    UrlDecode {},
+    UrlEncode {},
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -482,11 +632,32 @@ pub enum PredicateFuncValue {
         space0: Whitespace,
         value: PredicateValue,
     },
+    IncludeJson {
+        space0: Whitespace,
+        value: json::Value,
+    },
+    MatchJson {
+        space0: Whitespace,
+        value: json::Value,
+    },
+    JsonSchema {
+        space0: Whitespace,
+        value: File,
+    },
+    XmlSchema {
+        space0: Whitespace,
+        value: File,
+    },
     IsInteger {},
     IsFloat {},
     IsBoolean {},
     IsString {},
     IsCollection {},
+    IsEmpty {},
+    IsDate {},
+    IsIsoDate {},
+    IsNumber {},
+    IsList {},
     Exist {},
 }
 
@@ -515,6 +686,14 @@ pub struct File {
     pub space1: Whitespace,
 }
 
+/// A file whose content is rendered as a template before being sent.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TemplateFile {
+    pub space0: Whitespace,
+    pub filename: Filename,
+    pub space1: Whitespace,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Template {
     pub quotes: bool,
@@ -591,6 +770,7 @@ pub enum Bytes {
     Base64(Base64),
     File(File),
     Hex(Hex),
+    TemplateFile(TemplateFile),
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -649,12 +829,44 @@ impl SourceInfo {
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Expr {
     pub space0: Whitespace,
-    pub variable: Variable,
+    pub kind: ExprKind,
     pub space1: Whitespace,
 }
 
+impl Expr {
+    pub fn source_info(&self) -> SourceInfo {
+        match &self.kind {
+            ExprKind::Variable(variable) => variable.source_info.clone(),
+            ExprKind::Function(function) => function.source_info.clone(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ExprKind {
+    Variable(Variable),
+    Function(Function),
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Variable {
     pub name: String,
     pub source_info: SourceInfo,
 }
+
+///
+/// Generator functions, evaluated each time the template is rendered
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Function {
+    pub source_info: SourceInfo,
+    pub value: FunctionValue,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FunctionValue {
+    Env { space0: Whitespace, name: String },
+    NewDate {},
+    NewUuid {},
+    RandomInt {},
+}
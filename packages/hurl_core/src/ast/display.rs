@@ -96,7 +96,33 @@ impl fmt::Display for Float {
 
 impl fmt::Display for Expr {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.variable.name)
+        write!(f, "{}", self.kind)
+    }
+}
+
+impl fmt::Display for ExprKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ExprKind::Variable(variable) => write!(f, "{}", variable.name),
+            ExprKind::Function(function) => write!(f, "{}", function.value),
+        }
+    }
+}
+
+impl fmt::Display for FunctionValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FunctionValue::Env { space0, name } => {
+                write!(
+                    f,
+                    "{}{}{}",
+                    self.name(),
+                    space0.value,
+                    encode_env_name(name)
+                )
+            }
+            _ => write!(f, "{}", self.name()),
+        }
     }
 }
 
@@ -137,12 +163,107 @@ impl fmt::Display for Hex {
     }
 }
 
+impl fmt::Display for OptionKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let value = match self {
+            OptionKind::CaCertificate(filename) => filename.value.clone(),
+            OptionKind::ClientCertificate(filename) => filename.value.clone(),
+            OptionKind::ClientKey(filename) => filename.value.clone(),
+            OptionKind::Compressed(value) => value.to_string(),
+            OptionKind::ConnectTimeout(value) => value.to_string(),
+            OptionKind::FollowLocation(value) => value.to_string(),
+            OptionKind::Http10(value) => value.to_string(),
+            OptionKind::Http11(value) => value.to_string(),
+            OptionKind::Http2(value) => value.to_string(),
+            OptionKind::Http2PriorKnowledge(value) => value.to_string(),
+            OptionKind::IgnoreJsonKey(value) => value.to_string(),
+            OptionKind::Insecure(value) => value.to_string(),
+            OptionKind::KeyPassword(value) => value.to_string(),
+            OptionKind::MaxRedirect(value) => value.to_string(),
+            OptionKind::NoProxy(value) => value.to_string(),
+            OptionKind::Output(filename) => filename.value.clone(),
+            OptionKind::Proxy(value) => value.to_string(),
+            OptionKind::Retry(value) => value.to_string(),
+            OptionKind::RetryInterval(value) => value.to_string(),
+            OptionKind::Timeout(value) => value.to_string(),
+            OptionKind::UserAgent(value) => value.to_string(),
+        };
+        write!(f, "{}", value)
+    }
+}
+
+impl fmt::Display for TimingName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            TimingName::NameLookup => "namelookup",
+            TimingName::Connect => "connect",
+            TimingName::AppConnect => "appconnect",
+            TimingName::PreTransfer => "pretransfer",
+            TimingName::StartTransfer => "starttransfer",
+            TimingName::Total => "total",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+impl fmt::Display for CertificateAttributeName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            CertificateAttributeName::Subject => "Subject",
+            CertificateAttributeName::Issuer => "Issuer",
+            CertificateAttributeName::StartDate => "Start-Date",
+            CertificateAttributeName::ExpireDate => "Expire-Date",
+            CertificateAttributeName::SerialNumber => "Serial-Number",
+        };
+        write!(f, "{}", s)
+    }
+}
+
 impl fmt::Display for Regex {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", self.inner)
     }
 }
 
+impl FilterValue {
+    pub fn name(&self) -> &str {
+        match self {
+            FilterValue::Base64Decode {} => "base64Decode",
+            FilterValue::Count {} => "count",
+            FilterValue::DaysAfterNow {} => "daysAfterNow",
+            FilterValue::DaysBeforeNow {} => "daysBeforeNow",
+            FilterValue::Format { .. } => "format",
+            FilterValue::HtmlEscape {} => "htmlEscape",
+            FilterValue::HtmlUnescape {} => "htmlUnescape",
+            FilterValue::Nth { .. } => "nth",
+            FilterValue::Regex { .. } => "regex",
+            FilterValue::Replace { .. } => "replace",
+            FilterValue::Split { .. } => "split",
+            FilterValue::ToDate { .. } => "toDate",
+            FilterValue::ToFloat {} => "toFloat",
+            FilterValue::ToInt {} => "toInt",
+            FilterValue::UrlDecode {} => "urlDecode",
+            FilterValue::UrlEncode {} => "urlEncode",
+        }
+    }
+}
+
+/// Returns the quoted name of an environment variable, with its quotes and backslashes escaped.
+pub fn encode_env_name(name: &str) -> String {
+    format!("\"{}\"", name.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+impl FunctionValue {
+    pub fn name(&self) -> &str {
+        match self {
+            FunctionValue::Env { .. } => "env",
+            FunctionValue::NewDate {} => "newDate",
+            FunctionValue::NewUuid {} => "newUuid",
+            FunctionValue::RandomInt {} => "randomInt",
+        }
+    }
+}
+
 impl PredicateFuncValue {
     pub fn name(&self) -> String {
         match self {
@@ -194,11 +315,20 @@ impl PredicateFuncValue {
             PredicateFuncValue::Contain { .. } => "contains".to_string(),
             PredicateFuncValue::Include { .. } => "includes".to_string(),
             PredicateFuncValue::Match { .. } => "matches".to_string(),
+            PredicateFuncValue::IncludeJson { .. } => "includesJson".to_string(),
+            PredicateFuncValue::MatchJson { .. } => "matchesJson".to_string(),
+            PredicateFuncValue::JsonSchema { .. } => "jsonschema".to_string(),
+            PredicateFuncValue::XmlSchema { .. } => "xmlschema".to_string(),
             PredicateFuncValue::IsInteger { .. } => "isInteger".to_string(),
             PredicateFuncValue::IsFloat { .. } => "isFloat".to_string(),
             PredicateFuncValue::IsBoolean { .. } => "isBoolean".to_string(),
             PredicateFuncValue::IsString { .. } => "isString".to_string(),
             PredicateFuncValue::IsCollection { .. } => "isCollection".to_string(),
+            PredicateFuncValue::IsEmpty { .. } => "isEmpty".to_string(),
+            PredicateFuncValue::IsDate { .. } => "isDate".to_string(),
+            PredicateFuncValue::IsIsoDate { .. } => "isIsoDate".to_string(),
+            PredicateFuncValue::IsNumber { .. } => "isNumber".to_string(),
+            PredicateFuncValue::IsList { .. } => "isList".to_string(),
             PredicateFuncValue::Exist { .. } => "exists".to_string(),
         }
     }
@@ -218,10 +348,10 @@ mod tests {
     fn variable_expr() -> Expr {
         Expr {
             space0: whitespace(),
-            variable: Variable {
+            kind: ExprKind::Variable(Variable {
                 name: "name".to_string(),
                 source_info: SourceInfo::init(0, 0, 0, 0),
-            },
+            }),
             space1: whitespace(),
         }
     }
@@ -280,6 +410,12 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_encode_env_name() {
+        assert_eq!(encode_env_name("HOME"), r#""HOME""#);
+        assert_eq!(encode_env_name(r#"A"B\C"#), r#""A\"B\\C""#);
+    }
+
     #[test]
     fn test_template() {
         assert_eq!(hello_template().to_string(), "Hello {{name}}!");
@@ -215,7 +215,7 @@ impl TemplateElement {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::ast::{SourceInfo, TemplateElement, Variable, Whitespace};
+    use crate::ast::{ExprKind, SourceInfo, TemplateElement, Variable, Whitespace};
 
     #[test]
     fn test_to_string() {
@@ -226,10 +226,10 @@ mod tests {
                     value: "".to_string(),
                     source_info: SourceInfo::init(0, 0, 0, 0),
                 },
-                variable: Variable {
+                kind: ExprKind::Variable(Variable {
                     name: "x".to_string(),
                     source_info: SourceInfo::init(0, 0, 0, 0),
-                },
+                }),
                 space1: Whitespace {
                     value: "".to_string(),
                     source_info: SourceInfo::init(0, 0, 0, 0),
@@ -327,10 +327,10 @@ mod tests {
                     value: "".to_string(),
                     source_info: SourceInfo::init(1, 1, 1, 1),
                 },
-                variable: Variable {
+                kind: ExprKind::Variable(Variable {
                     name: "name".to_string(),
                     source_info: SourceInfo::init(1, 1, 1, 1),
-                },
+                }),
                 space1: Whitespace {
                     value: "".to_string(),
                     source_info: SourceInfo::init(1, 1, 1, 1),
@@ -347,10 +347,10 @@ mod tests {
                         value: "".to_string(),
                         source_info: SourceInfo::init(1, 1, 1, 1),
                     },
-                    variable: Variable {
+                    kind: ExprKind::Variable(Variable {
                         name: "name".to_string(),
                         source_info: SourceInfo::init(1, 1, 1, 1),
-                    },
+                    }),
                     space1: Whitespace {
                         value: "".to_string(),
                         source_info: SourceInfo::init(1, 1, 1, 1),
@@ -17,6 +17,7 @@
  */
 
 pub use self::core::*;
+pub use self::display::encode_env_name;
 pub use self::json::ListElement as JsonListElement;
 pub use self::json::ObjectElement as JsonObjectElement;
 pub use self::json::Value as JsonValue;
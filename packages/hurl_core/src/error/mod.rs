@@ -56,6 +56,7 @@ impl Error for parser::Error {
             ParseError::ResponseSection { .. } => "Parsing section".to_string(),
             ParseError::EscapeChar { .. } => "Parsing escape character".to_string(),
             ParseError::InvalidCookieAttribute { .. } => "Parsing cookie attribute".to_string(),
+            ParseError::InvalidOption { .. } => "Parsing option".to_string(),
             ParseError::OddNumberOfHexDigits { .. } => "Parsing hex bytearray".to_string(),
             ParseError::UrlIllegalCharacter(_) => "Parsing url".to_string(),
             _ => format!("{:?}", self),
@@ -77,9 +78,9 @@ impl Error for parser::Error {
             ParseError::Space { .. } => "expecting a space".to_string(),
             ParseError::RequestSectionName { name }
             => format!("the section is not valid. {}", did_you_mean(
-                &["QueryStringParams", "FormParams", "MultipartFormData", "Cookies"],
+                &["QueryStringParams", "FormParams", "MultipartFormData", "Cookies", "Options"],
                 name.as_str(),
-                "Valid values are QueryStringParams, FormParams, MultipartFormData or Cookies",
+                "Valid values are QueryStringParams, FormParams, MultipartFormData, Cookies or Options",
             )),
             ParseError::ResponseSectionName { name }
             => format!("the section is not valid. {}", did_you_mean(
@@ -105,6 +106,12 @@ impl Error for parser::Error {
             ParseError::InvalidCookieAttribute { .. } => {
                 "The cookie attribute is not valid".to_string()
             }
+            ParseError::InvalidOption { name }
+            => format!("the option is not valid. {}", did_you_mean(
+                &["cacert", "cert", "compressed", "connect-timeout", "http1.0", "http1.1", "http2", "http2-prior-knowledge", "ignore-json-key", "insecure", "key", "key-password", "location", "max-redirs", "max-time", "noproxy", "output", "proxy", "retry", "retry-interval", "user-agent"],
+                name.as_str(),
+                "Valid values are cacert, cert, compressed, connect-timeout, http1.0, http1.1, http2, http2-prior-knowledge, ignore-json-key, insecure, key, key-password, location, max-redirs, max-time, noproxy, output, proxy, retry, retry-interval or user-agent",
+            )),
             ParseError::OddNumberOfHexDigits { .. } => {
                 "Expecting an even number of hex digits".to_string()
             }
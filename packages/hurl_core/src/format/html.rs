@@ -204,6 +204,11 @@ impl Htmlable for SectionValue {
                     buffer.push_str(item.to_html().as_str())
                 }
             }
+            SectionValue::Options(items) => {
+                for item in items {
+                    buffer.push_str(item.to_html().as_str())
+                }
+            }
         }
         buffer
     }
@@ -258,11 +263,19 @@ impl Htmlable for FileValue {
         buffer.push_str("file,");
         buffer.push_str(self.space0.to_html().as_str());
         buffer.push_str(
-            format!("<span class=\"string\">{}</span>", self.filename.to_html()).as_str(),
+            format!(
+                "<span class=\"string\"><span class=\"filename\">{}</span></span>",
+                self.filename.to_html()
+            )
+            .as_str(),
         );
         buffer.push_str(self.space1.to_html().as_str());
         buffer.push(';');
         buffer.push_str(self.space2.to_html().as_str());
+        if let Some(content) = self.content.clone() {
+            buffer.push_str(content.to_html().as_str());
+        }
+        buffer.push_str(self.space3.to_html().as_str());
         if let Some(content_type) = self.content_type.clone() {
             buffer.push_str(format!("<span class=\"string\">{}</span>", content_type).as_str());
         }
@@ -270,6 +283,17 @@ impl Htmlable for FileValue {
     }
 }
 
+impl Htmlable for FileContent {
+    fn to_html(&self) -> String {
+        match self {
+            FileContent::RawString(value) => value.to_html(),
+            FileContent::Base64(value) => value.to_html(),
+            FileContent::Hex(value) => value.to_html(),
+            FileContent::Expression(value) => value.to_html(),
+        }
+    }
+}
+
 impl Htmlable for Filename {
     fn to_html(&self) -> String {
         let mut buffer = String::from("<span class=\"filename\">");
@@ -307,6 +331,69 @@ impl Htmlable for CookieValue {
     }
 }
 
+impl Htmlable for EntryOption {
+    fn to_html(&self) -> String {
+        let mut buffer = String::from("");
+        add_line_terminators(&mut buffer, self.line_terminators.clone());
+        buffer.push_str("<span class=\"line\">");
+        buffer.push_str(self.space0.to_html().as_str());
+        buffer.push_str(format!("<span class=\"string\">{}</span>", self.kind.name()).as_str());
+        buffer.push_str(self.space1.to_html().as_str());
+        buffer.push_str("<span>:</span>");
+        buffer.push_str(self.space2.to_html().as_str());
+        buffer.push_str(self.kind.to_html().as_str());
+        buffer.push_str("</span>");
+        buffer.push_str(self.line_terminator0.to_html().as_str());
+        buffer
+    }
+}
+
+impl Htmlable for OptionKind {
+    fn to_html(&self) -> String {
+        match self {
+            OptionKind::CaCertificate(filename) => filename.to_html(),
+            OptionKind::ClientCertificate(filename) => filename.to_html(),
+            OptionKind::ClientKey(filename) => filename.to_html(),
+            OptionKind::Compressed(value) => format!("<span class=\"boolean\">{}</span>", value),
+            OptionKind::ConnectTimeout(value) => {
+                format!("<span class=\"number\">{}</span>", value)
+            }
+            OptionKind::FollowLocation(value) => {
+                format!("<span class=\"boolean\">{}</span>", value)
+            }
+            OptionKind::Http10(value) => format!("<span class=\"boolean\">{}</span>", value),
+            OptionKind::Http11(value) => format!("<span class=\"boolean\">{}</span>", value),
+            OptionKind::Http2(value) => format!("<span class=\"boolean\">{}</span>", value),
+            OptionKind::Http2PriorKnowledge(value) => {
+                format!("<span class=\"boolean\">{}</span>", value)
+            }
+            OptionKind::IgnoreJsonKey(value) => {
+                format!("<span class=\"string\">{}</span>", value.to_html())
+            }
+            OptionKind::Insecure(value) => format!("<span class=\"boolean\">{}</span>", value),
+            OptionKind::KeyPassword(value) => {
+                format!("<span class=\"string\">{}</span>", value.to_html())
+            }
+            OptionKind::MaxRedirect(value) => format!("<span class=\"number\">{}</span>", value),
+            OptionKind::NoProxy(value) => {
+                format!("<span class=\"string\">{}</span>", value.to_html())
+            }
+            OptionKind::Output(filename) => filename.to_html(),
+            OptionKind::Proxy(value) => {
+                format!("<span class=\"string\">{}</span>", value.to_html())
+            }
+            OptionKind::Retry(value) => format!("<span class=\"number\">{}</span>", value),
+            OptionKind::RetryInterval(value) => {
+                format!("<span class=\"number\">{}</span>", value)
+            }
+            OptionKind::Timeout(value) => format!("<span class=\"number\">{}</span>", value),
+            OptionKind::UserAgent(value) => {
+                format!("<span class=\"string\">{}</span>", value.to_html())
+            }
+        }
+    }
+}
+
 impl Htmlable for Capture {
     fn to_html(&self) -> String {
         let mut buffer = String::from("");
@@ -328,9 +415,9 @@ impl Htmlable for Capture {
 impl Htmlable for Query {
     fn to_html(&self) -> String {
         let mut buffer = self.value.clone().to_html();
-        if let Some((space, subquery)) = self.clone().subquery {
+        for (space, filter) in self.filters.iter() {
             buffer.push_str(space.to_html().as_str());
-            buffer.push_str(subquery.to_html().as_str());
+            buffer.push_str(filter.to_html().as_str());
         }
         buffer
     }
@@ -343,6 +430,15 @@ impl Htmlable for QueryValue {
             QueryValue::Status {} => {
                 buffer.push_str("<span class=\"query-type\">status</span>");
             }
+            QueryValue::Url {} => {
+                buffer.push_str("<span class=\"query-type\">url</span>");
+            }
+            QueryValue::Ip {} => {
+                buffer.push_str("<span class=\"query-type\">ip</span>");
+            }
+            QueryValue::Redirects {} => {
+                buffer.push_str("<span class=\"query-type\">redirects</span>");
+            }
             QueryValue::Header { space0, name } => {
                 buffer.push_str("<span class=\"query-type\">header</span>");
                 buffer.push_str(space0.to_html().as_str());
@@ -391,6 +487,21 @@ impl Htmlable for QueryValue {
             QueryValue::Duration {} => {
                 buffer.push_str("<span class=\"query-type\">duration</span>");
             }
+            QueryValue::Timing { space0, name } => {
+                buffer.push_str("<span class=\"query-type\">timing</span>");
+                buffer.push_str(space0.to_html().as_str());
+                buffer.push_str(format!("<span class=\"string\">{}</span>", name).as_str());
+            }
+            QueryValue::Certificate {
+                space0,
+                attribute_name,
+            } => {
+                buffer.push_str("<span class=\"query-type\">certificate</span>");
+                buffer.push_str(space0.to_html().as_str());
+                buffer.push_str(
+                    format!("<span class=\"string\">\"{}\"</span>", attribute_name).as_str(),
+                );
+            }
             QueryValue::Bytes {} => {
                 buffer.push_str("<span class=\"query-type\">bytes</span>");
             }
@@ -405,20 +516,56 @@ impl Htmlable for QueryValue {
     }
 }
 
-impl Htmlable for Subquery {
+impl Htmlable for Filter {
     fn to_html(&self) -> String {
         let mut buffer = String::from("");
+        buffer
+            .push_str(format!("<span class=\"filter-type\">{}</span>", self.value.name()).as_str());
         match self.value.clone() {
-            SubqueryValue::Regex { expr, space0 } => {
-                buffer.push_str("<span class=\"subquery-type\">regex</span>");
+            FilterValue::Format { space0, fmt } => {
+                buffer.push_str(space0.to_html().as_str());
+                buffer.push_str(
+                    format!("<span class=\"string\">\"{}\"</span>", fmt.to_html()).as_str(),
+                );
+            }
+            FilterValue::Nth { space0, n } => {
+                buffer.push_str(space0.to_html().as_str());
+                buffer.push_str(format!("<span class=\"number\">{}</span>", n).as_str());
+            }
+            FilterValue::Regex { space0, expr } => {
                 buffer.push_str(space0.to_html().as_str());
                 buffer.push_str(
                     format!("<span class=\"string\">\"{}\"</span>", expr.to_html()).as_str(),
                 );
             }
-            SubqueryValue::Count {} => {
-                buffer.push_str("<span class=\"subquery-type\">count</span>")
+            FilterValue::Replace {
+                space0,
+                old_value,
+                space1,
+                new_value,
+            } => {
+                buffer.push_str(space0.to_html().as_str());
+                buffer.push_str(
+                    format!("<span class=\"string\">\"{}\"</span>", old_value.to_html()).as_str(),
+                );
+                buffer.push_str(space1.to_html().as_str());
+                buffer.push_str(
+                    format!("<span class=\"string\">\"{}\"</span>", new_value.to_html()).as_str(),
+                );
+            }
+            FilterValue::Split { space0, sep } => {
+                buffer.push_str(space0.to_html().as_str());
+                buffer.push_str(
+                    format!("<span class=\"string\">\"{}\"</span>", sep.to_html()).as_str(),
+                );
+            }
+            FilterValue::ToDate { space0, fmt } => {
+                buffer.push_str(space0.to_html().as_str());
+                buffer.push_str(
+                    format!("<span class=\"string\">\"{}\"</span>", fmt.to_html()).as_str(),
+                );
             }
+            _ => {}
         }
         buffer
     }
@@ -578,6 +725,30 @@ impl Htmlable for PredicateFuncValue {
                 buffer.push_str(value.to_html().as_str());
             }
 
+            PredicateFuncValue::IncludeJson { space0, value }
+            | PredicateFuncValue::MatchJson { space0, value } => {
+                buffer.push_str(
+                    format!("<span class=\"predicate-type\">{}</span>", self.name()).as_str(),
+                );
+                buffer.push_str(space0.to_html().as_str());
+                buffer.push_str(
+                    format!(
+                        "<span class=\"json\">{}</span>",
+                        xml_escape(value.encoded())
+                    )
+                    .as_str(),
+                );
+            }
+
+            PredicateFuncValue::JsonSchema { space0, value }
+            | PredicateFuncValue::XmlSchema { space0, value } => {
+                buffer.push_str(
+                    format!("<span class=\"predicate-type\">{}</span>", self.name()).as_str(),
+                );
+                buffer.push_str(space0.to_html().as_str());
+                buffer.push_str(value.to_html().as_str());
+            }
+
             PredicateFuncValue::Match { space0, value } => {
                 buffer.push_str(
                     format!("<span class=\"predicate-type\">{}</span>", self.name()).as_str(),
@@ -610,6 +781,31 @@ impl Htmlable for PredicateFuncValue {
                     format!("<span class=\"predicate-type\">{}</span>", self.name()).as_str(),
                 );
             }
+            PredicateFuncValue::IsEmpty {} => {
+                buffer.push_str(
+                    format!("<span class=\"predicate-type\">{}</span>", self.name()).as_str(),
+                );
+            }
+            PredicateFuncValue::IsDate {} => {
+                buffer.push_str(
+                    format!("<span class=\"predicate-type\">{}</span>", self.name()).as_str(),
+                );
+            }
+            PredicateFuncValue::IsIsoDate {} => {
+                buffer.push_str(
+                    format!("<span class=\"predicate-type\">{}</span>", self.name()).as_str(),
+                );
+            }
+            PredicateFuncValue::IsNumber {} => {
+                buffer.push_str(
+                    format!("<span class=\"predicate-type\">{}</span>", self.name()).as_str(),
+                );
+            }
+            PredicateFuncValue::IsList {} => {
+                buffer.push_str(
+                    format!("<span class=\"predicate-type\">{}</span>", self.name()).as_str(),
+                );
+            }
             PredicateFuncValue::Exist {} => {
                 buffer.push_str(
                     format!("<span class=\"predicate-type\">{}</span>", self.name()).as_str(),
@@ -703,6 +899,9 @@ impl Htmlable for Bytes {
             Bytes::Hex(value) => format!("<span class=\"line\">{}</span>", value.to_html()),
             Bytes::Json { value } => value.to_html(),
             Bytes::RawString(value) => value.to_html(),
+            Bytes::TemplateFile(value) => {
+                format!("<span class=\"line\">{}</span>", value.to_html())
+            }
             Bytes::Xml { value } => xml_html(value),
         }
     }
@@ -775,6 +974,17 @@ impl Htmlable for File {
     }
 }
 
+impl Htmlable for TemplateFile {
+    fn to_html(&self) -> String {
+        let mut buffer = String::from("template,");
+        buffer.push_str(self.space0.to_html().as_str());
+        buffer.push_str(self.filename.to_html().as_str());
+        buffer.push_str(self.space1.to_html().as_str());
+        buffer.push(';');
+        buffer
+    }
+}
+
 impl Htmlable for Base64 {
     fn to_html(&self) -> String {
         let mut buffer = String::from("base64,");
@@ -33,6 +33,7 @@ pub fn bytes(reader: &mut Reader) -> ParseResult<'static, Bytes> {
             xml_bytes,
             base64_bytes,
             hex_bytes,
+            template_file_bytes,
             file_bytes,
         ],
         reader,
@@ -60,6 +61,10 @@ fn file_bytes(reader: &mut Reader) -> ParseResult<'static, Bytes> {
     file(reader).map(Bytes::File)
 }
 
+fn template_file_bytes(reader: &mut Reader) -> ParseResult<'static, Bytes> {
+    template_file(reader).map(Bytes::TemplateFile)
+}
+
 fn base64_bytes(reader: &mut Reader) -> ParseResult<'static, Bytes> {
     base64(reader).map(Bytes::Base64)
 }
@@ -149,10 +149,10 @@ mod tests {
                             value: String::from(""),
                             source_info: SourceInfo::init(1, 3, 1, 3),
                         },
-                        variable: Variable {
+                        kind: ExprKind::Variable(Variable {
                             name: "name".to_string(),
                             source_info: SourceInfo::init(1, 3, 1, 7),
-                        },
+                        }),
                         space1: Whitespace {
                             value: String::from(""),
                             source_info: SourceInfo::init(1, 7, 1, 7),
@@ -58,6 +58,7 @@ pub enum ParseError {
     EscapeChar,
 
     InvalidCookieAttribute,
+    InvalidOption { name: String },
     OddNumberOfHexDigits,
     UrlIllegalCharacter(char),
 }
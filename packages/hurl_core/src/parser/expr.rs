@@ -27,7 +27,7 @@ pub fn parse(reader: &mut Reader) -> ParseResult<'static, Expr> {
 
     try_literal("{{", reader)?;
     let space0 = zero_or_more_spaces(reader)?;
-    let variable = variable_name(reader)?;
+    let kind = expr_kind(reader)?;
     let space1 = zero_or_more_spaces(reader)?;
 
     //literal("}}", p)?;
@@ -37,7 +37,7 @@ pub fn parse(reader: &mut Reader) -> ParseResult<'static, Expr> {
 
     Ok(Expr {
         space0,
-        variable,
+        kind,
         space1,
     })
 }
@@ -46,16 +46,80 @@ pub fn parse2(reader: &mut Reader) -> ParseResult<'static, Expr> {
     // let start = p.state.clone();
 
     let space0 = zero_or_more_spaces(reader)?;
-    let variable = variable_name(reader)?;
+    let kind = expr_kind(reader)?;
     let space1 = zero_or_more_spaces(reader)?;
 
     Ok(Expr {
         space0,
-        variable,
+        kind,
         space1,
     })
 }
 
+fn expr_kind(reader: &mut Reader) -> ParseResult<'static, ExprKind> {
+    let start = reader.state.clone();
+    let variable = variable_name(reader)?;
+    let value = match variable.name.as_str() {
+        "newDate" => FunctionValue::NewDate {},
+        "newUuid" => FunctionValue::NewUuid {},
+        "randomInt" => FunctionValue::RandomInt {},
+        // env is only a function when followed by the quoted name
+        // of an environment variable, {{env}} being still a variable.
+        "env" => {
+            let save = reader.state.clone();
+            match env_name(reader) {
+                Ok((space0, name)) => FunctionValue::Env { space0, name },
+                Err(_) => {
+                    reader.state = save;
+                    return Ok(ExprKind::Variable(variable));
+                }
+            }
+        }
+        _ => return Ok(ExprKind::Variable(variable)),
+    };
+    Ok(ExprKind::Function(Function {
+        source_info: SourceInfo {
+            start: start.pos,
+            end: reader.state.pos.clone(),
+        },
+        value,
+    }))
+}
+
+fn env_name(reader: &mut Reader) -> ParseResult<'static, (Whitespace, String)> {
+    let space0 = one_or_more_spaces(reader)?;
+    literal("\"", reader)?;
+    // the name can contain escaped quotes and backslashes
+    let mut name = String::new();
+    loop {
+        let pos = reader.state.pos.clone();
+        match reader.read() {
+            Some('"') => break,
+            Some('\\') => match reader.read() {
+                Some(c) if c == '"' || c == '\\' => name.push(c),
+                _ => {
+                    return Err(Error {
+                        pos,
+                        recoverable: false,
+                        inner: ParseError::EscapeChar {},
+                    })
+                }
+            },
+            Some(c) => name.push(c),
+            None => {
+                return Err(Error {
+                    pos,
+                    recoverable: false,
+                    inner: ParseError::Expecting {
+                        value: "\"".to_string(),
+                    },
+                })
+            }
+        }
+    }
+    Ok((space0, name))
+}
+
 fn variable_name(reader: &mut Reader) -> ParseResult<'static, Variable> {
     let start = reader.state.clone();
     let name = reader.read_while(|c| c.is_alphanumeric() || *c == '_' || *c == '-');
@@ -93,10 +157,10 @@ mod tests {
                     value: String::from(" "),
                     source_info: SourceInfo::init(1, 3, 1, 4),
                 },
-                variable: Variable {
+                kind: ExprKind::Variable(Variable {
                     name: String::from("name"),
                     source_info: SourceInfo::init(1, 4, 1, 8),
-                },
+                }),
                 space1: Whitespace {
                     value: String::from(""),
                     source_info: SourceInfo::init(1, 8, 1, 8),
@@ -105,6 +169,57 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_expr_function() {
+        let mut reader = Reader::init("{{newUuid}}");
+        assert_eq!(
+            parse(&mut reader).unwrap().kind,
+            ExprKind::Function(Function {
+                source_info: SourceInfo::init(1, 3, 1, 10),
+                value: FunctionValue::NewUuid {},
+            })
+        );
+
+        let mut reader = Reader::init("{{ env \"HOME\" }}");
+        assert_eq!(
+            parse(&mut reader).unwrap().kind,
+            ExprKind::Function(Function {
+                source_info: SourceInfo::init(1, 4, 1, 14),
+                value: FunctionValue::Env {
+                    space0: Whitespace {
+                        value: String::from(" "),
+                        source_info: SourceInfo::init(1, 7, 1, 8),
+                    },
+                    name: String::from("HOME"),
+                },
+            })
+        );
+
+        let mut reader = Reader::init(r#"{{env "A\"B\\C"}}"#);
+        assert_eq!(
+            parse(&mut reader).unwrap().kind,
+            ExprKind::Function(Function {
+                source_info: SourceInfo::init(1, 3, 1, 16),
+                value: FunctionValue::Env {
+                    space0: Whitespace {
+                        value: String::from(" "),
+                        source_info: SourceInfo::init(1, 6, 1, 7),
+                    },
+                    name: String::from("A\"B\\C"),
+                },
+            })
+        );
+
+        let mut reader = Reader::init("{{env}}");
+        assert_eq!(
+            parse(&mut reader).unwrap().kind,
+            ExprKind::Variable(Variable {
+                name: String::from("env"),
+                source_info: SourceInfo::init(1, 3, 1, 6),
+            })
+        );
+    }
+
     #[test]
     fn test_expr_error() {
         let mut reader = Reader::init("{{host>}}");
@@ -18,6 +18,7 @@
 
 use super::error::*;
 use super::reader::Reader;
+use super::template;
 
 use super::ParseResult;
 use crate::ast::*;
@@ -47,6 +48,67 @@ pub fn parse(reader: &mut Reader) -> ParseResult<'static, Filename> {
     })
 }
 
+/// Parses a filename including templates, `report-{{id}}.csv` for instance.
+pub fn parse_template(reader: &mut Reader) -> ParseResult<'static, Template> {
+    let start = reader.state.clone();
+    let mut chars = vec![];
+    let mut expression = false;
+    while let Some(c) = reader.peek() {
+        let pos = reader.state.pos.clone();
+        if expression || reader.remaining().starts_with("{{") {
+            // expressions are kept verbatim until their closing brackets
+            if expression && reader.remaining().starts_with("}}") {
+                expression = false;
+                reader.read();
+                chars.push(('}', "}".to_string(), pos));
+                let pos = reader.state.pos.clone();
+                reader.read();
+                chars.push(('}', "}".to_string(), pos));
+                continue;
+            }
+            expression = true;
+            reader.read();
+            chars.push((c, c.to_string(), pos));
+        } else if c == '\\' {
+            reader.read();
+            if reader.peek() == Some(' ') {
+                reader.read();
+                chars.push((' ', "\\ ".to_string(), pos));
+            }
+        } else if c.is_alphanumeric() || c == '.' || c == '/' || c == '_' || c == '-' {
+            reader.read();
+            chars.push((c, c.to_string(), pos));
+        } else {
+            break;
+        }
+    }
+    if chars.is_empty() {
+        return Err(Error {
+            pos: start.pos,
+            recoverable: false,
+            inner: ParseError::Filename {},
+        });
+    }
+
+    let end = reader.state.pos.clone();
+    let encoded_string = template::EncodedString {
+        source_info: SourceInfo {
+            start: start.pos.clone(),
+            end: end.clone(),
+        },
+        chars,
+    };
+    let elements = template::templatize(encoded_string)?;
+    Ok(Template {
+        quotes: false,
+        elements,
+        source_info: SourceInfo {
+            start: start.pos,
+            end,
+        },
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use crate::ast::Pos;
@@ -96,4 +158,41 @@ mod tests {
         assert_eq!(error.inner, ParseError::Filename {});
         assert_eq!(error.pos, Pos { line: 1, column: 1 });
     }
+
+    #[test]
+    fn test_filename_template() {
+        let mut reader = Reader::init("report-{{ id }}.csv;");
+        let template = parse_template(&mut reader).unwrap();
+        assert_eq!(template.source_info, SourceInfo::init(1, 1, 1, 20));
+        assert_eq!(template.elements.len(), 3);
+        assert_eq!(
+            template.elements[0],
+            TemplateElement::String {
+                value: "report-".to_string(),
+                encoded: "report-".to_string(),
+            }
+        );
+        assert_eq!(
+            template.elements[2],
+            TemplateElement::String {
+                value: ".csv".to_string(),
+                encoded: ".csv".to_string(),
+            }
+        );
+        assert_eq!(reader.state.cursor, 19);
+
+        let mut reader = Reader::init("file\\ with\\ spaces");
+        let template = parse_template(&mut reader).unwrap();
+        assert_eq!(
+            template.elements,
+            vec![TemplateElement::String {
+                value: "file with spaces".to_string(),
+                encoded: "file\\ with\\ spaces".to_string(),
+            }]
+        );
+
+        let mut reader = Reader::init("???");
+        let error = parse_template(&mut reader).err().unwrap();
+        assert_eq!(error.inner, ParseError::Filename {});
+    }
 }
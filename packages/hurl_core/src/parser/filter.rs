@@ -0,0 +1,301 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2022 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use crate::ast::*;
+
+use super::combinators::*;
+use super::primitives::*;
+use super::reader::Reader;
+use super::string::*;
+use super::ParseResult;
+use crate::parser::{Error, ParseError};
+
+pub fn filter(reader: &mut Reader) -> ParseResult<'static, Filter> {
+    let start = reader.state.pos.clone();
+    let value = filter_value(reader)?;
+    let end = reader.state.pos.clone();
+    Ok(Filter {
+        source_info: SourceInfo { start, end },
+        value,
+    })
+}
+
+fn filter_value(reader: &mut Reader) -> ParseResult<'static, FilterValue> {
+    choice(
+        vec![
+            base64_decode_filter,
+            count_filter,
+            days_after_now_filter,
+            days_before_now_filter,
+            format_filter,
+            html_escape_filter,
+            html_unescape_filter,
+            nth_filter,
+            regex_filter,
+            replace_filter,
+            split_filter,
+            to_date_filter,
+            to_float_filter,
+            to_int_filter,
+            url_decode_filter,
+            url_encode_filter,
+        ],
+        reader,
+    )
+}
+
+fn base64_decode_filter(reader: &mut Reader) -> ParseResult<'static, FilterValue> {
+    try_literal("base64Decode", reader)?;
+    Ok(FilterValue::Base64Decode {})
+}
+
+fn count_filter(reader: &mut Reader) -> ParseResult<'static, FilterValue> {
+    // temporary backward compatibility for countEquals predicate
+    if reader.remaining().starts_with("countEquals") {
+        return Err(Error {
+            pos: reader.state.pos.clone(),
+            recoverable: true,
+            inner: ParseError::Unexpected {
+                character: "countEquals".to_string(),
+            },
+        });
+    }
+
+    try_literal("count", reader)?;
+    Ok(FilterValue::Count {})
+}
+
+fn days_after_now_filter(reader: &mut Reader) -> ParseResult<'static, FilterValue> {
+    try_literal("daysAfterNow", reader)?;
+    Ok(FilterValue::DaysAfterNow {})
+}
+
+fn days_before_now_filter(reader: &mut Reader) -> ParseResult<'static, FilterValue> {
+    try_literal("daysBeforeNow", reader)?;
+    Ok(FilterValue::DaysBeforeNow {})
+}
+
+fn format_filter(reader: &mut Reader) -> ParseResult<'static, FilterValue> {
+    try_literal("format", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let fmt = quoted_template(reader).map_err(|e| e.non_recoverable())?;
+    Ok(FilterValue::Format { space0, fmt })
+}
+
+fn html_escape_filter(reader: &mut Reader) -> ParseResult<'static, FilterValue> {
+    try_literal("htmlEscape", reader)?;
+    Ok(FilterValue::HtmlEscape {})
+}
+
+fn html_unescape_filter(reader: &mut Reader) -> ParseResult<'static, FilterValue> {
+    try_literal("htmlUnescape", reader)?;
+    Ok(FilterValue::HtmlUnescape {})
+}
+
+fn nth_filter(reader: &mut Reader) -> ParseResult<'static, FilterValue> {
+    try_literal("nth", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let n = nonrecover(natural, reader)?;
+    Ok(FilterValue::Nth { space0, n })
+}
+
+fn regex_filter(reader: &mut Reader) -> ParseResult<'static, FilterValue> {
+    try_literal("regex", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let expr = quoted_template(reader).map_err(|e| e.non_recoverable())?;
+    Ok(FilterValue::Regex { space0, expr })
+}
+
+fn replace_filter(reader: &mut Reader) -> ParseResult<'static, FilterValue> {
+    try_literal("replace", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let old_value = quoted_template(reader).map_err(|e| e.non_recoverable())?;
+    let space1 = one_or_more_spaces(reader)?;
+    let new_value = quoted_template(reader).map_err(|e| e.non_recoverable())?;
+    Ok(FilterValue::Replace {
+        space0,
+        old_value,
+        space1,
+        new_value,
+    })
+}
+
+fn split_filter(reader: &mut Reader) -> ParseResult<'static, FilterValue> {
+    try_literal("split", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let sep = quoted_template(reader).map_err(|e| e.non_recoverable())?;
+    Ok(FilterValue::Split { space0, sep })
+}
+
+fn to_date_filter(reader: &mut Reader) -> ParseResult<'static, FilterValue> {
+    try_literal("toDate", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let fmt = quoted_template(reader).map_err(|e| e.non_recoverable())?;
+    Ok(FilterValue::ToDate { space0, fmt })
+}
+
+fn to_float_filter(reader: &mut Reader) -> ParseResult<'static, FilterValue> {
+    try_literal("toFloat", reader)?;
+    Ok(FilterValue::ToFloat {})
+}
+
+fn to_int_filter(reader: &mut Reader) -> ParseResult<'static, FilterValue> {
+    try_literal("toInt", reader)?;
+    Ok(FilterValue::ToInt {})
+}
+
+fn url_decode_filter(reader: &mut Reader) -> ParseResult<'static, FilterValue> {
+    try_literal("urlDecode", reader)?;
+    Ok(FilterValue::UrlDecode {})
+}
+
+fn url_encode_filter(reader: &mut Reader) -> ParseResult<'static, FilterValue> {
+    try_literal("urlEncode", reader)?;
+    Ok(FilterValue::UrlEncode {})
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ast::Pos;
+
+    #[test]
+    fn test_regex_filter() {
+        let mut reader = Reader::init("regex \"Hello (.*)!\"");
+        assert_eq!(
+            filter(&mut reader).unwrap(),
+            Filter {
+                source_info: SourceInfo::init(1, 1, 1, 20),
+                value: FilterValue::Regex {
+                    space0: Whitespace {
+                        value: " ".to_string(),
+                        source_info: SourceInfo::init(1, 6, 1, 7)
+                    },
+                    expr: Template {
+                        quotes: true,
+                        elements: vec![TemplateElement::String {
+                            value: "Hello (.*)!".to_string(),
+                            encoded: "Hello (.*)!".to_string()
+                        }],
+                        source_info: SourceInfo::init(1, 7, 1, 20)
+                    }
+                }
+            }
+        );
+    }
+
+    #[test]
+    fn test_count_filter() {
+        let mut reader = Reader::init("count");
+        assert_eq!(
+            filter(&mut reader).unwrap(),
+            Filter {
+                source_info: SourceInfo::init(1, 1, 1, 6),
+                value: FilterValue::Count {}
+            }
+        );
+
+        let mut reader = Reader::init("countEquals 2");
+        assert!(filter(&mut reader).err().unwrap().recoverable);
+    }
+
+    #[test]
+    fn test_nth_filter() {
+        let mut reader = Reader::init("nth 2");
+        assert_eq!(
+            filter(&mut reader).unwrap(),
+            Filter {
+                source_info: SourceInfo::init(1, 1, 1, 6),
+                value: FilterValue::Nth {
+                    space0: Whitespace {
+                        value: " ".to_string(),
+                        source_info: SourceInfo::init(1, 4, 1, 5)
+                    },
+                    n: 2
+                }
+            }
+        );
+
+        let mut reader = Reader::init("nth x");
+        let error = filter(&mut reader).err().unwrap();
+        assert_eq!(error.pos, Pos { line: 1, column: 5 });
+        assert!(!error.recoverable);
+    }
+
+    #[test]
+    fn test_replace_filter() {
+        let mut reader = Reader::init("replace \",\" \";\"");
+        let filter = filter(&mut reader).unwrap();
+        assert_eq!(filter.source_info, SourceInfo::init(1, 1, 1, 16));
+        assert_eq!(filter.value.name(), "replace");
+    }
+
+    #[test]
+    fn test_to_date_filter() {
+        let mut reader = Reader::init("toDate \"%Y-%m-%d\"");
+        assert_eq!(
+            filter(&mut reader).unwrap(),
+            Filter {
+                source_info: SourceInfo::init(1, 1, 1, 18),
+                value: FilterValue::ToDate {
+                    space0: Whitespace {
+                        value: " ".to_string(),
+                        source_info: SourceInfo::init(1, 7, 1, 8)
+                    },
+                    fmt: Template {
+                        quotes: true,
+                        elements: vec![TemplateElement::String {
+                            value: "%Y-%m-%d".to_string(),
+                            encoded: "%Y-%m-%d".to_string()
+                        }],
+                        source_info: SourceInfo::init(1, 8, 1, 18)
+                    }
+                }
+            }
+        );
+
+        let mut reader = Reader::init("toDate 1");
+        let error = filter(&mut reader).err().unwrap();
+        assert_eq!(error.pos, Pos { line: 1, column: 8 });
+        assert!(!error.recoverable);
+    }
+
+    #[test]
+    fn test_filter_without_argument() {
+        for (s, name) in [
+            ("base64Decode", "base64Decode"),
+            ("daysAfterNow", "daysAfterNow"),
+            ("daysBeforeNow", "daysBeforeNow"),
+            ("htmlEscape", "htmlEscape"),
+            ("htmlUnescape", "htmlUnescape"),
+            ("toFloat", "toFloat"),
+            ("toInt", "toInt"),
+            ("urlDecode", "urlDecode"),
+            ("urlEncode", "urlEncode"),
+        ] {
+            let mut reader = Reader::init(s);
+            assert_eq!(filter(&mut reader).unwrap().value.name(), name);
+        }
+    }
+
+    #[test]
+    fn test_filter_error() {
+        let mut reader = Reader::init("equals 1");
+        let error = filter(&mut reader).err().unwrap();
+        assert!(error.recoverable);
+    }
+}
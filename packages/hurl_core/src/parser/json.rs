@@ -434,10 +434,10 @@ mod tests {
                         value: "".to_string(),
                         source_info: SourceInfo::init(1, 15, 1, 15),
                     },
-                    variable: Variable {
+                    kind: ExprKind::Variable(Variable {
                         name: "name".to_string(),
                         source_info: SourceInfo::init(1, 15, 1, 19),
-                    },
+                    }),
                     space1: Whitespace {
                         value: "".to_string(),
                         source_info: SourceInfo::init(1, 19, 1, 19),
@@ -700,10 +700,10 @@ mod tests {
                     value: "".to_string(),
                     source_info: SourceInfo::init(1, 3, 1, 3)
                 },
-                variable: Variable {
+                kind: ExprKind::Variable(Variable {
                     name: "n".to_string(),
                     source_info: SourceInfo::init(1, 3, 1, 4)
-                },
+                }),
                 space1: Whitespace {
                     value: "".to_string(),
                     source_info: SourceInfo::init(1, 4, 1, 4)
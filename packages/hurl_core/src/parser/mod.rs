@@ -15,7 +15,7 @@
  * limitations under the License.
  *
  */
-use crate::ast::HurlFile;
+use crate::ast::{HurlFile, Template};
 
 pub type ParseResult<'a, T> = std::result::Result<T, Error>;
 pub type ParseFunc<'a, T> = fn(&mut reader::Reader) -> ParseResult<'a, T>;
@@ -25,6 +25,11 @@ pub fn parse_hurl_file(s: &str) -> ParseResult<'static, HurlFile> {
     parsers::hurl_file(&mut reader)
 }
 
+pub fn parse_template(s: &str) -> ParseResult<'static, Template> {
+    let mut reader = reader::Reader::init(s);
+    template::template_content(&mut reader)
+}
+
 pub use self::error::{Error, ParseError};
 pub use self::json::boolean_value as parse_json_boolean;
 pub use self::json::null_value as parse_json_null;
@@ -40,6 +45,7 @@ mod cookiepath;
 mod error;
 mod expr;
 mod filename;
+mod filter;
 mod json;
 mod parsers;
 mod predicate;
@@ -49,7 +55,6 @@ mod query;
 mod reader;
 mod sections;
 mod string;
-mod subquery;
 mod template;
 mod url;
 mod xml;
@@ -20,6 +20,7 @@ use crate::ast::*;
 
 use super::combinators::*;
 use super::error::*;
+use super::json::parse as parse_json;
 use super::predicate_value::predicate_value;
 use super::primitives::*;
 use super::reader::Reader;
@@ -82,13 +83,22 @@ fn predicate_func_value(reader: &mut Reader) -> ParseResult<'static, PredicateFu
             start_with_predicate,
             end_with_predicate,
             contain_predicate,
+            include_json_predicate,
             include_predicate,
+            match_json_predicate,
             match_predicate,
+            json_schema_predicate,
+            xml_schema_predicate,
             integer_predicate,
             float_predicate,
             boolean_predicate,
             string_predicate,
             collection_predicate,
+            empty_predicate,
+            date_predicate,
+            iso_date_predicate,
+            number_predicate,
+            list_predicate,
             exist_predicate,
         ],
         reader,
@@ -156,7 +166,7 @@ fn greater_predicate(reader: &mut Reader) -> ParseResult<'static, PredicateFuncV
     };
     let start = reader.state.clone();
     let value = predicate_value(reader)?;
-    if !value.is_number() {
+    if !value.is_number() && !value.is_string() {
         return Err(Error {
             pos: start.pos,
             recoverable: false,
@@ -179,7 +189,7 @@ fn greater_or_equal_predicate(reader: &mut Reader) -> ParseResult<'static, Predi
     };
     let start = reader.state.clone();
     let value = predicate_value(reader)?;
-    if !value.is_number() {
+    if !value.is_number() && !value.is_string() {
         return Err(Error {
             pos: start.pos,
             recoverable: false,
@@ -202,7 +212,7 @@ fn less_predicate(reader: &mut Reader) -> ParseResult<'static, PredicateFuncValu
     };
     let start = reader.state.clone();
     let value = predicate_value(reader)?;
-    if !value.is_number() {
+    if !value.is_number() && !value.is_string() {
         return Err(Error {
             pos: start.pos,
             recoverable: false,
@@ -225,7 +235,7 @@ fn less_or_equal_predicate(reader: &mut Reader) -> ParseResult<'static, Predicat
     };
     let start = reader.state.clone();
     let value = predicate_value(reader)?;
-    if !value.is_number() {
+    if !value.is_number() && !value.is_string() {
         return Err(Error {
             pos: start.pos,
             recoverable: false,
@@ -321,6 +331,34 @@ fn match_predicate(reader: &mut Reader) -> ParseResult<'static, PredicateFuncVal
     Ok(PredicateFuncValue::Match { space0, value })
 }
 
+fn include_json_predicate(reader: &mut Reader) -> ParseResult<'static, PredicateFuncValue> {
+    try_literal("includesJson", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let value = nonrecover(parse_json, reader)?;
+    Ok(PredicateFuncValue::IncludeJson { space0, value })
+}
+
+fn match_json_predicate(reader: &mut Reader) -> ParseResult<'static, PredicateFuncValue> {
+    try_literal("matchesJson", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let value = nonrecover(parse_json, reader)?;
+    Ok(PredicateFuncValue::MatchJson { space0, value })
+}
+
+fn json_schema_predicate(reader: &mut Reader) -> ParseResult<'static, PredicateFuncValue> {
+    try_literal("jsonschema", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let value = nonrecover(file, reader)?;
+    Ok(PredicateFuncValue::JsonSchema { space0, value })
+}
+
+fn xml_schema_predicate(reader: &mut Reader) -> ParseResult<'static, PredicateFuncValue> {
+    try_literal("xmlschema", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let value = nonrecover(file, reader)?;
+    Ok(PredicateFuncValue::XmlSchema { space0, value })
+}
+
 fn integer_predicate(reader: &mut Reader) -> ParseResult<'static, PredicateFuncValue> {
     try_literal("isInteger", reader)?;
     Ok(PredicateFuncValue::IsInteger {})
@@ -346,6 +384,31 @@ fn collection_predicate(reader: &mut Reader) -> ParseResult<'static, PredicateFu
     Ok(PredicateFuncValue::IsCollection {})
 }
 
+fn empty_predicate(reader: &mut Reader) -> ParseResult<'static, PredicateFuncValue> {
+    try_literal("isEmpty", reader)?;
+    Ok(PredicateFuncValue::IsEmpty {})
+}
+
+fn date_predicate(reader: &mut Reader) -> ParseResult<'static, PredicateFuncValue> {
+    try_literal("isDate", reader)?;
+    Ok(PredicateFuncValue::IsDate {})
+}
+
+fn iso_date_predicate(reader: &mut Reader) -> ParseResult<'static, PredicateFuncValue> {
+    try_literal("isIsoDate", reader)?;
+    Ok(PredicateFuncValue::IsIsoDate {})
+}
+
+fn number_predicate(reader: &mut Reader) -> ParseResult<'static, PredicateFuncValue> {
+    try_literal("isNumber", reader)?;
+    Ok(PredicateFuncValue::IsNumber {})
+}
+
+fn list_predicate(reader: &mut Reader) -> ParseResult<'static, PredicateFuncValue> {
+    try_literal("isList", reader)?;
+    Ok(PredicateFuncValue::IsList {})
+}
+
 fn exist_predicate(reader: &mut Reader) -> ParseResult<'static, PredicateFuncValue> {
     try_literal("exists", reader)?;
     Ok(PredicateFuncValue::Exist {})
@@ -427,6 +490,145 @@ mod tests {
         assert_eq!(error.inner, ParseError::PredicateValue {});
     }
 
+    #[test]
+    fn test_greater_predicate() {
+        let mut reader = Reader::init("> \"2022-01-01T00:00:00Z\"");
+        let value = predicate_func_value(&mut reader).unwrap();
+        assert!(matches!(
+            value,
+            PredicateFuncValue::GreaterThan {
+                value: PredicateValue::String(_),
+                operator: true,
+                ..
+            }
+        ));
+
+        let mut reader = Reader::init("> true");
+        let error = predicate_func_value(&mut reader).err().unwrap();
+        assert!(!error.recoverable);
+        assert_eq!(error.inner, ParseError::PredicateValue {});
+    }
+
+    #[test]
+    fn test_type_predicates() {
+        for (s, expected) in [
+            ("isEmpty", PredicateFuncValue::IsEmpty {}),
+            ("isDate", PredicateFuncValue::IsDate {}),
+            ("isIsoDate", PredicateFuncValue::IsIsoDate {}),
+            ("isNumber", PredicateFuncValue::IsNumber {}),
+            ("isList", PredicateFuncValue::IsList {}),
+        ] {
+            let mut reader = Reader::init(s);
+            assert_eq!(predicate_func_value(&mut reader).unwrap(), expected);
+            assert_eq!(reader.state.cursor, s.len());
+        }
+    }
+
+    #[test]
+    fn test_json_schema_predicate() {
+        let mut reader = Reader::init("jsonschema file,user.schema.json;");
+        assert_eq!(
+            predicate_func_value(&mut reader).unwrap(),
+            PredicateFuncValue::JsonSchema {
+                space0: Whitespace {
+                    value: String::from(" "),
+                    source_info: SourceInfo::init(1, 11, 1, 12),
+                },
+                value: File {
+                    space0: Whitespace {
+                        value: String::from(""),
+                        source_info: SourceInfo::init(1, 17, 1, 17),
+                    },
+                    filename: Filename {
+                        value: String::from("user.schema.json"),
+                        source_info: SourceInfo::init(1, 17, 1, 33),
+                    },
+                    space1: Whitespace {
+                        value: String::from(""),
+                        source_info: SourceInfo::init(1, 33, 1, 33),
+                    },
+                },
+            }
+        );
+
+        let mut reader = Reader::init("jsonschema \"user.schema.json\"");
+        let error = predicate_func_value(&mut reader).err().unwrap();
+        assert_eq!(
+            error.pos,
+            Pos {
+                line: 1,
+                column: 12
+            }
+        );
+        assert!(!error.recoverable);
+    }
+
+    #[test]
+    fn test_xml_schema_predicate() {
+        let mut reader = Reader::init("xmlschema file,order.xsd;");
+        assert_eq!(
+            predicate_func_value(&mut reader).unwrap(),
+            PredicateFuncValue::XmlSchema {
+                space0: Whitespace {
+                    value: String::from(" "),
+                    source_info: SourceInfo::init(1, 10, 1, 11),
+                },
+                value: File {
+                    space0: Whitespace {
+                        value: String::from(""),
+                        source_info: SourceInfo::init(1, 16, 1, 16),
+                    },
+                    filename: Filename {
+                        value: String::from("order.xsd"),
+                        source_info: SourceInfo::init(1, 16, 1, 25),
+                    },
+                    space1: Whitespace {
+                        value: String::from(""),
+                        source_info: SourceInfo::init(1, 25, 1, 25),
+                    },
+                },
+            }
+        );
+    }
+
+    #[test]
+    fn test_json_predicates() {
+        let mut reader = Reader::init("includesJson [1]");
+        assert_eq!(
+            predicate_func_value(&mut reader).unwrap(),
+            PredicateFuncValue::IncludeJson {
+                space0: Whitespace {
+                    value: String::from(" "),
+                    source_info: SourceInfo::init(1, 13, 1, 14),
+                },
+                value: JsonValue::List {
+                    space0: "".to_string(),
+                    elements: vec![JsonListElement {
+                        space0: "".to_string(),
+                        value: JsonValue::Number("1".to_string()),
+                        space1: "".to_string(),
+                    }],
+                },
+            }
+        );
+
+        let mut reader = Reader::init("matchesJson true");
+        assert_eq!(
+            predicate_func_value(&mut reader).unwrap(),
+            PredicateFuncValue::MatchJson {
+                space0: Whitespace {
+                    value: String::from(" "),
+                    source_info: SourceInfo::init(1, 12, 1, 13),
+                },
+                value: JsonValue::Boolean(true),
+            }
+        );
+
+        let mut reader = Reader::init("includesJson {\"id\":}");
+        let error = predicate_func_value(&mut reader).err().unwrap();
+        assert!(!error.recoverable);
+    }
+
     #[test]
     fn test_predicate_func() {
         let mut reader = Reader::init("tata equals 1");
@@ -526,10 +728,10 @@ mod tests {
                         value: String::from(""),
                         source_info: SourceInfo::init(1, 10, 1, 10),
                     },
-                    variable: Variable {
+                    kind: ExprKind::Variable(Variable {
                         name: "count".to_string(),
                         source_info: SourceInfo::init(1, 10, 1, 15),
-                    },
+                    }),
                     space1: Whitespace {
                         value: String::from(""),
                         source_info: SourceInfo::init(1, 15, 1, 15),
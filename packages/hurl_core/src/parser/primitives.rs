@@ -571,6 +571,20 @@ pub(crate) fn file(reader: &mut Reader) -> ParseResult<'static, File> {
     })
 }
 
+pub(crate) fn template_file(reader: &mut Reader) -> ParseResult<'static, TemplateFile> {
+    try_literal("template", reader)?;
+    literal(",", reader)?;
+    let space0 = zero_or_more_spaces(reader)?;
+    let f = filename::parse(reader)?;
+    let space1 = zero_or_more_spaces(reader)?;
+    literal(";", reader)?;
+    Ok(TemplateFile {
+        space0,
+        filename: f,
+        space1,
+    })
+}
+
 pub(crate) fn base64(reader: &mut Reader) -> ParseResult<'static, Base64> {
     // base64 => can have whitespace
     // support pqrser position
@@ -844,10 +858,10 @@ mod tests {
                                 value: "".to_string(),
                                 source_info: SourceInfo::init(1, 18, 1, 18),
                             },
-                            variable: Variable {
+                            kind: ExprKind::Variable(Variable {
                                 name: "name".to_string(),
                                 source_info: SourceInfo::init(1, 18, 1, 22),
-                            },
+                            }),
                             space1: Whitespace {
                                 value: "".to_string(),
                                 source_info: SourceInfo::init(1, 22, 1, 22),
@@ -1457,6 +1471,31 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_template_file() {
+        let mut reader = Reader::init("template, payload.json;");
+        assert_eq!(
+            template_file(&mut reader).unwrap(),
+            TemplateFile {
+                space0: Whitespace {
+                    value: String::from(" "),
+                    source_info: SourceInfo::init(1, 10, 1, 11),
+                },
+                filename: Filename {
+                    value: String::from("payload.json"),
+                    source_info: SourceInfo::init(1, 11, 1, 23),
+                },
+                space1: Whitespace {
+                    value: String::from(""),
+                    source_info: SourceInfo::init(1, 23, 1, 23),
+                },
+            }
+        );
+
+        let mut reader = Reader::init("file,data.xml;");
+        assert!(template_file(&mut reader).err().unwrap().recoverable);
+    }
+
     #[test]
     fn test_file() {
         let mut reader = Reader::init("file,data.xml;");
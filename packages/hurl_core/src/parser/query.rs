@@ -19,10 +19,11 @@ use crate::ast::*;
 
 use super::combinators::*;
 use super::cookiepath::cookiepath;
+use super::error::*;
+use super::filter::filter;
 use super::primitives::*;
 use super::reader::Reader;
 use super::string::*;
-use super::subquery::subquery;
 use super::ParseResult;
 
 pub fn query(reader: &mut Reader) -> ParseResult<'static, Query> {
@@ -30,35 +31,27 @@ pub fn query(reader: &mut Reader) -> ParseResult<'static, Query> {
     let value = query_value(reader)?;
     let end = reader.state.pos.clone();
 
-    let save = reader.state.clone();
-    let space = zero_or_more_spaces(reader)?;
-    let optional_subquery = if space.value.is_empty() {
-        reader.state = save;
-        None
-    } else {
-        match subquery(reader) {
-            Ok(q) => Some((space, q)),
-            Err(e) => {
-                if e.recoverable {
-                    reader.state = save;
-                    None
-                } else {
-                    return Err(e);
-                }
-            }
-        }
-    };
+    let filters = zero_or_more(query_filter, reader)?;
     Ok(Query {
         source_info: SourceInfo { start, end },
         value,
-        subquery: optional_subquery,
+        filters,
     })
 }
 
+fn query_filter(reader: &mut Reader) -> ParseResult<'static, (Whitespace, Filter)> {
+    let space = recover(one_or_more_spaces, reader)?;
+    let filter = filter(reader)?;
+    Ok((space, filter))
+}
+
 fn query_value(reader: &mut Reader) -> ParseResult<'static, QueryValue> {
     choice(
         vec![
             status_query,
+            url_query,
+            ip_query,
+            redirects_query,
             header_query,
             cookie_query,
             body_query,
@@ -67,6 +60,8 @@ fn query_value(reader: &mut Reader) -> ParseResult<'static, QueryValue> {
             regex_query,
             variable_query,
             duration_query,
+            timing_query,
+            certificate_query,
             bytes_query,
             sha256_query,
             md5_query,
@@ -152,11 +147,88 @@ fn variable_query(reader: &mut Reader) -> ParseResult<'static, QueryValue> {
     Ok(QueryValue::Variable { space0, name })
 }
 
+fn url_query(reader: &mut Reader) -> ParseResult<'static, QueryValue> {
+    try_literal("url", reader)?;
+    Ok(QueryValue::Url {})
+}
+
+fn ip_query(reader: &mut Reader) -> ParseResult<'static, QueryValue> {
+    try_literal("ip", reader)?;
+    Ok(QueryValue::Ip {})
+}
+
+fn redirects_query(reader: &mut Reader) -> ParseResult<'static, QueryValue> {
+    try_literal("redirects", reader)?;
+    Ok(QueryValue::Redirects {})
+}
+
 fn duration_query(reader: &mut Reader) -> ParseResult<'static, QueryValue> {
     try_literal("duration", reader)?;
     Ok(QueryValue::Duration {})
 }
 
+fn timing_query(reader: &mut Reader) -> ParseResult<'static, QueryValue> {
+    try_literal("timing", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let name = timing_name(reader)?;
+    Ok(QueryValue::Timing { space0, name })
+}
+
+fn timing_name(reader: &mut Reader) -> ParseResult<'static, TimingName> {
+    let start = reader.state.pos.clone();
+    let s = reader.read_while(|c| c.is_ascii_alphabetic());
+    match s.as_str() {
+        "namelookup" => Ok(TimingName::NameLookup),
+        "connect" => Ok(TimingName::Connect),
+        "appconnect" => Ok(TimingName::AppConnect),
+        "pretransfer" => Ok(TimingName::PreTransfer),
+        "starttransfer" => Ok(TimingName::StartTransfer),
+        "total" => Ok(TimingName::Total),
+        _ => Err(Error {
+            pos: start,
+            recoverable: false,
+            inner: ParseError::Expecting {
+                value: "namelookup, connect, appconnect, pretransfer, starttransfer or total"
+                    .to_string(),
+            },
+        }),
+    }
+}
+
+fn certificate_query(reader: &mut Reader) -> ParseResult<'static, QueryValue> {
+    try_literal("certificate", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let attribute_name = certificate_attribute_name(reader)?;
+    Ok(QueryValue::Certificate {
+        space0,
+        attribute_name,
+    })
+}
+
+fn certificate_attribute_name(
+    reader: &mut Reader,
+) -> ParseResult<'static, CertificateAttributeName> {
+    let start = reader.state.pos.clone();
+    literal("\"", reader)?;
+    let s = reader.read_while(|c| c.is_ascii_alphabetic() || *c == '-');
+    literal("\"", reader)?;
+    match s.as_str() {
+        "Subject" => Ok(CertificateAttributeName::Subject),
+        "Issuer" => Ok(CertificateAttributeName::Issuer),
+        "Start-Date" => Ok(CertificateAttributeName::StartDate),
+        "Expire-Date" => Ok(CertificateAttributeName::ExpireDate),
+        "Serial-Number" => Ok(CertificateAttributeName::SerialNumber),
+        _ => Err(Error {
+            pos: start,
+            recoverable: false,
+            inner: ParseError::Expecting {
+                value: "Field <Subject>, <Issuer>, <Start-Date>, <Expire-Date> or <Serial-Number>"
+                    .to_string(),
+            },
+        }),
+    }
+}
+
 fn bytes_query(reader: &mut Reader) -> ParseResult<'static, QueryValue> {
     try_literal("bytes", reader)?;
     Ok(QueryValue::Bytes {})
@@ -184,7 +256,7 @@ mod tests {
             Query {
                 source_info: SourceInfo::init(1, 1, 1, 7),
                 value: QueryValue::Status {},
-                subquery: None
+                filters: vec![]
             }
         );
     }
@@ -197,11 +269,25 @@ mod tests {
             Query {
                 source_info: SourceInfo::init(1, 1, 1, 7),
                 value: QueryValue::Status {},
-                subquery: None
+                filters: vec![]
             }
         );
     }
 
+    #[test]
+    fn test_url_ip_redirects_query() {
+        let mut reader = Reader::init("url");
+        assert_eq!(query(&mut reader).unwrap().value, QueryValue::Url {});
+
+        let mut reader = Reader::init("ip");
+        assert_eq!(query(&mut reader).unwrap().value, QueryValue::Ip {});
+
+        let mut reader = Reader::init("redirects count");
+        let query = query(&mut reader).unwrap();
+        assert_eq!(query.value, QueryValue::Redirects {});
+        assert_eq!(query.filters.len(), 1);
+    }
+
     #[test]
     fn test_header_query() {
         let mut reader = Reader::init("header \"Foo\"");
@@ -342,4 +428,57 @@ mod tests {
             },
         );
     }
+
+    #[test]
+    fn test_certificate_query() {
+        let mut reader = Reader::init("certificate \"Expire-Date\"");
+        assert_eq!(
+            certificate_query(&mut reader).unwrap(),
+            QueryValue::Certificate {
+                space0: Whitespace {
+                    value: String::from(" "),
+                    source_info: SourceInfo::init(1, 12, 1, 13),
+                },
+                attribute_name: CertificateAttributeName::ExpireDate,
+            }
+        );
+
+        let mut reader = Reader::init("certificate \"Expire\"");
+        let error = certificate_query(&mut reader).err().unwrap();
+        assert_eq!(
+            error.pos,
+            Pos {
+                line: 1,
+                column: 13
+            }
+        );
+        assert!(!error.recoverable);
+    }
+
+    #[test]
+    fn test_timing_query() {
+        let mut reader = Reader::init("timing connect");
+        assert_eq!(
+            timing_query(&mut reader).unwrap(),
+            QueryValue::Timing {
+                space0: Whitespace {
+                    value: String::from(" "),
+                    source_info: SourceInfo::init(1, 7, 1, 8),
+                },
+                name: TimingName::Connect,
+            }
+        );
+
+        let mut reader = Reader::init("timing dns");
+        let error = timing_query(&mut reader).err().unwrap();
+        assert_eq!(error.pos, Pos { line: 1, column: 8 });
+        assert_eq!(
+            error.inner,
+            ParseError::Expecting {
+                value: "namelookup, connect, appconnect, pretransfer, starttransfer or total"
+                    .to_string()
+            }
+        );
+        assert!(!error.recoverable);
+    }
 }
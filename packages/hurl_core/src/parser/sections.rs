@@ -19,6 +19,7 @@ use crate::ast::*;
 
 use super::combinators::*;
 use super::error::*;
+use super::expr;
 use super::filename;
 use super::predicate::predicate;
 use super::primitives::*;
@@ -53,6 +54,7 @@ fn request_section(reader: &mut Reader) -> ParseResult<'static, Section> {
         "FormParams" => section_value_form_params(reader)?,
         "MultipartFormData" => section_value_multipart_form_data(reader)?,
         "Cookies" => section_value_cookies(reader)?,
+        "Options" => section_value_options(reader)?,
         _ => {
             return Err(Error {
                 pos: Pos {
@@ -140,6 +142,11 @@ fn section_value_cookies(reader: &mut Reader) -> ParseResult<'static, SectionVal
     Ok(SectionValue::Cookies(items))
 }
 
+fn section_value_options(reader: &mut Reader) -> ParseResult<'static, SectionValue> {
+    let options = zero_or_more(option, reader)?;
+    Ok(SectionValue::Options(options))
+}
+
 fn section_value_captures(reader: &mut Reader) -> ParseResult<'static, SectionValue> {
     let items = zero_or_more(capture, reader)?;
     Ok(SectionValue::Captures(items))
@@ -227,27 +234,32 @@ fn file_param(reader: &mut Reader) -> ParseResult<'static, FileParam> {
 fn file_value(reader: &mut Reader) -> ParseResult<'static, FileValue> {
     try_literal("file,", reader)?;
     let space0 = zero_or_more_spaces(reader)?;
-    let f = filename::parse(reader)?;
+    let f = filename::parse_template(reader)?;
     let space1 = zero_or_more_spaces(reader)?;
     literal(";", reader)?;
+    let space2 = spaces_before_value(reader)?;
     let save = reader.state.clone();
-    let (space2, content_type) = match line_terminator(reader) {
+    let content = match file_content(reader) {
+        Ok(content) => Some(content),
+        Err(e) if !e.recoverable => return Err(e),
+        Err(_) => {
+            reader.state = save;
+            None
+        }
+    };
+    let space3 = match content {
+        Some(_) => spaces_before_value(reader)?,
+        None => empty_space(reader),
+    };
+    let save = reader.state.clone();
+    let content_type = match line_terminator(reader) {
         Ok(_) => {
-            reader.state = save.clone();
-            let space2 = Whitespace {
-                value: "".to_string(),
-                source_info: SourceInfo {
-                    start: save.pos.clone(),
-                    end: save.pos,
-                },
-            };
-            (space2, None)
+            reader.state = save;
+            None
         }
         Err(_) => {
             reader.state = save;
-            let space2 = zero_or_more_spaces(reader)?;
-            let content_type = file_content_type(reader)?;
-            (space2, Some(content_type))
+            Some(file_content_type(reader)?)
         }
     };
 
@@ -256,10 +268,46 @@ fn file_value(reader: &mut Reader) -> ParseResult<'static, FileValue> {
         filename: f,
         space1,
         space2,
+        content,
+        space3,
         content_type,
     })
 }
 
+/// Parses the spaces before a value, the spaces at the end of the line being left to the line terminator.
+fn spaces_before_value(reader: &mut Reader) -> ParseResult<'static, Whitespace> {
+    let save = reader.state.clone();
+    let is_end_of_line = line_terminator(reader).is_ok();
+    reader.state = save;
+    if is_end_of_line {
+        Ok(empty_space(reader))
+    } else {
+        zero_or_more_spaces(reader)
+    }
+}
+
+fn empty_space(reader: &mut Reader) -> Whitespace {
+    Whitespace {
+        value: "".to_string(),
+        source_info: SourceInfo {
+            start: reader.state.pos.clone(),
+            end: reader.state.pos.clone(),
+        },
+    }
+}
+
+fn file_content(reader: &mut Reader) -> ParseResult<'static, FileContent> {
+    choice(
+        vec![
+            |p1| raw_string(p1).map(FileContent::RawString),
+            |p1| base64(p1).map(FileContent::Base64),
+            |p1| hex(p1).map(FileContent::Hex),
+            |p1| expr::parse(p1).map(FileContent::Expression),
+        ],
+        reader,
+    )
+}
+
 fn file_content_type(reader: &mut Reader) -> ParseResult<'static, String> {
     let start = reader.state.clone();
     let mut buf = "".to_string();
@@ -289,6 +337,55 @@ fn file_content_type(reader: &mut Reader) -> ParseResult<'static, String> {
     Ok(buf)
 }
 
+fn option(reader: &mut Reader) -> ParseResult<'static, EntryOption> {
+    let line_terminators = optional_line_terminators(reader)?;
+    let space0 = zero_or_more_spaces(reader)?;
+    let start = reader.state.clone();
+    let name = reader.read_while(|c| c.is_ascii_alphanumeric() || *c == '-' || *c == '.');
+    let space1 = zero_or_more_spaces(reader)?;
+    recover(|p1| literal(":", p1), reader)?;
+    let space2 = zero_or_more_spaces(reader)?;
+    let kind = match name.as_str() {
+        "cacert" => OptionKind::CaCertificate(filename::parse(reader)?),
+        "cert" => OptionKind::ClientCertificate(filename::parse(reader)?),
+        "compressed" => OptionKind::Compressed(nonrecover(boolean, reader)?),
+        "connect-timeout" => OptionKind::ConnectTimeout(nonrecover(natural, reader)?),
+        "http1.0" => OptionKind::Http10(nonrecover(boolean, reader)?),
+        "http1.1" => OptionKind::Http11(nonrecover(boolean, reader)?),
+        "http2" => OptionKind::Http2(nonrecover(boolean, reader)?),
+        "http2-prior-knowledge" => OptionKind::Http2PriorKnowledge(nonrecover(boolean, reader)?),
+        "ignore-json-key" => OptionKind::IgnoreJsonKey(unquoted_template(reader)?),
+        "insecure" => OptionKind::Insecure(nonrecover(boolean, reader)?),
+        "key" => OptionKind::ClientKey(filename::parse(reader)?),
+        "key-password" => OptionKind::KeyPassword(unquoted_template(reader)?),
+        "location" => OptionKind::FollowLocation(nonrecover(boolean, reader)?),
+        "max-redirs" => OptionKind::MaxRedirect(nonrecover(natural, reader)?),
+        "max-time" => OptionKind::Timeout(nonrecover(natural, reader)?),
+        "noproxy" => OptionKind::NoProxy(unquoted_template(reader)?),
+        "output" => OptionKind::Output(filename::parse(reader)?),
+        "proxy" => OptionKind::Proxy(unquoted_template(reader)?),
+        "retry" => OptionKind::Retry(nonrecover(natural, reader)?),
+        "retry-interval" => OptionKind::RetryInterval(nonrecover(natural, reader)?),
+        "user-agent" => OptionKind::UserAgent(unquoted_template(reader)?),
+        _ => {
+            return Err(Error {
+                pos: start.pos,
+                recoverable: false,
+                inner: ParseError::InvalidOption { name },
+            })
+        }
+    };
+    let line_terminator0 = line_terminator(reader)?;
+    Ok(EntryOption {
+        line_terminators,
+        space0,
+        space1,
+        space2,
+        kind,
+        line_terminator0,
+    })
+}
+
 fn capture(reader: &mut Reader) -> ParseResult<'static, Capture> {
     let line_terminators = optional_line_terminators(reader)?;
     let space0 = zero_or_more_spaces(reader)?;
@@ -409,7 +506,7 @@ mod tests {
                                 source_info: SourceInfo::init(2, 8, 2, 18),
                             },
                         },
-                        subquery: None,
+                        filters: vec![],
                     },
                     space1: Whitespace {
                         value: String::from(" "),
@@ -534,8 +631,12 @@ mod tests {
                     value: "".to_string(),
                     source_info: SourceInfo::init(1, 6, 1, 6),
                 },
-                filename: Filename {
-                    value: "hello.txt".to_string(),
+                filename: Template {
+                    quotes: false,
+                    elements: vec![TemplateElement::String {
+                        value: "hello.txt".to_string(),
+                        encoded: "hello.txt".to_string(),
+                    }],
                     source_info: SourceInfo::init(1, 6, 1, 15),
                 },
                 space1: Whitespace {
@@ -546,6 +647,11 @@ mod tests {
                     value: "".to_string(),
                     source_info: SourceInfo::init(1, 16, 1, 16),
                 },
+                content: None,
+                space3: Whitespace {
+                    value: "".to_string(),
+                    source_info: SourceInfo::init(1, 16, 1, 16),
+                },
                 content_type: None,
             }
         );
@@ -557,8 +663,12 @@ mod tests {
                     value: "".to_string(),
                     source_info: SourceInfo::init(1, 6, 1, 6),
                 },
-                filename: Filename {
-                    value: "hello.txt".to_string(),
+                filename: Template {
+                    quotes: false,
+                    elements: vec![TemplateElement::String {
+                        value: "hello.txt".to_string(),
+                        encoded: "hello.txt".to_string(),
+                    }],
                     source_info: SourceInfo::init(1, 6, 1, 15),
                 },
                 space1: Whitespace {
@@ -569,11 +679,49 @@ mod tests {
                     value: " ".to_string(),
                     source_info: SourceInfo::init(1, 16, 1, 17),
                 },
+                content: None,
+                space3: Whitespace {
+                    value: "".to_string(),
+                    source_info: SourceInfo::init(1, 17, 1, 17),
+                },
                 content_type: Some("text/html".to_string()),
             }
         );
     }
 
+    #[test]
+    fn test_file_value_inline_content() {
+        let mut reader = Reader::init("file,data.bin; hex,0102; application/octet-stream");
+        let value = file_value(&mut reader).unwrap();
+        assert_eq!(
+            value.content,
+            Some(FileContent::Hex(Hex {
+                space0: Whitespace {
+                    value: "".to_string(),
+                    source_info: SourceInfo::init(1, 20, 1, 20),
+                },
+                value: vec![1, 2],
+                encoded: "0102".to_string(),
+                space1: Whitespace {
+                    value: "".to_string(),
+                    source_info: SourceInfo::init(1, 24, 1, 24),
+                },
+            }))
+        );
+        assert_eq!(value.space2.value, " ");
+        assert_eq!(value.space3.value, " ");
+        assert_eq!(
+            value.content_type,
+            Some("application/octet-stream".to_string())
+        );
+
+        let mut reader = Reader::init("file,report-{{id}}.csv; {{data}}");
+        let value = file_value(&mut reader).unwrap();
+        assert_eq!(value.filename.to_string(), "report-{{id}}.csv");
+        assert!(matches!(value.content, Some(FileContent::Expression(_))));
+        assert_eq!(value.content_type, None);
+    }
+
     #[test]
     fn test_file_content_type() {
         let mut reader = Reader::init("text/html");
@@ -598,6 +746,143 @@ mod tests {
         assert_eq!(reader.state.cursor, 9);
     }
 
+    #[test]
+    fn test_option_insecure() {
+        let mut reader = Reader::init("insecure: true");
+        let option = option(&mut reader).unwrap();
+        assert_eq!(
+            option,
+            EntryOption {
+                line_terminators: vec![],
+                space0: Whitespace {
+                    value: String::from(""),
+                    source_info: SourceInfo::init(1, 1, 1, 1),
+                },
+                space1: Whitespace {
+                    value: String::from(""),
+                    source_info: SourceInfo::init(1, 9, 1, 9),
+                },
+                space2: Whitespace {
+                    value: String::from(" "),
+                    source_info: SourceInfo::init(1, 10, 1, 11),
+                },
+                kind: OptionKind::Insecure(true),
+                line_terminator0: LineTerminator {
+                    space0: Whitespace {
+                        value: String::from(""),
+                        source_info: SourceInfo::init(1, 15, 1, 15),
+                    },
+                    comment: None,
+                    newline: Whitespace {
+                        value: String::from(""),
+                        source_info: SourceInfo::init(1, 15, 1, 15),
+                    },
+                },
+            }
+        );
+    }
+
+    #[test]
+    fn test_option_proxy() {
+        let mut reader = Reader::init("proxy: localhost:3128\n");
+        let option = option(&mut reader).unwrap();
+        assert_eq!(
+            option.kind,
+            OptionKind::Proxy(Template {
+                quotes: false,
+                elements: vec![TemplateElement::String {
+                    value: "localhost:3128".to_string(),
+                    encoded: "localhost:3128".to_string(),
+                }],
+                source_info: SourceInfo::init(1, 8, 1, 22),
+            })
+        );
+    }
+
+    #[test]
+    fn test_option_client_certificate() {
+        let mut reader = Reader::init("cert: client.pem\n");
+        let kind = option(&mut reader).unwrap().kind;
+        assert_eq!(
+            kind,
+            OptionKind::ClientCertificate(Filename {
+                value: "client.pem".to_string(),
+                source_info: SourceInfo::init(1, 7, 1, 17),
+            })
+        );
+
+        let mut reader = Reader::init("key-password: {{password}}\n");
+        let kind = option(&mut reader).unwrap().kind;
+        assert_eq!(kind.name(), "key-password");
+        assert_eq!(kind.to_string(), "{{password}}");
+    }
+
+    #[test]
+    fn test_option_output() {
+        let mut reader = Reader::init("output: build/data.bin\n");
+        let kind = option(&mut reader).unwrap().kind;
+        assert_eq!(
+            kind,
+            OptionKind::Output(Filename {
+                value: "build/data.bin".to_string(),
+                source_info: SourceInfo::init(1, 9, 1, 23),
+            })
+        );
+    }
+
+    #[test]
+    fn test_option_ignore_json_key() {
+        let mut reader = Reader::init("ignore-json-key: updatedAt\n");
+        let kind = option(&mut reader).unwrap().kind;
+        assert_eq!(kind.name(), "ignore-json-key");
+        assert_eq!(kind.to_string(), "updatedAt");
+    }
+
+    #[test]
+    fn test_option_error() {
+        let mut reader = Reader::init("location: yes");
+        let error = option(&mut reader).err().unwrap();
+        assert_eq!(
+            error.pos,
+            Pos {
+                line: 1,
+                column: 11
+            }
+        );
+        assert!(!error.recoverable);
+
+        let mut reader = Reader::init("verbose: true");
+        let error = option(&mut reader).err().unwrap();
+        assert_eq!(error.pos, Pos { line: 1, column: 1 });
+        assert!(!error.recoverable);
+        assert_eq!(
+            error.inner,
+            ParseError::InvalidOption {
+                name: "verbose".to_string()
+            }
+        );
+
+        let mut reader = Reader::init("GET http://localhost");
+        let error = option(&mut reader).err().unwrap();
+        assert!(error.recoverable);
+    }
+
+    #[test]
+    fn test_options_section() {
+        let mut reader = Reader::init("[Options]\nlocation: true\nmax-redirs: 10\nhttp1.0: true\n");
+        let section = request_section(&mut reader).unwrap();
+        assert_eq!(section.name(), "Options");
+        match section.value {
+            SectionValue::Options(options) => {
+                assert_eq!(options.len(), 3);
+                assert_eq!(options[0].kind, OptionKind::FollowLocation(true));
+                assert_eq!(options[1].kind, OptionKind::MaxRedirect(10));
+                assert_eq!(options[2].kind, OptionKind::Http10(true));
+            }
+            _ => panic!("expecting an options section"),
+        }
+    }
+
     #[test]
     fn test_capture() {
         let mut reader = Reader::init("url: header \"Location\"");
@@ -630,13 +915,13 @@ mod tests {
                         source_info: SourceInfo::init(1, 13, 1, 23),
                     },
                 },
-                subquery: None,
+                filters: vec![],
             }
         );
     }
 
     #[test]
-    fn test_capture_with_subquery() {
+    fn test_capture_with_filter() {
         let mut reader = Reader::init("token: header \"Location\" regex \"token=(.*)\"");
         let capture0 = capture(&mut reader).unwrap();
 
@@ -659,14 +944,14 @@ mod tests {
                     },
                 },
 
-                subquery: Some((
+                filters: vec![(
                     Whitespace {
                         value: " ".to_string(),
                         source_info: SourceInfo::init(1, 25, 1, 26),
                     },
-                    Subquery {
+                    Filter {
                         source_info: SourceInfo::init(1, 26, 1, 44),
-                        value: SubqueryValue::Regex {
+                        value: FilterValue::Regex {
                             space0: Whitespace {
                                 value: " ".to_string(),
                                 source_info: SourceInfo::init(1, 31, 1, 32),
@@ -681,14 +966,31 @@ mod tests {
                             },
                         },
                     }
-                )),
+                )],
             }
         );
         assert_eq!(reader.state.cursor, 43);
     }
 
     #[test]
-    fn test_capture_with_subquery_error() {
+    fn test_capture_with_filters() {
+        let mut reader = Reader::init("count: jsonpath \"$.ids\" split \",\" count\n");
+        let capture0 = capture(&mut reader).unwrap();
+        let filters = capture0
+            .query
+            .filters
+            .iter()
+            .map(|(_, f)| f.value.name())
+            .collect::<Vec<&str>>();
+        assert_eq!(filters, vec!["split", "count"]);
+        assert_eq!(
+            capture0.query.filters[1].1.source_info,
+            SourceInfo::init(1, 35, 1, 40)
+        );
+    }
+
+    #[test]
+    fn test_capture_with_filter_error() {
         let mut reader = Reader::init("token: header \"Location\" regex ");
         let error = capture(&mut reader).err().unwrap();
         assert_eq!(
@@ -747,7 +1049,7 @@ mod tests {
                         source_info: SourceInfo::init(1, 8, 1, 18),
                     },
                 },
-                subquery: None,
+                filters: vec![],
             }
         );
     }
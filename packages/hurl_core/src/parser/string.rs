@@ -382,10 +382,10 @@ mod tests {
                             value: "".to_string(),
                             source_info: SourceInfo::init(1, 14, 1, 14),
                         },
-                        variable: Variable {
+                        kind: ExprKind::Variable(Variable {
                             name: "name".to_string(),
                             source_info: SourceInfo::init(1, 14, 1, 18),
-                        },
+                        }),
                         space1: Whitespace {
                             value: "".to_string(),
                             source_info: SourceInfo::init(1, 18, 1, 18),
@@ -16,7 +16,7 @@
  *
  */
 
-use crate::ast::{Pos, SourceInfo, TemplateElement};
+use crate::ast::{Pos, SourceInfo, Template, TemplateElement};
 
 use super::error;
 use super::expr;
@@ -28,6 +28,31 @@ pub struct EncodedString {
     pub chars: Vec<(char, String, Pos)>,
 }
 
+/// Parses the whole remaining input as a template, the content of a template file for instance.
+pub fn template_content(reader: &mut Reader) -> ParseResult<'static, Template> {
+    let mut chars = vec![];
+    let start = reader.state.pos.clone();
+    while !reader.is_eof() {
+        let pos = reader.state.pos.clone();
+        let c = reader.read().unwrap();
+        chars.push((c, c.to_string(), pos));
+    }
+    let end = reader.state.pos.clone();
+    let encoded_string = EncodedString {
+        source_info: SourceInfo {
+            start: start.clone(),
+            end: end.clone(),
+        },
+        chars,
+    };
+    let elements = templatize(encoded_string)?;
+    Ok(Template {
+        quotes: false,
+        elements,
+        source_info: SourceInfo { start, end },
+    })
+}
+
 pub fn templatize(encoded_string: EncodedString) -> ParseResult<'static, Vec<TemplateElement>> {
     enum State {
         String {},
@@ -132,7 +157,7 @@ pub fn templatize(encoded_string: EncodedString) -> ParseResult<'static, Vec<Tem
 
 #[cfg(test)]
 mod tests {
-    use crate::ast::{Expr, Variable, Whitespace};
+    use crate::ast::{Expr, ExprKind, Variable, Whitespace};
 
     use super::*;
 
@@ -233,10 +258,10 @@ mod tests {
                         value: "".to_string(),
                         source_info: SourceInfo::init(1, 11, 1, 11),
                     },
-                    variable: Variable {
+                    kind: ExprKind::Variable(Variable {
                         name: "name".to_string(),
                         source_info: SourceInfo::init(1, 11, 1, 15),
-                    },
+                    }),
                     space1: Whitespace {
                         value: "".to_string(),
                         source_info: SourceInfo::init(1, 15, 1, 15),
@@ -270,10 +295,10 @@ mod tests {
                     value: "".to_string(),
                     source_info: SourceInfo::init(1, 3, 1, 3),
                 },
-                variable: Variable {
+                kind: ExprKind::Variable(Variable {
                     name: "x".to_string(),
                     source_info: SourceInfo::init(1, 3, 1, 4),
-                },
+                }),
                 space1: Whitespace {
                     value: "".to_string(),
                     source_info: SourceInfo::init(1, 4, 1, 4),
@@ -324,4 +349,24 @@ mod tests {
             },]
         );
     }
+
+    #[test]
+    fn test_template_content() {
+        let mut reader = Reader::init("{\n  \"id\": {{id}}\n}\n");
+        let template = template_content(&mut reader).unwrap();
+        assert_eq!(template.source_info, SourceInfo::init(1, 1, 4, 1));
+        assert_eq!(template.elements.len(), 3);
+        assert_eq!(
+            template.elements[0],
+            TemplateElement::String {
+                value: "{\n  \"id\": ".to_string(),
+                encoded: "{\n  \"id\": ".to_string(),
+            }
+        );
+        assert!(reader.is_eof());
+
+        let mut reader = Reader::init("{{id}");
+        let error = template_content(&mut reader).err().unwrap();
+        assert!(!error.recoverable);
+    }
 }
@@ -182,10 +182,10 @@ mod tests {
                             value: String::from(""),
                             source_info: SourceInfo::init(1, 10, 1, 10),
                         },
-                        variable: Variable {
+                        kind: ExprKind::Variable(Variable {
                             name: String::from("host"),
                             source_info: SourceInfo::init(1, 10, 1, 14),
-                        },
+                        }),
                         space1: Whitespace {
                             value: String::from(""),
                             source_info: SourceInfo::init(1, 14, 1, 14),
@@ -88,6 +88,10 @@ impl ToJson for Request {
             let cookies = self.clone().cookies().iter().map(|c| c.to_json()).collect();
             attributes.push(("cookies".to_string(), JValue::List(cookies)));
         }
+        if !self.clone().options().is_empty() {
+            let options = self.clone().options().iter().map(|o| o.to_json()).collect();
+            attributes.push(("options".to_string(), JValue::List(options)));
+        }
         if let Some(body) = self.body.clone() {
             attributes.push(("body".to_string(), body.to_json()));
         }
@@ -144,6 +148,7 @@ impl ToJson for Bytes {
             Bytes::Base64(value) => value.to_json(),
             Bytes::Hex(value) => value.to_json(),
             Bytes::File(value) => value.to_json(),
+            Bytes::TemplateFile(value) => value.to_json(),
             Bytes::Json { value } => JValue::Object(vec![
                 ("type".to_string(), JValue::String("json".to_string())),
                 ("value".to_string(), value.to_json()),
@@ -223,15 +228,33 @@ impl ToJson for MultipartParam {
     }
 }
 
+impl ToJson for TemplateFile {
+    fn to_json(&self) -> JValue {
+        JValue::Object(vec![
+            (
+                "type".to_string(),
+                JValue::String("template-file".to_string()),
+            ),
+            (
+                "filename".to_string(),
+                JValue::String(self.filename.value.clone()),
+            ),
+        ])
+    }
+}
+
 impl ToJson for FileParam {
     fn to_json(&self) -> JValue {
         let mut attributes = vec![
             ("name".to_string(), JValue::String(self.key.value.clone())),
             (
                 "filename".to_string(),
-                JValue::String(self.value.filename.value.clone()),
+                JValue::String(self.value.filename.to_string()),
             ),
         ];
+        if let Some(content) = self.value.content.clone() {
+            attributes.push(("content".to_string(), content.to_json()));
+        }
         if let Some(content_type) = self.value.content_type.clone() {
             attributes.push(("content_type".to_string(), JValue::String(content_type)));
         }
@@ -239,6 +262,26 @@ impl ToJson for FileParam {
     }
 }
 
+impl ToJson for FileContent {
+    fn to_json(&self) -> JValue {
+        match self {
+            FileContent::RawString(value) => JValue::Object(vec![
+                ("type".to_string(), JValue::String("raw-string".to_string())),
+                ("value".to_string(), JValue::String(value.value.to_string())),
+            ]),
+            FileContent::Base64(value) => value.to_json(),
+            FileContent::Hex(value) => value.to_json(),
+            FileContent::Expression(value) => JValue::Object(vec![
+                ("type".to_string(), JValue::String("expression".to_string())),
+                (
+                    "value".to_string(),
+                    JValue::String(format!("{{{{{}}}}}", value)),
+                ),
+            ]),
+        }
+    }
+}
+
 impl ToJson for Cookie {
     fn to_json(&self) -> JValue {
         let attributes = vec![
@@ -252,6 +295,42 @@ impl ToJson for Cookie {
     }
 }
 
+impl ToJson for EntryOption {
+    fn to_json(&self) -> JValue {
+        let value = match &self.kind {
+            OptionKind::CaCertificate(filename) => JValue::String(filename.value.clone()),
+            OptionKind::ClientCertificate(filename) => JValue::String(filename.value.clone()),
+            OptionKind::ClientKey(filename) => JValue::String(filename.value.clone()),
+            OptionKind::Compressed(value) => JValue::Boolean(*value),
+            OptionKind::ConnectTimeout(value) => JValue::Number(value.to_string()),
+            OptionKind::FollowLocation(value) => JValue::Boolean(*value),
+            OptionKind::Http10(value) => JValue::Boolean(*value),
+            OptionKind::Http11(value) => JValue::Boolean(*value),
+            OptionKind::Http2(value) => JValue::Boolean(*value),
+            OptionKind::Http2PriorKnowledge(value) => JValue::Boolean(*value),
+            OptionKind::IgnoreJsonKey(value) => JValue::String(value.to_string()),
+            OptionKind::Insecure(value) => JValue::Boolean(*value),
+            OptionKind::KeyPassword(value) => JValue::String(value.to_string()),
+            OptionKind::MaxRedirect(value) => JValue::Number(value.to_string()),
+            OptionKind::NoProxy(value) => JValue::String(value.to_string()),
+            OptionKind::Output(filename) => JValue::String(filename.value.clone()),
+            OptionKind::Proxy(value) => JValue::String(value.to_string()),
+            OptionKind::Retry(value) => JValue::Number(value.to_string()),
+            OptionKind::RetryInterval(value) => JValue::Number(value.to_string()),
+            OptionKind::Timeout(value) => JValue::Number(value.to_string()),
+            OptionKind::UserAgent(value) => JValue::String(value.to_string()),
+        };
+        let attributes = vec![
+            (
+                "name".to_string(),
+                JValue::String(self.kind.name().to_string()),
+            ),
+            ("value".to_string(), value),
+        ];
+        JValue::Object(attributes)
+    }
+}
+
 impl ToJson for Capture {
     fn to_json(&self) -> JValue {
         let attributes = vec![
@@ -275,8 +354,9 @@ impl ToJson for Assert {
 impl ToJson for Query {
     fn to_json(&self) -> JValue {
         let mut attributes = query_value_attributes(&self.value);
-        if let Some((_, subquery)) = self.subquery.clone() {
-            attributes.push(("subquery".to_string(), subquery.to_json()));
+        if !self.filters.is_empty() {
+            let filters = self.filters.iter().map(|(_, f)| f.to_json()).collect();
+            attributes.push(("filters".to_string(), JValue::List(filters)));
         }
         JValue::Object(attributes)
     }
@@ -315,9 +395,32 @@ fn query_value_attributes(query_value: &QueryValue) -> Vec<(String, JValue)> {
             attributes.push(("type".to_string(), JValue::String("variable".to_string())));
             attributes.push(("name".to_string(), JValue::String(name.to_string())));
         }
+        QueryValue::Url {} => {
+            attributes.push(("type".to_string(), JValue::String("url".to_string())));
+        }
+        QueryValue::Ip {} => {
+            attributes.push(("type".to_string(), JValue::String("ip".to_string())));
+        }
+        QueryValue::Redirects {} => {
+            attributes.push(("type".to_string(), JValue::String("redirects".to_string())));
+        }
         QueryValue::Duration {} => {
             attributes.push(("type".to_string(), JValue::String("duration".to_string())));
         }
+        QueryValue::Timing { name, .. } => {
+            attributes.push(("type".to_string(), JValue::String("timing".to_string())));
+            attributes.push(("name".to_string(), JValue::String(name.to_string())));
+        }
+        QueryValue::Certificate { attribute_name, .. } => {
+            attributes.push((
+                "type".to_string(),
+                JValue::String("certificate".to_string()),
+            ));
+            attributes.push((
+                "expr".to_string(),
+                JValue::String(attribute_name.to_string()),
+            ));
+        }
         QueryValue::Bytes {} => {
             attributes.push(("type".to_string(), JValue::String("bytes".to_string())));
         }
@@ -331,23 +434,46 @@ fn query_value_attributes(query_value: &QueryValue) -> Vec<(String, JValue)> {
     attributes
 }
 
-impl ToJson for Subquery {
+impl ToJson for Filter {
     fn to_json(&self) -> JValue {
         self.value.to_json()
     }
 }
 
-impl ToJson for SubqueryValue {
+impl ToJson for FilterValue {
     fn to_json(&self) -> JValue {
-        let mut attributes = vec![];
+        let mut attributes = vec![("type".to_string(), JValue::String(self.name().to_string()))];
         match self {
-            SubqueryValue::Regex { expr, .. } => {
-                attributes.push(("type".to_string(), JValue::String("regex".to_string())));
+            FilterValue::Format { fmt, .. } => {
+                attributes.push(("fmt".to_string(), JValue::String(fmt.to_string())));
+            }
+            FilterValue::Nth { n, .. } => {
+                attributes.push(("n".to_string(), JValue::Number(n.to_string())));
+            }
+            FilterValue::Regex { expr, .. } => {
                 attributes.push(("expr".to_string(), JValue::String(expr.to_string())));
             }
-            SubqueryValue::Count { .. } => {
-                attributes.push(("type".to_string(), JValue::String("count".to_string())));
+            FilterValue::Replace {
+                old_value,
+                new_value,
+                ..
+            } => {
+                attributes.push((
+                    "old_value".to_string(),
+                    JValue::String(old_value.to_string()),
+                ));
+                attributes.push((
+                    "new_value".to_string(),
+                    JValue::String(new_value.to_string()),
+                ));
+            }
+            FilterValue::Split { sep, .. } => {
+                attributes.push(("sep".to_string(), JValue::String(sep.to_string())));
             }
+            FilterValue::ToDate { fmt, .. } => {
+                attributes.push(("fmt".to_string(), JValue::String(fmt.to_string())));
+            }
+            _ => {}
         }
         JValue::Object(attributes)
     }
@@ -414,6 +540,28 @@ impl ToJson for Predicate {
                 attributes.push(("type".to_string(), JValue::String("match".to_string())));
                 add_predicate_value(&mut attributes, value);
             }
+            PredicateFuncValue::IncludeJson { value, .. } => {
+                attributes.push((
+                    "type".to_string(),
+                    JValue::String("includesJson".to_string()),
+                ));
+                attributes.push(("value".to_string(), value.to_json()));
+            }
+            PredicateFuncValue::MatchJson { value, .. } => {
+                attributes.push((
+                    "type".to_string(),
+                    JValue::String("matchesJson".to_string()),
+                ));
+                attributes.push(("value".to_string(), value.to_json()));
+            }
+            PredicateFuncValue::JsonSchema { value, .. } => {
+                attributes.push(("type".to_string(), JValue::String("jsonschema".to_string())));
+                attributes.push(("value".to_string(), value.to_json()));
+            }
+            PredicateFuncValue::XmlSchema { value, .. } => {
+                attributes.push(("type".to_string(), JValue::String("xmlschema".to_string())));
+                attributes.push(("value".to_string(), value.to_json()));
+            }
             PredicateFuncValue::IsInteger {} => {
                 attributes.push(("type".to_string(), JValue::String("isInteger".to_string())));
             }
@@ -432,6 +580,21 @@ impl ToJson for Predicate {
                     JValue::String("isCollection".to_string()),
                 ));
             }
+            PredicateFuncValue::IsEmpty {} => {
+                attributes.push(("type".to_string(), JValue::String("isEmpty".to_string())));
+            }
+            PredicateFuncValue::IsDate {} => {
+                attributes.push(("type".to_string(), JValue::String("isDate".to_string())));
+            }
+            PredicateFuncValue::IsIsoDate {} => {
+                attributes.push(("type".to_string(), JValue::String("isIsoDate".to_string())));
+            }
+            PredicateFuncValue::IsNumber {} => {
+                attributes.push(("type".to_string(), JValue::String("isNumber".to_string())));
+            }
+            PredicateFuncValue::IsList {} => {
+                attributes.push(("type".to_string(), JValue::String("isList".to_string())));
+            }
             PredicateFuncValue::Exist {} => {
                 attributes.push(("type".to_string(), JValue::String("exist".to_string())));
             }
@@ -651,7 +814,7 @@ pub mod tests {
                     source_info: SourceInfo::init(0, 0, 0, 0),
                 },
             },
-            subquery: None,
+            filters: vec![],
         }
     }
 
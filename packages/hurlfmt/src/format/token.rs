@@ -204,6 +204,9 @@ impl Tokenizable for Bytes {
             Bytes::File(value) => {
                 tokens.append(&mut value.tokenize());
             }
+            Bytes::TemplateFile(value) => {
+                tokens.append(&mut value.tokenize());
+            }
         }
         tokens
     }
@@ -272,6 +275,12 @@ impl Tokenizable for SectionValue {
                     items.iter().flat_map(|e| e.tokenize()).collect(),
                 );
             }
+            SectionValue::Options(items) => {
+                add_tokens(
+                    &mut tokens,
+                    items.iter().flat_map(|e| e.tokenize()).collect(),
+                );
+            }
         }
         tokens
     }
@@ -340,6 +349,17 @@ impl Tokenizable for MultipartParam {
     }
 }
 
+impl Tokenizable for TemplateFile {
+    fn tokenize(&self) -> Vec<Token> {
+        let mut tokens: Vec<Token> = vec![Token::Keyword(String::from("template,"))];
+        add_tokens(&mut tokens, self.space0.tokenize());
+        add_tokens(&mut tokens, self.filename.tokenize());
+        add_tokens(&mut tokens, self.space1.tokenize());
+        tokens.push(Token::Keyword(String::from(";")));
+        tokens
+    }
+}
+
 impl Tokenizable for FileParam {
     fn tokenize(&self) -> Vec<Token> {
         let mut tokens: Vec<Token> = vec![];
@@ -362,6 +382,10 @@ impl Tokenizable for FileValue {
         tokens.append(&mut self.space1.tokenize());
         tokens.push(Token::Keyword(";".to_string()));
         tokens.append(&mut self.space2.tokenize());
+        if let Some(content) = self.content.clone() {
+            tokens.append(&mut content.tokenize());
+        }
+        tokens.append(&mut self.space3.tokenize());
         if let Some(content_type) = self.content_type.clone() {
             tokens.push(Token::String(content_type));
         }
@@ -369,6 +393,17 @@ impl Tokenizable for FileValue {
     }
 }
 
+impl Tokenizable for FileContent {
+    fn tokenize(&self) -> Vec<Token> {
+        match self {
+            FileContent::RawString(value) => value.tokenize(),
+            FileContent::Base64(value) => value.tokenize(),
+            FileContent::Hex(value) => value.tokenize(),
+            FileContent::Expression(value) => value.tokenize(),
+        }
+    }
+}
+
 impl Tokenizable for Cookie {
     fn tokenize(&self) -> Vec<Token> {
         let mut tokens: Vec<Token> = vec![];
@@ -396,6 +431,55 @@ impl Tokenizable for CookieValue {
     }
 }
 
+impl Tokenizable for EntryOption {
+    fn tokenize(&self) -> Vec<Token> {
+        let mut tokens: Vec<Token> = vec![];
+        add_tokens(
+            &mut tokens,
+            self.line_terminators
+                .iter()
+                .flat_map(|e| e.tokenize())
+                .collect(),
+        );
+        add_tokens(&mut tokens, self.space0.tokenize());
+        tokens.push(Token::String(self.kind.name().to_string()));
+        add_tokens(&mut tokens, self.space1.tokenize());
+        tokens.push(Token::Colon(String::from(":")));
+        add_tokens(&mut tokens, self.space2.tokenize());
+        add_tokens(&mut tokens, self.kind.tokenize());
+        add_tokens(&mut tokens, self.line_terminator0.tokenize());
+        tokens
+    }
+}
+
+impl Tokenizable for OptionKind {
+    fn tokenize(&self) -> Vec<Token> {
+        match self {
+            OptionKind::CaCertificate(filename) => filename.tokenize(),
+            OptionKind::ClientCertificate(filename) => filename.tokenize(),
+            OptionKind::ClientKey(filename) => filename.tokenize(),
+            OptionKind::Compressed(value) => vec![Token::Boolean(value.to_string())],
+            OptionKind::ConnectTimeout(value) => vec![Token::Number(value.to_string())],
+            OptionKind::FollowLocation(value) => vec![Token::Boolean(value.to_string())],
+            OptionKind::Http10(value) => vec![Token::Boolean(value.to_string())],
+            OptionKind::Http11(value) => vec![Token::Boolean(value.to_string())],
+            OptionKind::Http2(value) => vec![Token::Boolean(value.to_string())],
+            OptionKind::Http2PriorKnowledge(value) => vec![Token::Boolean(value.to_string())],
+            OptionKind::IgnoreJsonKey(value) => value.tokenize(),
+            OptionKind::Insecure(value) => vec![Token::Boolean(value.to_string())],
+            OptionKind::KeyPassword(value) => value.tokenize(),
+            OptionKind::MaxRedirect(value) => vec![Token::Number(value.to_string())],
+            OptionKind::NoProxy(value) => value.tokenize(),
+            OptionKind::Output(filename) => filename.tokenize(),
+            OptionKind::Proxy(value) => value.tokenize(),
+            OptionKind::Retry(value) => vec![Token::Number(value.to_string())],
+            OptionKind::RetryInterval(value) => vec![Token::Number(value.to_string())],
+            OptionKind::Timeout(value) => vec![Token::Number(value.to_string())],
+            OptionKind::UserAgent(value) => value.tokenize(),
+        }
+    }
+}
+
 impl Tokenizable for Capture {
     fn tokenize(&self) -> Vec<Token> {
         let mut tokens: Vec<Token> = vec![];
@@ -441,9 +525,9 @@ impl Tokenizable for Assert {
 impl Tokenizable for Query {
     fn tokenize(&self) -> Vec<Token> {
         let mut tokens: Vec<Token> = self.value.clone().tokenize();
-        if let Some((space, subquery)) = &self.subquery {
+        for (space, filter) in &self.filters {
             add_tokens(&mut tokens, space.clone().tokenize());
-            tokens.append(&mut subquery.tokenize());
+            tokens.append(&mut filter.tokenize());
         }
         tokens
     }
@@ -487,7 +571,25 @@ impl Tokenizable for QueryValue {
                 add_tokens(&mut tokens, space0.tokenize());
                 add_tokens(&mut tokens, name.tokenize());
             }
+            QueryValue::Url {} => tokens.push(Token::QueryType(String::from("url"))),
+            QueryValue::Ip {} => tokens.push(Token::QueryType(String::from("ip"))),
+            QueryValue::Redirects {} => tokens.push(Token::QueryType(String::from("redirects"))),
             QueryValue::Duration {} => tokens.push(Token::QueryType(String::from("duration"))),
+            QueryValue::Timing { space0, name } => {
+                tokens.push(Token::QueryType(String::from("timing")));
+                add_tokens(&mut tokens, space0.tokenize());
+                tokens.push(Token::String(name.to_string()));
+            }
+            QueryValue::Certificate {
+                space0,
+                attribute_name,
+            } => {
+                tokens.push(Token::QueryType(String::from("certificate")));
+                add_tokens(&mut tokens, space0.tokenize());
+                tokens.push(Token::Quote("\"".to_string()));
+                tokens.push(Token::String(attribute_name.to_string()));
+                tokens.push(Token::Quote("\"".to_string()));
+            }
             QueryValue::Bytes {} => tokens.push(Token::QueryType(String::from("bytes"))),
             QueryValue::Sha256 {} => tokens.push(Token::QueryType(String::from("sha256"))),
             QueryValue::Md5 {} => tokens.push(Token::QueryType(String::from("md5"))),
@@ -518,18 +620,42 @@ impl Tokenizable for CookieAttribute {
     }
 }
 
-impl Tokenizable for Subquery {
+impl Tokenizable for Filter {
     fn tokenize(&self) -> Vec<Token> {
-        let mut tokens: Vec<Token> = vec![];
+        let mut tokens: Vec<Token> = vec![Token::QueryType(self.value.name().to_string())];
         match self.value.clone() {
-            SubqueryValue::Regex { space0, expr } => {
-                tokens.push(Token::QueryType(String::from("regex")));
+            FilterValue::Format { space0, fmt } => {
+                add_tokens(&mut tokens, space0.tokenize());
+                add_tokens(&mut tokens, fmt.tokenize());
+            }
+            FilterValue::Nth { space0, n } => {
+                add_tokens(&mut tokens, space0.tokenize());
+                tokens.push(Token::Number(n.to_string()));
+            }
+            FilterValue::Regex { space0, expr } => {
                 add_tokens(&mut tokens, space0.tokenize());
                 add_tokens(&mut tokens, expr.tokenize());
             }
-            SubqueryValue::Count { .. } => {
-                tokens.push(Token::QueryType(String::from("count")));
+            FilterValue::Replace {
+                space0,
+                old_value,
+                space1,
+                new_value,
+            } => {
+                add_tokens(&mut tokens, space0.tokenize());
+                add_tokens(&mut tokens, old_value.tokenize());
+                add_tokens(&mut tokens, space1.tokenize());
+                add_tokens(&mut tokens, new_value.tokenize());
+            }
+            FilterValue::Split { space0, sep } => {
+                add_tokens(&mut tokens, space0.tokenize());
+                add_tokens(&mut tokens, sep.tokenize());
+            }
+            FilterValue::ToDate { space0, fmt } => {
+                add_tokens(&mut tokens, space0.tokenize());
+                add_tokens(&mut tokens, fmt.tokenize());
             }
+            _ => {}
         }
         tokens
     }
@@ -617,6 +743,18 @@ impl Tokenizable for PredicateFuncValue {
                 add_tokens(&mut tokens, space0.tokenize());
                 add_tokens(&mut tokens, value.tokenize());
             }
+            PredicateFuncValue::IncludeJson { space0, value }
+            | PredicateFuncValue::MatchJson { space0, value } => {
+                tokens.push(Token::PredicateType(self.name()));
+                add_tokens(&mut tokens, space0.tokenize());
+                add_tokens(&mut tokens, value.tokenize());
+            }
+            PredicateFuncValue::JsonSchema { space0, value }
+            | PredicateFuncValue::XmlSchema { space0, value } => {
+                tokens.push(Token::PredicateType(self.name()));
+                add_tokens(&mut tokens, space0.tokenize());
+                add_tokens(&mut tokens, value.tokenize());
+            }
 
             PredicateFuncValue::IsInteger {} => {
                 tokens.push(Token::PredicateType(self.name()));
@@ -633,6 +771,21 @@ impl Tokenizable for PredicateFuncValue {
             PredicateFuncValue::IsCollection {} => {
                 tokens.push(Token::PredicateType(self.name()));
             }
+            PredicateFuncValue::IsEmpty {} => {
+                tokens.push(Token::PredicateType(self.name()));
+            }
+            PredicateFuncValue::IsDate {} => {
+                tokens.push(Token::PredicateType(self.name()));
+            }
+            PredicateFuncValue::IsIsoDate {} => {
+                tokens.push(Token::PredicateType(self.name()));
+            }
+            PredicateFuncValue::IsNumber {} => {
+                tokens.push(Token::PredicateType(self.name()));
+            }
+            PredicateFuncValue::IsList {} => {
+                tokens.push(Token::PredicateType(self.name()));
+            }
             PredicateFuncValue::Exist {} => {
                 tokens.push(Token::PredicateType(self.name()));
             }
@@ -729,7 +882,19 @@ impl Tokenizable for Expr {
     fn tokenize(&self) -> Vec<Token> {
         let mut tokens: Vec<Token> = vec![Token::CodeDelimiter(String::from("{{"))];
         add_tokens(&mut tokens, self.space0.tokenize());
-        tokens.push(Token::CodeVariable(self.variable.name.clone()));
+        match &self.kind {
+            ExprKind::Variable(variable) => {
+                tokens.push(Token::CodeVariable(variable.name.clone()));
+            }
+            ExprKind::Function(function) => match &function.value {
+                FunctionValue::Env { space0, name } => {
+                    tokens.push(Token::CodeVariable(function.value.name().to_string()));
+                    add_tokens(&mut tokens, space0.tokenize());
+                    tokens.push(Token::String(encode_env_name(name)));
+                }
+                value => tokens.push(Token::CodeVariable(value.name().to_string())),
+            },
+        }
         add_tokens(&mut tokens, self.space1.tokenize());
         tokens.push(Token::CodeDelimiter(String::from("}}")));
         tokens
@@ -185,6 +185,9 @@ impl Lintable<SectionValue> for SectionValue {
             SectionValue::Cookies(cookies) => {
                 SectionValue::Cookies(cookies.iter().map(|e| e.lint()).collect())
             }
+            SectionValue::Options(options) => {
+                SectionValue::Options(options.iter().map(|e| e.lint()).collect())
+            }
         }
     }
 }
@@ -196,6 +199,7 @@ fn section_value_index(section_value: SectionValue) -> u32 {
         SectionValue::FormParams(_) => 2,
         SectionValue::MultipartFormData(_) => 3,
         SectionValue::Cookies(_) => 3,
+        SectionValue::Options(_) => 4,
         SectionValue::Captures(_) => 0,
         SectionValue::Asserts(_) => 1,
     }
@@ -219,6 +223,24 @@ impl Lintable<Assert> for Assert {
     }
 }
 
+impl Lintable<EntryOption> for EntryOption {
+    fn errors(&self) -> Vec<Error> {
+        let errors = vec![];
+        errors
+    }
+
+    fn lint(&self) -> EntryOption {
+        EntryOption {
+            line_terminators: self.line_terminators.clone(),
+            space0: empty_whitespace(),
+            space1: empty_whitespace(),
+            space2: one_whitespace(),
+            kind: self.kind.clone(),
+            line_terminator0: self.line_terminator0.lint(),
+        }
+    }
+}
+
 impl Lintable<Capture> for Capture {
     fn errors(&self) -> Vec<Error> {
         let errors = vec![];
@@ -248,10 +270,11 @@ impl Lintable<Query> for Query {
         Query {
             source_info: SourceInfo::init(0, 0, 0, 0),
             value: self.value.lint(),
-            subquery: self
-                .subquery
-                .clone()
-                .map(|(_, s)| (one_whitespace(), s.lint())),
+            filters: self
+                .filters
+                .iter()
+                .map(|(_, f)| (one_whitespace(), f.lint()))
+                .collect(),
         }
     }
 }
@@ -299,7 +322,18 @@ impl Lintable<QueryValue> for QueryValue {
                 name: name.clone(),
                 space0: one_whitespace(),
             },
+            QueryValue::Url {} => QueryValue::Url {},
+            QueryValue::Ip {} => QueryValue::Ip {},
+            QueryValue::Redirects {} => QueryValue::Redirects {},
             QueryValue::Duration {} => QueryValue::Duration {},
+            QueryValue::Timing { name, .. } => QueryValue::Timing {
+                name: name.clone(),
+                space0: one_whitespace(),
+            },
+            QueryValue::Certificate { attribute_name, .. } => QueryValue::Certificate {
+                attribute_name: attribute_name.clone(),
+                space0: one_whitespace(),
+            },
             QueryValue::Bytes {} => QueryValue::Bytes {},
             QueryValue::Sha256 {} => QueryValue::Sha256 {},
             QueryValue::Md5 {} => QueryValue::Md5 {},
@@ -307,32 +341,58 @@ impl Lintable<QueryValue> for QueryValue {
     }
 }
 
-impl Lintable<Subquery> for Subquery {
+impl Lintable<Filter> for Filter {
     fn errors(&self) -> Vec<Error> {
         let errors = vec![];
         errors
     }
 
-    fn lint(&self) -> Subquery {
+    fn lint(&self) -> Filter {
         let source_info = SourceInfo::init(0, 0, 0, 0);
         let value = self.value.lint();
-        Subquery { source_info, value }
+        Filter { source_info, value }
     }
 }
 
-impl Lintable<SubqueryValue> for SubqueryValue {
+impl Lintable<FilterValue> for FilterValue {
     fn errors(&self) -> Vec<Error> {
         let errors = vec![];
         errors
     }
 
-    fn lint(&self) -> SubqueryValue {
+    fn lint(&self) -> FilterValue {
         match self {
-            SubqueryValue::Regex { expr, .. } => SubqueryValue::Regex {
+            FilterValue::Format { fmt, .. } => FilterValue::Format {
+                space0: one_whitespace(),
+                fmt: fmt.clone(),
+            },
+            FilterValue::Nth { n, .. } => FilterValue::Nth {
+                space0: one_whitespace(),
+                n: *n,
+            },
+            FilterValue::Regex { expr, .. } => FilterValue::Regex {
                 space0: one_whitespace(),
                 expr: expr.clone(),
             },
-            SubqueryValue::Count {} => SubqueryValue::Count {},
+            FilterValue::Replace {
+                old_value,
+                new_value,
+                ..
+            } => FilterValue::Replace {
+                space0: one_whitespace(),
+                old_value: old_value.clone(),
+                space1: one_whitespace(),
+                new_value: new_value.clone(),
+            },
+            FilterValue::Split { sep, .. } => FilterValue::Split {
+                space0: one_whitespace(),
+                sep: sep.clone(),
+            },
+            FilterValue::ToDate { fmt, .. } => FilterValue::ToDate {
+                space0: one_whitespace(),
+                fmt: fmt.clone(),
+            },
+            _ => self.clone(),
         }
     }
 }
@@ -469,6 +529,22 @@ impl Lintable<PredicateFuncValue> for PredicateFuncValue {
                 space0: one_whitespace(),
                 value: value.clone().lint(),
             },
+            PredicateFuncValue::IncludeJson { value, .. } => PredicateFuncValue::IncludeJson {
+                space0: one_whitespace(),
+                value: value.clone(),
+            },
+            PredicateFuncValue::MatchJson { value, .. } => PredicateFuncValue::MatchJson {
+                space0: one_whitespace(),
+                value: value.clone(),
+            },
+            PredicateFuncValue::JsonSchema { value, .. } => PredicateFuncValue::JsonSchema {
+                space0: one_whitespace(),
+                value: value.lint(),
+            },
+            PredicateFuncValue::XmlSchema { value, .. } => PredicateFuncValue::XmlSchema {
+                space0: one_whitespace(),
+                value: value.lint(),
+            },
             PredicateFuncValue::StartWith { value, .. } => PredicateFuncValue::StartWith {
                 space0: one_whitespace(),
                 value: value.clone().lint(),
@@ -486,6 +562,11 @@ impl Lintable<PredicateFuncValue> for PredicateFuncValue {
             PredicateFuncValue::IsBoolean {} => PredicateFuncValue::IsBoolean {},
             PredicateFuncValue::IsString {} => PredicateFuncValue::IsString {},
             PredicateFuncValue::IsCollection {} => PredicateFuncValue::IsCollection {},
+            PredicateFuncValue::IsEmpty {} => PredicateFuncValue::IsEmpty {},
+            PredicateFuncValue::IsDate {} => PredicateFuncValue::IsDate {},
+            PredicateFuncValue::IsIsoDate {} => PredicateFuncValue::IsIsoDate {},
+            PredicateFuncValue::IsNumber {} => PredicateFuncValue::IsNumber {},
+            PredicateFuncValue::IsList {} => PredicateFuncValue::IsList {},
             PredicateFuncValue::Exist {} => PredicateFuncValue::Exist {},
         }
     }
@@ -567,6 +648,7 @@ impl Lintable<Bytes> for Bytes {
         //let line_terminator0 = self.clone().line_terminator0;
         match self {
             Bytes::File(value) => Bytes::File(value.lint()),
+            Bytes::TemplateFile(value) => Bytes::TemplateFile(value.lint()),
             Bytes::Base64(value) => Bytes::Base64(value.lint()),
             Bytes::Hex(value) => Bytes::Hex(value.lint()),
             Bytes::Json { value } => Bytes::Json {
@@ -627,6 +709,23 @@ impl Lintable<File> for File {
     }
 }
 
+impl Lintable<TemplateFile> for TemplateFile {
+    fn errors(&self) -> Vec<Error> {
+        unimplemented!()
+    }
+
+    fn lint(&self) -> TemplateFile {
+        TemplateFile {
+            space0: one_whitespace(),
+            filename: Filename {
+                value: self.filename.clone().value,
+                source_info: SourceInfo::init(0, 0, 0, 0),
+            },
+            space1: empty_whitespace(),
+        }
+    }
+}
+
 impl Lintable<KeyValue> for KeyValue {
     fn errors(&self) -> Vec<Error> {
         let errors = vec![];
@@ -85,7 +85,7 @@ fn value_string() -> BoxedStrategy<JsonValue> {
                         value: "".to_string(),
                         source_info: source_info.clone()
                     },
-                    variable,
+                    kind: ExprKind::Variable(variable),
                     space1: Whitespace {
                         value: "".to_string(),
                         source_info: source_info.clone()